@@ -4,10 +4,14 @@ import 'dart:io';
 import 'package:flutter/foundation.dart';
 import 'package:path_provider/path_provider.dart';
 import 'package:rustnithm_server/src/rust/api.dart' as rust_api;
-import 'package:rustnithm_server/src/rust/api.dart' show SensorData;
+import 'package:rustnithm_server/src/rust/events.dart' show ServerEvent;
+import 'package:rustnithm_server/src/rust/logging.dart' show LogEntry;
+import 'package:rustnithm_server/src/rust/state.dart' show SensorData;
 
 class ServerIO {
   StreamSubscription? _sensorSub;
+  StreamSubscription? _eventSub;
+  StreamSubscription? _logSub;
 
   Future<File> _getConfigFile() async {
     final appSupportDir = await getApplicationSupportDirectory();
@@ -76,8 +80,27 @@ class ServerIO {
     });
   }
 
+  void listenEvents(Function(ServerEvent) onEvent) {
+    _eventSub?.cancel();
+    _eventSub = rust_api.createEventStream().listen((event) {
+      onEvent(event);
+    });
+  }
+
+  void listenLogs(Function(LogEntry) onLog, {int backlog = 0}) {
+    _logSub?.cancel();
+    if (backlog > 0) {
+      rust_api.getRecentLogs(limit: backlog).forEach(onLog);
+    }
+    _logSub = rust_api.createLogStream().listen((entry) {
+      onLog(entry);
+    });
+  }
+
   void stopListening() {
     _sensorSub?.cancel();
     _sensorSub = null;
+    _eventSub?.cancel();
+    _eventSub = null;
   }
 }
\ No newline at end of file
@@ -1,13 +1,16 @@
 import 'dart:io';
 import 'package:flutter/material.dart';
 import 'package:network_info_plus/network_info_plus.dart';
-import 'dart:async';
 import 'dart:typed_data';
 import 'io.dart';
-import 'package:rustnithm_server/src/rust/api.dart' show SensorData;
+import 'package:rustnithm_server/src/rust/events.dart';
+import 'package:rustnithm_server/src/rust/logging.dart' show LogEntry;
+import 'package:rustnithm_server/src/rust/state.dart' show SensorData;
 
 enum ServerProtocol { udp, tcp }
 
+const int _maxLogs = 200;
+
 class ServerState extends ChangeNotifier {
   final ServerIO _io = ServerIO();
 
@@ -30,6 +33,8 @@ class ServerState extends ChangeNotifier {
   int service = 0;
   int test = 0;
   Uint8List code = Uint8List(10);
+  bool cardPresent = false;
+  final List<LogEntry> logs = [];
 
   bool get isRunning => _isRunning;
   bool get isActivated => _isActivated;
@@ -42,6 +47,19 @@ class ServerState extends ChangeNotifier {
 
   ServerState() {
     _refreshIps();
+    _io.listenLogs(_onLog, backlog: _maxLogs);
+  }
+
+  void _onLog(LogEntry entry) {
+    debugPrint(
+      "[${entry.level.name.toUpperCase()}] ${entry.category.name}: "
+      "${entry.message}",
+    );
+    logs.add(entry);
+    if (logs.length > _maxLogs) {
+      logs.removeRange(0, logs.length - _maxLogs);
+    }
+    notifyListeners();
   }
 
   void consumeTipsSignal() {
@@ -110,6 +128,7 @@ class ServerState extends ChangeNotifier {
         _statusMessage = "RUNNING";
         _io.saveLastIp(_allIps[_currentIpIndex]);
         _io.listenSensors(_onSensorUpdate);
+        _io.listenEvents(_onServerEvent);
       } else {
         _statusMessage = "IDLE";
         _isActivated = false;
@@ -149,32 +168,30 @@ class ServerState extends ChangeNotifier {
       }
     }
 
-    final incomingCode = data.code;
-    bool hasValue = false;
-    for (var b in incomingCode) {
-      if (b != 0) {
-        hasValue = true;
-        break;
-      }
-    }
-
-    if (hasValue) {
+    if (data.cardPresent) {
       bool codeChanged = false;
       for (int i = 0; i < 10; i++) {
-        if (code[i] != incomingCode[i]) {
+        if (code[i] != data.code[i]) {
           codeChanged = true;
           break;
         }
       }
 
-      if (codeChanged) {
-        code = Uint8List.fromList(incomingCode.take(10).toList());
+      if (codeChanged || !cardPresent) {
+        code = Uint8List.fromList(data.code);
+        cardPresent = true;
         _isActivated = true;
         changed = true;
         _checkAndPersistIp();
       }
-    } else if (code.any((e) => e != 0)) {
+    } else if (cardPresent) {
       code = Uint8List(10);
+      cardPresent = false;
+      changed = true;
+    }
+
+    if (data.active && !_isActivated) {
+      _isActivated = true;
       changed = true;
     }
 
@@ -190,6 +207,7 @@ class ServerState extends ChangeNotifier {
     service = 0;
     test = 0;
     code = Uint8List(10);
+    cardPresent = false;
   }
 
   Future<void> _checkAndPersistIp() async {
@@ -216,19 +234,28 @@ class ServerState extends ChangeNotifier {
       return false;
     }
 
-    Timer(const Duration(milliseconds: 500), () {
-      _isTransitioning = false;
-      if (!_isActivated) {
+    return true;
+  }
+
+  void _onServerEvent(ServerEvent event) {
+    switch (event) {
+      case ServerEvent_SyncConfirmed(:final active):
+        _isTransitioning = false;
+        _isActivated = active;
+        _failCount = 0;
+        notifyListeners();
+      case ServerEvent_SyncFailed(:final active, :final reason):
+        debugPrint("Sync Failed: $reason");
+        _isTransitioning = false;
+        _isActivated = active;
         _failCount++;
         if (_failCount >= 5) {
           _failCount = 0;
           _showTipsSignal = true;
         }
-      } else {
-        _failCount = 0;
-      }
-      notifyListeners();
-    });
-    return true;
+        notifyListeners();
+      default:
+        break;
+    }
   }
 }
\ No newline at end of file
@@ -3,14 +3,55 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import 'card_library.dart';
+import 'coin.dart';
+import 'discovery.dart';
+import 'events.dart';
+import 'filter.dart';
+import 'framing.dart';
 import 'frb_generated.dart';
-import 'package:collection/collection.dart';
+import 'gesture.dart';
+import 'latency.dart';
+import 'liveness.dart';
+import 'logging.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'pairing.dart';
 import 'protocol.dart';
+import 'remap.dart';
+import 'server.dart';
+import 'state.dart';
+import 'sync.dart';
+
+// These functions are ignored because they are not marked as `pub`: `default_server`, `parse_client_ip`
+
+/// Runs when the Dart side loads the library, before any other call.
+Future<void> initApp() => RustLib.instance.api.crateApiInitApp();
+
+Future<ServerHandle> createServer({required ServerConfig config}) =>
+    RustLib.instance.api.crateApiCreateServer(config: config);
 
 Stream<SensorData> createSensorStream() =>
     RustLib.instance.api.crateApiCreateSensorStream();
 
+Stream<ServerEvent> createEventStream() =>
+    RustLib.instance.api.crateApiCreateEventStream();
+
+Stream<LogEntry> createLogStream() =>
+    RustLib.instance.api.crateApiCreateLogStream();
+
+List<LogEntry> getRecentLogs({required int limit}) =>
+    RustLib.instance.api.crateApiGetRecentLogs(limit: limit);
+
+Future<void> clearLogs() => RustLib.instance.api.crateApiClearLogs();
+
+Future<void> setLogFile({String? path}) =>
+    RustLib.instance.api.crateApiSetLogFile(path: path);
+
+Future<void> setLogLevel({required LogLevel level}) =>
+    RustLib.instance.api.crateApiSetLogLevel(level: level);
+
+LogLevel getLogLevel() => RustLib.instance.api.crateApiGetLogLevel();
+
 Future<void> initLastIp({required String ip}) =>
     RustLib.instance.api.crateApiInitLastIp(ip: ip);
 
@@ -22,6 +63,33 @@ Future<void> handleHandshake({required HandshakePayload incoming}) =>
 
 Future<bool> toggleSync() => RustLib.instance.api.crateApiToggleSync();
 
+String getConnectionUri({List<String>? hosts, String? secret}) =>
+    RustLib.instance.api.crateApiGetConnectionUri(hosts: hosts, secret: secret);
+
+QrMatrix getConnectionQr({List<String>? hosts, String? secret}) =>
+    RustLib.instance.api.crateApiGetConnectionQr(hosts: hosts, secret: secret);
+
+String generatePairingSecret() =>
+    RustLib.instance.api.crateApiGeneratePairingSecret();
+
+LinkState getConnectionState() =>
+    RustLib.instance.api.crateApiGetConnectionState();
+
+List<ClientSession> getSessions() => RustLib.instance.api.crateApiGetSessions();
+
+Future<void> setLivenessConfig({required LivenessConfig config}) =>
+    RustLib.instance.api.crateApiSetLivenessConfig(config: config);
+
+LivenessConfig getLivenessConfig() =>
+    RustLib.instance.api.crateApiGetLivenessConfig();
+
+SyncState getSyncState() => RustLib.instance.api.crateApiGetSyncState();
+
+Future<void> setSyncConfig({required SyncConfig config}) =>
+    RustLib.instance.api.crateApiSetSyncConfig(config: config);
+
+SyncConfig getSyncConfig() => RustLib.instance.api.crateApiGetSyncConfig();
+
 Future<void> syncToShmem({
   required List<int> air,
   required List<int> slider,
@@ -36,69 +104,210 @@ Future<void> syncToShmem({
   test: test,
 );
 
-Future<void> reportToFlutter({
-  required List<int> air,
-  required List<int> slider,
-  required int coin,
-  required int service,
-  required int test,
-  required U8Array10 code,
-}) => RustLib.instance.api.crateApiReportToFlutter(
-  air: air,
-  slider: slider,
-  coin: coin,
-  service: service,
-  test: test,
-  code: code,
+Future<void> setDiscoveryEnabled({required bool enabled}) =>
+    RustLib.instance.api.crateApiSetDiscoveryEnabled(enabled: enabled);
+
+bool isDiscoveryEnabled() => RustLib.instance.api.crateApiIsDiscoveryEnabled();
+
+/// Broadcasts a discovery probe and lists the servers that answered within `timeout_ms`.
+Future<List<DiscoveredServer>> discoverServers({required int timeoutMs}) =>
+    RustLib.instance.api.crateApiDiscoverServers(timeoutMs: timeoutMs);
+
+Future<List<CardEntry>> initCardLibrary({required String path}) =>
+    RustLib.instance.api.crateApiInitCardLibrary(path: path);
+
+List<CardEntry> listCards() => RustLib.instance.api.crateApiListCards();
+
+Future<void> saveCard({required String name, required String accessCode}) =>
+    RustLib.instance.api.crateApiSaveCard(name: name, accessCode: accessCode);
+
+Future<bool> removeCard({required String name}) =>
+    RustLib.instance.api.crateApiRemoveCard(name: name);
+
+Future<void> insertCard({required String name, required int durationMs}) =>
+    RustLib.instance.api.crateApiInsertCard(name: name, durationMs: durationMs);
+
+Future<void> setCardMode({required CardMode mode}) =>
+    RustLib.instance.api.crateApiSetCardMode(mode: mode);
+
+CardMode getCardMode() => RustLib.instance.api.crateApiGetCardMode();
+
+Future<void> insertCredits({required int count}) =>
+    RustLib.instance.api.crateApiInsertCredits(count: count);
+
+int pendingCredits() => RustLib.instance.api.crateApiPendingCredits();
+
+Future<void> setCoinCadence({required CoinCadence cadence}) =>
+    RustLib.instance.api.crateApiSetCoinCadence(cadence: cadence);
+
+CoinCadence getCoinCadence() => RustLib.instance.api.crateApiGetCoinCadence();
+
+Future<void> setAirGesture({required AirGestureConfig config}) =>
+    RustLib.instance.api.crateApiSetAirGesture(config: config);
+
+AirGestureConfig getAirGesture() =>
+    RustLib.instance.api.crateApiGetAirGesture();
+
+Future<void> setSliderProfile({
+  String? clientIp,
+  required SliderProfile profile,
+}) => RustLib.instance.api.crateApiSetSliderProfile(
+  clientIp: clientIp,
+  profile: profile,
+);
+
+SliderProfile getSliderProfile({String? clientIp}) =>
+    RustLib.instance.api.crateApiGetSliderProfile(clientIp: clientIp);
+
+Future<bool> clearSliderProfile({required String clientIp}) =>
+    RustLib.instance.api.crateApiClearSliderProfile(clientIp: clientIp);
+
+Future<void> setSliderFilter({required DebounceConfig config}) =>
+    RustLib.instance.api.crateApiSetSliderFilter(config: config);
+
+Future<void> setAirFilter({required DebounceConfig config}) =>
+    RustLib.instance.api.crateApiSetAirFilter(config: config);
+
+DebounceConfig getSliderFilter() =>
+    RustLib.instance.api.crateApiGetSliderFilter();
+
+DebounceConfig getAirFilter() => RustLib.instance.api.crateApiGetAirFilter();
+
+Future<void> setCellFilter({
+  required FilterTarget target,
+  required int cell,
+  DebounceConfig? config,
+}) => RustLib.instance.api.crateApiSetCellFilter(
+  target: target,
+  cell: cell,
+  config: config,
 );
 
-class SensorData {
-  final Uint8List air;
-  final Uint8List slider;
-  final int coin;
-  final int service;
-  final int test;
-  final U8Array10 code;
-
-  const SensorData({
-    required this.air,
-    required this.slider,
-    required this.coin,
-    required this.service,
-    required this.test,
-    required this.code,
+DebounceConfig getCellFilter({
+  required FilterTarget target,
+  required int cell,
+}) => RustLib.instance.api.crateApiGetCellFilter(target: target, cell: cell);
+
+FilterStats getFilterStats() => RustLib.instance.api.crateApiGetFilterStats();
+
+Future<void> resetFilterStats() =>
+    RustLib.instance.api.crateApiResetFilterStats();
+
+List<LatencyStats> getLatencyStats() =>
+    RustLib.instance.api.crateApiGetLatencyStats();
+
+Future<void> resetLatencyStats() =>
+    RustLib.instance.api.crateApiResetLatencyStats();
+
+Future<void> setFramingConfig({required FramingConfig config}) =>
+    RustLib.instance.api.crateApiSetFramingConfig(config: config);
+
+FramingConfig getFramingConfig() =>
+    RustLib.instance.api.crateApiGetFramingConfig();
+
+List<ConnectionStats> getConnectionStats() =>
+    RustLib.instance.api.crateApiGetConnectionStats();
+
+SensorData getState() => RustLib.instance.api.crateApiGetState();
+
+/// A server instance with its own port, shared-memory mapping and sensor stream.
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ServerHandle>>
+abstract class ServerHandle implements RustOpaqueInterface {
+  ServerConfig config();
+
+  Future<void> start();
+
+  Future<void> stop();
+
+  bool isRunning();
+
+  /// Adds a subscriber for input snapshots; earlier streams keep receiving until Dart closes them.
+  Stream<SensorData> createSensorStream();
+
+  Stream<ServerEvent> createEventStream();
+
+  SensorData getState();
+
+  /// Returns whether a sync was started; the outcome is reported on the event stream.
+  Future<bool> toggleSync();
+
+  /// URI for the client to scan; `hosts` defaults to this machine's addresses.
+  String getConnectionUri({List<String>? hosts, String? secret});
+
+  QrMatrix getConnectionQr({List<String>? hosts, String? secret});
+
+  LinkState getConnectionState();
+
+  List<ClientSession> getSessions();
+
+  Future<void> setLivenessConfig({required LivenessConfig config});
+
+  LivenessConfig getLivenessConfig();
+
+  SyncState getSyncState();
+
+  Future<void> setSyncConfig({required SyncConfig config});
+
+  SyncConfig getSyncConfig();
+
+  Future<void> insertCard({required String name, required int durationMs});
+
+  Future<void> insertCredits({required int count});
+
+  int pendingCredits();
+
+  Future<void> setCoinCadence({required CoinCadence cadence});
+
+  CoinCadence getCoinCadence();
+
+  Future<void> setCardMode({required CardMode mode});
+
+  CardMode getCardMode();
+
+  Future<void> setAirGesture({required AirGestureConfig config});
+
+  AirGestureConfig getAirGesture();
+
+  Future<void> setSliderProfile({
+    String? clientIp,
+    required SliderProfile profile,
   });
 
-  @override
-  int get hashCode =>
-      air.hashCode ^
-      slider.hashCode ^
-      coin.hashCode ^
-      service.hashCode ^
-      test.hashCode ^
-      code.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SensorData &&
-          runtimeType == other.runtimeType &&
-          air == other.air &&
-          slider == other.slider &&
-          coin == other.coin &&
-          service == other.service &&
-          test == other.test &&
-          code == other.code;
-}
+  SliderProfile getSliderProfile({String? clientIp});
+
+  Future<bool> clearSliderProfile({required String clientIp});
+
+  Future<void> setSliderFilter({required DebounceConfig config});
+
+  Future<void> setAirFilter({required DebounceConfig config});
+
+  DebounceConfig getSliderFilter();
+
+  DebounceConfig getAirFilter();
+
+  /// Overrides one slider (0..32) or air (0..6) cell; `None` restores the shared config.
+  Future<void> setCellFilter({
+    required FilterTarget target,
+    required int cell,
+    DebounceConfig? config,
+  });
+
+  DebounceConfig getCellFilter({
+    required FilterTarget target,
+    required int cell,
+  });
+
+  FilterStats getFilterStats();
+
+  Future<void> resetFilterStats();
+
+  List<LatencyStats> getLatencyStats();
 
-class U8Array10 extends NonGrowableListView<int> {
-  static const arraySize = 10;
+  Future<void> resetLatencyStats();
 
-  @internal
-  Uint8List get inner => _inner;
-  final Uint8List _inner;
+  Future<void> setFramingConfig({required FramingConfig config});
 
-  U8Array10(this._inner) : assert(_inner.length == arraySize), super(_inner);
+  FramingConfig getFramingConfig();
 
-  U8Array10.init() : this(Uint8List(arraySize));
+  List<ConnectionStats> getConnectionStats();
 }
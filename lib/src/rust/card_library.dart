@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class CardEntry {
+  final String name;
+  final String accessCode;
+
+  const CardEntry({
+    required this.name,
+    required this.accessCode,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ accessCode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CardEntry &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          accessCode == other.accessCode;
+}
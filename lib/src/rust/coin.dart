@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class CoinCadence {
+  final int pressMs;
+  final int releaseMs;
+
+  const CoinCadence({
+    required this.pressMs,
+    required this.releaseMs,
+  });
+
+  @override
+  int get hashCode => pressMs.hashCode ^ releaseMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CoinCadence &&
+          runtimeType == other.runtimeType &&
+          pressMs == other.pressMs &&
+          releaseMs == other.releaseMs;
+}
@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class DiscoveredServer {
+  final String name;
+  final List<String> addresses;
+  final int port;
+  final String transport;
+  final int version;
+
+  const DiscoveredServer({
+    required this.name,
+    required this.addresses,
+    required this.port,
+    required this.transport,
+    required this.version,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      addresses.hashCode ^
+      port.hashCode ^
+      transport.hashCode ^
+      version.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiscoveredServer &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          addresses == other.addresses &&
+          port == other.port &&
+          transport == other.transport &&
+          version == other.version;
+}
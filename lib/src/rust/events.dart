@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'liveness.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'state.dart';
+part 'events.freezed.dart';
+
+@freezed
+sealed class ServerEvent with _$ServerEvent {
+  const ServerEvent._();
+
+  const factory ServerEvent.input(
+    SensorData field0,
+  ) = ServerEvent_Input;
+  const factory ServerEvent.connected({
+    required String client,
+  }) = ServerEvent_Connected;
+  const factory ServerEvent.disconnected({
+    required String client,
+  }) = ServerEvent_Disconnected;
+  const factory ServerEvent.handshake({
+    required String? client,
+    required bool active,
+    required bool acknowledged,
+  }) = ServerEvent_Handshake;
+  const factory ServerEvent.error({
+    required String message,
+  }) = ServerEvent_Error;
+  const factory ServerEvent.syncPending({
+    required bool target,
+  }) = ServerEvent_SyncPending;
+  const factory ServerEvent.syncConfirmed({
+    required bool active,
+  }) = ServerEvent_SyncConfirmed;
+  /// The sync did not take effect as requested; `active` is the resulting state.
+  const factory ServerEvent.syncFailed({
+    required bool active,
+    required String reason,
+  }) = ServerEvent_SyncFailed;
+  const factory ServerEvent.linkChanged({
+    required String client,
+    required LinkState state,
+  }) = ServerEvent_LinkChanged;
+  /// Delivered to a subscriber that fell behind; `missed` older events were dropped for it.
+  const factory ServerEvent.lagged({
+    required BigInt missed,
+  }) = ServerEvent_Lagged;
+}
@@ -0,0 +1,896 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'events.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$ServerEvent {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ServerEvent()';
+}
+
+
+}
+
+/// @nodoc
+class $ServerEventCopyWith<$Res>  {
+$ServerEventCopyWith(ServerEvent _, $Res Function(ServerEvent) __);
+}
+
+
+
+/// Adds pattern-matching-related methods to [ServerEvent].
+extension ServerEventPatterns on ServerEvent {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( ServerEvent_Input value)?  input,TResult Function( ServerEvent_Connected value)?  connected,TResult Function( ServerEvent_Disconnected value)?  disconnected,TResult Function( ServerEvent_Handshake value)?  handshake,TResult Function( ServerEvent_Error value)?  error,TResult Function( ServerEvent_SyncPending value)?  syncPending,TResult Function( ServerEvent_SyncConfirmed value)?  syncConfirmed,TResult Function( ServerEvent_SyncFailed value)?  syncFailed,TResult Function( ServerEvent_LinkChanged value)?  linkChanged,TResult Function( ServerEvent_Lagged value)?  lagged,required TResult orElse(),){
+final _that = this;
+switch (_that) {
+case ServerEvent_Input() when input != null:
+return input(_that);case ServerEvent_Connected() when connected != null:
+return connected(_that);case ServerEvent_Disconnected() when disconnected != null:
+return disconnected(_that);case ServerEvent_Handshake() when handshake != null:
+return handshake(_that);case ServerEvent_Error() when error != null:
+return error(_that);case ServerEvent_SyncPending() when syncPending != null:
+return syncPending(_that);case ServerEvent_SyncConfirmed() when syncConfirmed != null:
+return syncConfirmed(_that);case ServerEvent_SyncFailed() when syncFailed != null:
+return syncFailed(_that);case ServerEvent_LinkChanged() when linkChanged != null:
+return linkChanged(_that);case ServerEvent_Lagged() when lagged != null:
+return lagged(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(required TResult Function( ServerEvent_Input value)  input,required TResult Function( ServerEvent_Connected value)  connected,required TResult Function( ServerEvent_Disconnected value)  disconnected,required TResult Function( ServerEvent_Handshake value)  handshake,required TResult Function( ServerEvent_Error value)  error,required TResult Function( ServerEvent_SyncPending value)  syncPending,required TResult Function( ServerEvent_SyncConfirmed value)  syncConfirmed,required TResult Function( ServerEvent_SyncFailed value)  syncFailed,required TResult Function( ServerEvent_LinkChanged value)  linkChanged,required TResult Function( ServerEvent_Lagged value)  lagged,){
+final _that = this;
+switch (_that) {
+case ServerEvent_Input():
+return input(_that);case ServerEvent_Connected():
+return connected(_that);case ServerEvent_Disconnected():
+return disconnected(_that);case ServerEvent_Handshake():
+return handshake(_that);case ServerEvent_Error():
+return error(_that);case ServerEvent_SyncPending():
+return syncPending(_that);case ServerEvent_SyncConfirmed():
+return syncConfirmed(_that);case ServerEvent_SyncFailed():
+return syncFailed(_that);case ServerEvent_LinkChanged():
+return linkChanged(_that);case ServerEvent_Lagged():
+return lagged(_that);case _:
+  throw StateError('Unexpected subclass');
+
+}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult Function( ServerEvent_Input value)?  input,TResult Function( ServerEvent_Connected value)?  connected,TResult Function( ServerEvent_Disconnected value)?  disconnected,TResult Function( ServerEvent_Handshake value)?  handshake,TResult Function( ServerEvent_Error value)?  error,TResult Function( ServerEvent_SyncPending value)?  syncPending,TResult Function( ServerEvent_SyncConfirmed value)?  syncConfirmed,TResult Function( ServerEvent_SyncFailed value)?  syncFailed,TResult Function( ServerEvent_LinkChanged value)?  linkChanged,TResult Function( ServerEvent_Lagged value)?  lagged,){
+final _that = this;
+switch (_that) {
+case ServerEvent_Input() when input != null:
+return input(_that);case ServerEvent_Connected() when connected != null:
+return connected(_that);case ServerEvent_Disconnected() when disconnected != null:
+return disconnected(_that);case ServerEvent_Handshake() when handshake != null:
+return handshake(_that);case ServerEvent_Error() when error != null:
+return error(_that);case ServerEvent_SyncPending() when syncPending != null:
+return syncPending(_that);case ServerEvent_SyncConfirmed() when syncConfirmed != null:
+return syncConfirmed(_that);case ServerEvent_SyncFailed() when syncFailed != null:
+return syncFailed(_that);case ServerEvent_LinkChanged() when linkChanged != null:
+return linkChanged(_that);case ServerEvent_Lagged() when lagged != null:
+return lagged(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( SensorData field0)?  input,TResult Function( String client)?  connected,TResult Function( String client)?  disconnected,TResult Function( String? client,  bool active,  bool acknowledged)?  handshake,TResult Function( String message)?  error,TResult Function( bool target)?  syncPending,TResult Function( bool active)?  syncConfirmed,TResult Function( bool active,  String reason)?  syncFailed,TResult Function( String client,  LinkState state)?  linkChanged,TResult Function( BigInt missed)?  lagged,required TResult orElse(),) {final _that = this;
+switch (_that) {
+case ServerEvent_Input() when input != null:
+return input(_that.field0);case ServerEvent_Connected() when connected != null:
+return connected(_that.client);case ServerEvent_Disconnected() when disconnected != null:
+return disconnected(_that.client);case ServerEvent_Handshake() when handshake != null:
+return handshake(_that.client,_that.active,_that.acknowledged);case ServerEvent_Error() when error != null:
+return error(_that.message);case ServerEvent_SyncPending() when syncPending != null:
+return syncPending(_that.target);case ServerEvent_SyncConfirmed() when syncConfirmed != null:
+return syncConfirmed(_that.active);case ServerEvent_SyncFailed() when syncFailed != null:
+return syncFailed(_that.active,_that.reason);case ServerEvent_LinkChanged() when linkChanged != null:
+return linkChanged(_that.client,_that.state);case ServerEvent_Lagged() when lagged != null:
+return lagged(_that.missed);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(required TResult Function( SensorData field0)  input,required TResult Function( String client)  connected,required TResult Function( String client)  disconnected,required TResult Function( String? client,  bool active,  bool acknowledged)  handshake,required TResult Function( String message)  error,required TResult Function( bool target)  syncPending,required TResult Function( bool active)  syncConfirmed,required TResult Function( bool active,  String reason)  syncFailed,required TResult Function( String client,  LinkState state)  linkChanged,required TResult Function( BigInt missed)  lagged,) {final _that = this;
+switch (_that) {
+case ServerEvent_Input():
+return input(_that.field0);case ServerEvent_Connected():
+return connected(_that.client);case ServerEvent_Disconnected():
+return disconnected(_that.client);case ServerEvent_Handshake():
+return handshake(_that.client,_that.active,_that.acknowledged);case ServerEvent_Error():
+return error(_that.message);case ServerEvent_SyncPending():
+return syncPending(_that.target);case ServerEvent_SyncConfirmed():
+return syncConfirmed(_that.active);case ServerEvent_SyncFailed():
+return syncFailed(_that.active,_that.reason);case ServerEvent_LinkChanged():
+return linkChanged(_that.client,_that.state);case ServerEvent_Lagged():
+return lagged(_that.missed);case _:
+  throw StateError('Unexpected subclass');
+
+}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult Function( SensorData field0)?  input,TResult Function( String client)?  connected,TResult Function( String client)?  disconnected,TResult Function( String? client,  bool active,  bool acknowledged)?  handshake,TResult Function( String message)?  error,TResult Function( bool target)?  syncPending,TResult Function( bool active)?  syncConfirmed,TResult Function( bool active,  String reason)?  syncFailed,TResult Function( String client,  LinkState state)?  linkChanged,TResult Function( BigInt missed)?  lagged,) {final _that = this;
+switch (_that) {
+case ServerEvent_Input() when input != null:
+return input(_that.field0);case ServerEvent_Connected() when connected != null:
+return connected(_that.client);case ServerEvent_Disconnected() when disconnected != null:
+return disconnected(_that.client);case ServerEvent_Handshake() when handshake != null:
+return handshake(_that.client,_that.active,_that.acknowledged);case ServerEvent_Error() when error != null:
+return error(_that.message);case ServerEvent_SyncPending() when syncPending != null:
+return syncPending(_that.target);case ServerEvent_SyncConfirmed() when syncConfirmed != null:
+return syncConfirmed(_that.active);case ServerEvent_SyncFailed() when syncFailed != null:
+return syncFailed(_that.active,_that.reason);case ServerEvent_LinkChanged() when linkChanged != null:
+return linkChanged(_that.client,_that.state);case ServerEvent_Lagged() when lagged != null:
+return lagged(_that.missed);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class ServerEvent_Input extends ServerEvent {
+  const ServerEvent_Input(this.field0): super._();
+  
+
+ final  SensorData field0;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ServerEvent_InputCopyWith<ServerEvent_Input> get copyWith => _$ServerEvent_InputCopyWithImpl<ServerEvent_Input>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent_Input&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ServerEvent.input(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ServerEvent_InputCopyWith<$Res> implements $ServerEventCopyWith<$Res> {
+  factory $ServerEvent_InputCopyWith(ServerEvent_Input value, $Res Function(ServerEvent_Input) _then) = _$ServerEvent_InputCopyWithImpl;
+@useResult
+$Res call({
+ SensorData field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ServerEvent_InputCopyWithImpl<$Res>
+    implements $ServerEvent_InputCopyWith<$Res> {
+  _$ServerEvent_InputCopyWithImpl(this._self, this._then);
+
+  final ServerEvent_Input _self;
+  final $Res Function(ServerEvent_Input) _then;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ServerEvent_Input(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as SensorData,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ServerEvent_Connected extends ServerEvent {
+  const ServerEvent_Connected({required this.client}): super._();
+  
+
+ final  String client;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ServerEvent_ConnectedCopyWith<ServerEvent_Connected> get copyWith => _$ServerEvent_ConnectedCopyWithImpl<ServerEvent_Connected>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent_Connected&&(identical(other.client, client) || other.client == client));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,client);
+
+@override
+String toString() {
+  return 'ServerEvent.connected(client: $client)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ServerEvent_ConnectedCopyWith<$Res> implements $ServerEventCopyWith<$Res> {
+  factory $ServerEvent_ConnectedCopyWith(ServerEvent_Connected value, $Res Function(ServerEvent_Connected) _then) = _$ServerEvent_ConnectedCopyWithImpl;
+@useResult
+$Res call({
+ String client
+});
+
+
+
+
+}
+/// @nodoc
+class _$ServerEvent_ConnectedCopyWithImpl<$Res>
+    implements $ServerEvent_ConnectedCopyWith<$Res> {
+  _$ServerEvent_ConnectedCopyWithImpl(this._self, this._then);
+
+  final ServerEvent_Connected _self;
+  final $Res Function(ServerEvent_Connected) _then;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? client = null,}) {
+  return _then(ServerEvent_Connected(
+client: null == client ? _self.client : client // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ServerEvent_Disconnected extends ServerEvent {
+  const ServerEvent_Disconnected({required this.client}): super._();
+  
+
+ final  String client;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ServerEvent_DisconnectedCopyWith<ServerEvent_Disconnected> get copyWith => _$ServerEvent_DisconnectedCopyWithImpl<ServerEvent_Disconnected>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent_Disconnected&&(identical(other.client, client) || other.client == client));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,client);
+
+@override
+String toString() {
+  return 'ServerEvent.disconnected(client: $client)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ServerEvent_DisconnectedCopyWith<$Res> implements $ServerEventCopyWith<$Res> {
+  factory $ServerEvent_DisconnectedCopyWith(ServerEvent_Disconnected value, $Res Function(ServerEvent_Disconnected) _then) = _$ServerEvent_DisconnectedCopyWithImpl;
+@useResult
+$Res call({
+ String client
+});
+
+
+
+
+}
+/// @nodoc
+class _$ServerEvent_DisconnectedCopyWithImpl<$Res>
+    implements $ServerEvent_DisconnectedCopyWith<$Res> {
+  _$ServerEvent_DisconnectedCopyWithImpl(this._self, this._then);
+
+  final ServerEvent_Disconnected _self;
+  final $Res Function(ServerEvent_Disconnected) _then;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? client = null,}) {
+  return _then(ServerEvent_Disconnected(
+client: null == client ? _self.client : client // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ServerEvent_Handshake extends ServerEvent {
+  const ServerEvent_Handshake({required this.client, required this.active, required this.acknowledged}): super._();
+  
+
+ final  String? client;
+ final  bool active;
+ final  bool acknowledged;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ServerEvent_HandshakeCopyWith<ServerEvent_Handshake> get copyWith => _$ServerEvent_HandshakeCopyWithImpl<ServerEvent_Handshake>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent_Handshake&&(identical(other.client, client) || other.client == client)&&(identical(other.active, active) || other.active == active)&&(identical(other.acknowledged, acknowledged) || other.acknowledged == acknowledged));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,client,active,acknowledged);
+
+@override
+String toString() {
+  return 'ServerEvent.handshake(client: $client, active: $active, acknowledged: $acknowledged)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ServerEvent_HandshakeCopyWith<$Res> implements $ServerEventCopyWith<$Res> {
+  factory $ServerEvent_HandshakeCopyWith(ServerEvent_Handshake value, $Res Function(ServerEvent_Handshake) _then) = _$ServerEvent_HandshakeCopyWithImpl;
+@useResult
+$Res call({
+ String? client, bool active, bool acknowledged
+});
+
+
+
+
+}
+/// @nodoc
+class _$ServerEvent_HandshakeCopyWithImpl<$Res>
+    implements $ServerEvent_HandshakeCopyWith<$Res> {
+  _$ServerEvent_HandshakeCopyWithImpl(this._self, this._then);
+
+  final ServerEvent_Handshake _self;
+  final $Res Function(ServerEvent_Handshake) _then;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? client = freezed,Object? active = null,Object? acknowledged = null,}) {
+  return _then(ServerEvent_Handshake(
+client: freezed == client ? _self.client : client // ignore: cast_nullable_to_non_nullable
+as String?,active: null == active ? _self.active : active // ignore: cast_nullable_to_non_nullable
+as bool,acknowledged: null == acknowledged ? _self.acknowledged : acknowledged // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ServerEvent_Error extends ServerEvent {
+  const ServerEvent_Error({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ServerEvent_ErrorCopyWith<ServerEvent_Error> get copyWith => _$ServerEvent_ErrorCopyWithImpl<ServerEvent_Error>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent_Error&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'ServerEvent.error(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ServerEvent_ErrorCopyWith<$Res> implements $ServerEventCopyWith<$Res> {
+  factory $ServerEvent_ErrorCopyWith(ServerEvent_Error value, $Res Function(ServerEvent_Error) _then) = _$ServerEvent_ErrorCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$ServerEvent_ErrorCopyWithImpl<$Res>
+    implements $ServerEvent_ErrorCopyWith<$Res> {
+  _$ServerEvent_ErrorCopyWithImpl(this._self, this._then);
+
+  final ServerEvent_Error _self;
+  final $Res Function(ServerEvent_Error) _then;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(ServerEvent_Error(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ServerEvent_SyncPending extends ServerEvent {
+  const ServerEvent_SyncPending({required this.target}): super._();
+  
+
+ final  bool target;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ServerEvent_SyncPendingCopyWith<ServerEvent_SyncPending> get copyWith => _$ServerEvent_SyncPendingCopyWithImpl<ServerEvent_SyncPending>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent_SyncPending&&(identical(other.target, target) || other.target == target));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,target);
+
+@override
+String toString() {
+  return 'ServerEvent.syncPending(target: $target)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ServerEvent_SyncPendingCopyWith<$Res> implements $ServerEventCopyWith<$Res> {
+  factory $ServerEvent_SyncPendingCopyWith(ServerEvent_SyncPending value, $Res Function(ServerEvent_SyncPending) _then) = _$ServerEvent_SyncPendingCopyWithImpl;
+@useResult
+$Res call({
+ bool target
+});
+
+
+
+
+}
+/// @nodoc
+class _$ServerEvent_SyncPendingCopyWithImpl<$Res>
+    implements $ServerEvent_SyncPendingCopyWith<$Res> {
+  _$ServerEvent_SyncPendingCopyWithImpl(this._self, this._then);
+
+  final ServerEvent_SyncPending _self;
+  final $Res Function(ServerEvent_SyncPending) _then;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? target = null,}) {
+  return _then(ServerEvent_SyncPending(
+target: null == target ? _self.target : target // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ServerEvent_SyncConfirmed extends ServerEvent {
+  const ServerEvent_SyncConfirmed({required this.active}): super._();
+  
+
+ final  bool active;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ServerEvent_SyncConfirmedCopyWith<ServerEvent_SyncConfirmed> get copyWith => _$ServerEvent_SyncConfirmedCopyWithImpl<ServerEvent_SyncConfirmed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent_SyncConfirmed&&(identical(other.active, active) || other.active == active));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,active);
+
+@override
+String toString() {
+  return 'ServerEvent.syncConfirmed(active: $active)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ServerEvent_SyncConfirmedCopyWith<$Res> implements $ServerEventCopyWith<$Res> {
+  factory $ServerEvent_SyncConfirmedCopyWith(ServerEvent_SyncConfirmed value, $Res Function(ServerEvent_SyncConfirmed) _then) = _$ServerEvent_SyncConfirmedCopyWithImpl;
+@useResult
+$Res call({
+ bool active
+});
+
+
+
+
+}
+/// @nodoc
+class _$ServerEvent_SyncConfirmedCopyWithImpl<$Res>
+    implements $ServerEvent_SyncConfirmedCopyWith<$Res> {
+  _$ServerEvent_SyncConfirmedCopyWithImpl(this._self, this._then);
+
+  final ServerEvent_SyncConfirmed _self;
+  final $Res Function(ServerEvent_SyncConfirmed) _then;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? active = null,}) {
+  return _then(ServerEvent_SyncConfirmed(
+active: null == active ? _self.active : active // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ServerEvent_SyncFailed extends ServerEvent {
+  const ServerEvent_SyncFailed({required this.active, required this.reason}): super._();
+  
+
+ final  bool active;
+ final  String reason;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ServerEvent_SyncFailedCopyWith<ServerEvent_SyncFailed> get copyWith => _$ServerEvent_SyncFailedCopyWithImpl<ServerEvent_SyncFailed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent_SyncFailed&&(identical(other.active, active) || other.active == active)&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,active,reason);
+
+@override
+String toString() {
+  return 'ServerEvent.syncFailed(active: $active, reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ServerEvent_SyncFailedCopyWith<$Res> implements $ServerEventCopyWith<$Res> {
+  factory $ServerEvent_SyncFailedCopyWith(ServerEvent_SyncFailed value, $Res Function(ServerEvent_SyncFailed) _then) = _$ServerEvent_SyncFailedCopyWithImpl;
+@useResult
+$Res call({
+ bool active, String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$ServerEvent_SyncFailedCopyWithImpl<$Res>
+    implements $ServerEvent_SyncFailedCopyWith<$Res> {
+  _$ServerEvent_SyncFailedCopyWithImpl(this._self, this._then);
+
+  final ServerEvent_SyncFailed _self;
+  final $Res Function(ServerEvent_SyncFailed) _then;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? active = null,Object? reason = null,}) {
+  return _then(ServerEvent_SyncFailed(
+active: null == active ? _self.active : active // ignore: cast_nullable_to_non_nullable
+as bool,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ServerEvent_LinkChanged extends ServerEvent {
+  const ServerEvent_LinkChanged({required this.client, required this.state}): super._();
+  
+
+ final  String client;
+ final  LinkState state;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ServerEvent_LinkChangedCopyWith<ServerEvent_LinkChanged> get copyWith => _$ServerEvent_LinkChangedCopyWithImpl<ServerEvent_LinkChanged>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent_LinkChanged&&(identical(other.client, client) || other.client == client)&&(identical(other.state, state) || other.state == state));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,client,state);
+
+@override
+String toString() {
+  return 'ServerEvent.linkChanged(client: $client, state: $state)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ServerEvent_LinkChangedCopyWith<$Res> implements $ServerEventCopyWith<$Res> {
+  factory $ServerEvent_LinkChangedCopyWith(ServerEvent_LinkChanged value, $Res Function(ServerEvent_LinkChanged) _then) = _$ServerEvent_LinkChangedCopyWithImpl;
+@useResult
+$Res call({
+ String client, LinkState state
+});
+
+
+
+
+}
+/// @nodoc
+class _$ServerEvent_LinkChangedCopyWithImpl<$Res>
+    implements $ServerEvent_LinkChangedCopyWith<$Res> {
+  _$ServerEvent_LinkChangedCopyWithImpl(this._self, this._then);
+
+  final ServerEvent_LinkChanged _self;
+  final $Res Function(ServerEvent_LinkChanged) _then;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? client = null,Object? state = null,}) {
+  return _then(ServerEvent_LinkChanged(
+client: null == client ? _self.client : client // ignore: cast_nullable_to_non_nullable
+as String,state: null == state ? _self.state : state // ignore: cast_nullable_to_non_nullable
+as LinkState,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ServerEvent_Lagged extends ServerEvent {
+  const ServerEvent_Lagged({required this.missed}): super._();
+  
+
+ final  BigInt missed;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ServerEvent_LaggedCopyWith<ServerEvent_Lagged> get copyWith => _$ServerEvent_LaggedCopyWithImpl<ServerEvent_Lagged>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ServerEvent_Lagged&&(identical(other.missed, missed) || other.missed == missed));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,missed);
+
+@override
+String toString() {
+  return 'ServerEvent.lagged(missed: $missed)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ServerEvent_LaggedCopyWith<$Res> implements $ServerEventCopyWith<$Res> {
+  factory $ServerEvent_LaggedCopyWith(ServerEvent_Lagged value, $Res Function(ServerEvent_Lagged) _then) = _$ServerEvent_LaggedCopyWithImpl;
+@useResult
+$Res call({
+ BigInt missed
+});
+
+
+
+
+}
+/// @nodoc
+class _$ServerEvent_LaggedCopyWithImpl<$Res>
+    implements $ServerEvent_LaggedCopyWith<$Res> {
+  _$ServerEvent_LaggedCopyWithImpl(this._self, this._then);
+
+  final ServerEvent_Lagged _self;
+  final $Res Function(ServerEvent_Lagged) _then;
+
+/// Create a copy of ServerEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? missed = null,}) {
+  return _then(ServerEvent_Lagged(
+missed: null == missed ? _self.missed : missed // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class DebounceConfig {
+  final bool enabled;
+  /// A press must be held this long before a release is accepted.
+  final int minPressMs;
+  /// A release must last this long before a new press is accepted.
+  final int minReleaseMs;
+  /// Raw value at which a released cell becomes pressed.
+  final int pressThreshold;
+  /// Raw value below which a pressed cell becomes released; values between the
+  /// two thresholds keep the cell's current state.
+  final int releaseThreshold;
+
+  const DebounceConfig({
+    required this.enabled,
+    required this.minPressMs,
+    required this.minReleaseMs,
+    required this.pressThreshold,
+    required this.releaseThreshold,
+  });
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^
+      minPressMs.hashCode ^
+      minReleaseMs.hashCode ^
+      pressThreshold.hashCode ^
+      releaseThreshold.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DebounceConfig &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          minPressMs == other.minPressMs &&
+          minReleaseMs == other.minReleaseMs &&
+          pressThreshold == other.pressThreshold &&
+          releaseThreshold == other.releaseThreshold;
+}
+
+class FilterStats {
+  final BigInt sliderSuppressed;
+  final BigInt airSuppressed;
+
+  const FilterStats({
+    required this.sliderSuppressed,
+    required this.airSuppressed,
+  });
+
+  @override
+  int get hashCode => sliderSuppressed.hashCode ^ airSuppressed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FilterStats &&
+          runtimeType == other.runtimeType &&
+          sliderSuppressed == other.sliderSuppressed &&
+          airSuppressed == other.airSuppressed;
+}
+
+enum FilterTarget {
+  slider,
+  air,
+  ;
+}
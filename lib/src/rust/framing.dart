@@ -0,0 +1,101 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Per-connection counters.
+class ConnectionStats {
+  final String client;
+  final String transport;
+  final bool connected;
+  final FramingStats framing;
+
+  const ConnectionStats({
+    required this.client,
+    required this.transport,
+    required this.connected,
+    required this.framing,
+  });
+
+  @override
+  int get hashCode =>
+      client.hashCode ^
+      transport.hashCode ^
+      connected.hashCode ^
+      framing.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConnectionStats &&
+          runtimeType == other.runtimeType &&
+          client == other.client &&
+          transport == other.transport &&
+          connected == other.connected &&
+          framing == other.framing;
+}
+
+class FramingConfig {
+  /// Largest payload accepted from a length prefix.
+  final int maxFrame;
+  final FramingPolicy policy;
+
+  const FramingConfig({
+    required this.maxFrame,
+    required this.policy,
+  });
+
+  @override
+  int get hashCode => maxFrame.hashCode ^ policy.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FramingConfig &&
+          runtimeType == other.runtimeType &&
+          maxFrame == other.maxFrame &&
+          policy == other.policy;
+}
+
+enum FramingPolicy {
+  /// Close the connection on the first framing error.
+  disconnect,
+  /// Skip to the next `FRAME_MAGIC` and only accept marked frames until one is found.
+  resync,
+  ;
+}
+
+class FramingStats {
+  final BigInt frames;
+  /// Length prefixes above `max_frame`.
+  final BigInt oversized;
+  final BigInt resyncs;
+  final BigInt discardedBytes;
+
+  const FramingStats({
+    required this.frames,
+    required this.oversized,
+    required this.resyncs,
+    required this.discardedBytes,
+  });
+
+  @override
+  int get hashCode =>
+      frames.hashCode ^
+      oversized.hashCode ^
+      resyncs.hashCode ^
+      discardedBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FramingStats &&
+          runtimeType == other.runtimeType &&
+          frames == other.frames &&
+          oversized == other.oversized &&
+          resyncs == other.resyncs &&
+          discardedBytes == other.discardedBytes;
+}
@@ -0,0 +1,5891 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
+
+import 'api.dart';
+import 'card_library.dart';
+import 'coin.dart';
+import 'dart:async';
+import 'dart:convert';
+import 'discovery.dart';
+import 'events.dart';
+import 'filter.dart';
+import 'framing.dart';
+import 'frb_generated.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'gesture.dart';
+import 'latency.dart';
+import 'liveness.dart';
+import 'logging.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'pairing.dart';
+import 'protocol.dart';
+import 'remap.dart';
+import 'server.dart';
+import 'state.dart';
+import 'sync.dart';
+
+/// Main entrypoint of the Rust API
+class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+  @internal
+  static final instance = RustLib._();
+
+  RustLib._();
+
+  /// Initialize flutter_rust_bridge
+  static Future<void> init({
+    RustLibApi? api,
+    BaseHandler? handler,
+    ExternalLibrary? externalLibrary,
+    bool forceSameCodegenVersion = true,
+  }) async {
+    await instance.initImpl(
+      api: api,
+      handler: handler,
+      externalLibrary: externalLibrary,
+      forceSameCodegenVersion: forceSameCodegenVersion,
+    );
+  }
+
+  /// Initialize flutter_rust_bridge in mock mode.
+  /// No libraries for FFI are loaded.
+  static void initMock({required RustLibApi api}) {
+    instance.initMockImpl(api: api);
+  }
+
+  /// Dispose flutter_rust_bridge
+  ///
+  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+  /// is automatically disposed when the app stops.
+  static void dispose() => instance.disposeImpl();
+
+  @override
+  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
+      RustLibApiImpl.new;
+
+  @override
+  WireConstructor<RustLibWire> get wireConstructor =>
+      RustLibWire.fromExternalLibrary;
+
+  @override
+  Future<void> executeRustInitializers() async {
+    await api.crateApiInitApp();
+  }
+
+  @override
+  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
+      kDefaultExternalLibraryLoaderConfig;
+
+  @override
+  String get codegenVersion => '2.12.0';
+
+  @override
+  int get rustContentHash => -1711316986;
+
+  static const kDefaultExternalLibraryLoaderConfig =
+      ExternalLibraryLoaderConfig(
+        stem: 'rustnithm_native',
+        ioDirectory: 'native/target/release/',
+        webPrefix: 'pkg/',
+      );
+}
+
+abstract class RustLibApi extends BaseApi {
+  Future<bool> crateApiServerHandleClearSliderProfile({
+    required ServerHandle that,
+    required String clientIp,
+  });
+
+  ServerConfig crateApiServerHandleConfig({required ServerHandle that});
+
+  Stream<ServerEvent> crateApiServerHandleCreateEventStream({
+    required ServerHandle that,
+  });
+
+  Stream<SensorData> crateApiServerHandleCreateSensorStream({
+    required ServerHandle that,
+  });
+
+  DebounceConfig crateApiServerHandleGetAirFilter({required ServerHandle that});
+
+  AirGestureConfig crateApiServerHandleGetAirGesture({
+    required ServerHandle that,
+  });
+
+  CardMode crateApiServerHandleGetCardMode({required ServerHandle that});
+
+  DebounceConfig crateApiServerHandleGetCellFilter({
+    required ServerHandle that,
+    required FilterTarget target,
+    required int cell,
+  });
+
+  CoinCadence crateApiServerHandleGetCoinCadence({required ServerHandle that});
+
+  QrMatrix crateApiServerHandleGetConnectionQr({
+    required ServerHandle that,
+    List<String>? hosts,
+    String? secret,
+  });
+
+  LinkState crateApiServerHandleGetConnectionState({
+    required ServerHandle that,
+  });
+
+  List<ConnectionStats> crateApiServerHandleGetConnectionStats({
+    required ServerHandle that,
+  });
+
+  String crateApiServerHandleGetConnectionUri({
+    required ServerHandle that,
+    List<String>? hosts,
+    String? secret,
+  });
+
+  FilterStats crateApiServerHandleGetFilterStats({required ServerHandle that});
+
+  FramingConfig crateApiServerHandleGetFramingConfig({
+    required ServerHandle that,
+  });
+
+  List<LatencyStats> crateApiServerHandleGetLatencyStats({
+    required ServerHandle that,
+  });
+
+  LivenessConfig crateApiServerHandleGetLivenessConfig({
+    required ServerHandle that,
+  });
+
+  List<ClientSession> crateApiServerHandleGetSessions({
+    required ServerHandle that,
+  });
+
+  DebounceConfig crateApiServerHandleGetSliderFilter({
+    required ServerHandle that,
+  });
+
+  SliderProfile crateApiServerHandleGetSliderProfile({
+    required ServerHandle that,
+    String? clientIp,
+  });
+
+  SensorData crateApiServerHandleGetState({required ServerHandle that});
+
+  SyncConfig crateApiServerHandleGetSyncConfig({required ServerHandle that});
+
+  SyncState crateApiServerHandleGetSyncState({required ServerHandle that});
+
+  Future<void> crateApiServerHandleInsertCard({
+    required ServerHandle that,
+    required String name,
+    required int durationMs,
+  });
+
+  Future<void> crateApiServerHandleInsertCredits({
+    required ServerHandle that,
+    required int count,
+  });
+
+  bool crateApiServerHandleIsRunning({required ServerHandle that});
+
+  int crateApiServerHandlePendingCredits({required ServerHandle that});
+
+  Future<void> crateApiServerHandleResetFilterStats({
+    required ServerHandle that,
+  });
+
+  Future<void> crateApiServerHandleResetLatencyStats({
+    required ServerHandle that,
+  });
+
+  Future<void> crateApiServerHandleSetAirFilter({
+    required ServerHandle that,
+    required DebounceConfig config,
+  });
+
+  Future<void> crateApiServerHandleSetAirGesture({
+    required ServerHandle that,
+    required AirGestureConfig config,
+  });
+
+  Future<void> crateApiServerHandleSetCardMode({
+    required ServerHandle that,
+    required CardMode mode,
+  });
+
+  Future<void> crateApiServerHandleSetCellFilter({
+    required ServerHandle that,
+    required FilterTarget target,
+    required int cell,
+    DebounceConfig? config,
+  });
+
+  Future<void> crateApiServerHandleSetCoinCadence({
+    required ServerHandle that,
+    required CoinCadence cadence,
+  });
+
+  Future<void> crateApiServerHandleSetFramingConfig({
+    required ServerHandle that,
+    required FramingConfig config,
+  });
+
+  Future<void> crateApiServerHandleSetLivenessConfig({
+    required ServerHandle that,
+    required LivenessConfig config,
+  });
+
+  Future<void> crateApiServerHandleSetSliderFilter({
+    required ServerHandle that,
+    required DebounceConfig config,
+  });
+
+  Future<void> crateApiServerHandleSetSliderProfile({
+    required ServerHandle that,
+    String? clientIp,
+    required SliderProfile profile,
+  });
+
+  Future<void> crateApiServerHandleSetSyncConfig({
+    required ServerHandle that,
+    required SyncConfig config,
+  });
+
+  Future<void> crateApiServerHandleStart({required ServerHandle that});
+
+  Future<void> crateApiServerHandleStop({required ServerHandle that});
+
+  Future<bool> crateApiServerHandleToggleSync({required ServerHandle that});
+
+  Future<void> crateApiClearLogs();
+
+  Future<bool> crateApiClearSliderProfile({required String clientIp});
+
+  Stream<ServerEvent> crateApiCreateEventStream();
+
+  Stream<LogEntry> crateApiCreateLogStream();
+
+  Stream<SensorData> crateApiCreateSensorStream();
+
+  Future<ServerHandle> crateApiCreateServer({required ServerConfig config});
+
+  Future<List<DiscoveredServer>> crateApiDiscoverServers({
+    required int timeoutMs,
+  });
+
+  String crateApiGeneratePairingSecret();
+
+  DebounceConfig crateApiGetAirFilter();
+
+  AirGestureConfig crateApiGetAirGesture();
+
+  CardMode crateApiGetCardMode();
+
+  DebounceConfig crateApiGetCellFilter({
+    required FilterTarget target,
+    required int cell,
+  });
+
+  CoinCadence crateApiGetCoinCadence();
+
+  QrMatrix crateApiGetConnectionQr({List<String>? hosts, String? secret});
+
+  LinkState crateApiGetConnectionState();
+
+  List<ConnectionStats> crateApiGetConnectionStats();
+
+  String crateApiGetConnectionUri({List<String>? hosts, String? secret});
+
+  FilterStats crateApiGetFilterStats();
+
+  FramingConfig crateApiGetFramingConfig();
+
+  List<LatencyStats> crateApiGetLatencyStats();
+
+  LivenessConfig crateApiGetLivenessConfig();
+
+  LogLevel crateApiGetLogLevel();
+
+  List<LogEntry> crateApiGetRecentLogs({required int limit});
+
+  List<ClientSession> crateApiGetSessions();
+
+  DebounceConfig crateApiGetSliderFilter();
+
+  SliderProfile crateApiGetSliderProfile({String? clientIp});
+
+  SensorData crateApiGetState();
+
+  SyncConfig crateApiGetSyncConfig();
+
+  SyncState crateApiGetSyncState();
+
+  Future<void> crateApiHandleHandshake({required HandshakePayload incoming});
+
+  Future<void> crateApiInitApp();
+
+  Future<List<CardEntry>> crateApiInitCardLibrary({required String path});
+
+  Future<void> crateApiInitLastIp({required String ip});
+
+  Future<void> crateApiInsertCard({
+    required String name,
+    required int durationMs,
+  });
+
+  Future<void> crateApiInsertCredits({required int count});
+
+  bool crateApiIsDiscoveryEnabled();
+
+  List<CardEntry> crateApiListCards();
+
+  int crateApiPendingCredits();
+
+  Future<bool> crateApiRemoveCard({required String name});
+
+  Future<void> crateApiResetFilterStats();
+
+  Future<void> crateApiResetLatencyStats();
+
+  Future<void> crateApiSaveCard({
+    required String name,
+    required String accessCode,
+  });
+
+  Future<void> crateApiSetAirFilter({required DebounceConfig config});
+
+  Future<void> crateApiSetAirGesture({required AirGestureConfig config});
+
+  Future<void> crateApiSetCardMode({required CardMode mode});
+
+  Future<void> crateApiSetCellFilter({
+    required FilterTarget target,
+    required int cell,
+    DebounceConfig? config,
+  });
+
+  Future<void> crateApiSetCoinCadence({required CoinCadence cadence});
+
+  Future<void> crateApiSetDiscoveryEnabled({required bool enabled});
+
+  Future<void> crateApiSetFramingConfig({required FramingConfig config});
+
+  Future<void> crateApiSetLivenessConfig({required LivenessConfig config});
+
+  Future<void> crateApiSetLogFile({String? path});
+
+  Future<void> crateApiSetLogLevel({required LogLevel level});
+
+  Future<void> crateApiSetSliderFilter({required DebounceConfig config});
+
+  Future<void> crateApiSetSliderProfile({
+    String? clientIp,
+    required SliderProfile profile,
+  });
+
+  Future<void> crateApiSetSyncConfig({required SyncConfig config});
+
+  Future<void> crateApiSyncToShmem({
+    required List<int> air,
+    required List<int> slider,
+    required int coin,
+    required int service,
+    required int test,
+  });
+
+  Future<bool> crateApiToggleServer({required int port, required bool isUdp});
+
+  Future<bool> crateApiToggleSync();
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ServerHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ServerHandle;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ServerHandlePtr;
+}
+
+class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+  RustLibApiImpl({
+    required super.handler,
+    required super.wire,
+    required super.generalizedFrbRustBinding,
+    required super.portManager,
+  });
+
+  @override
+  Future<bool> crateApiServerHandleClearSliderProfile({
+    required ServerHandle that,
+    required String clientIp,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_String(clientIp, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleClearSliderProfileConstMeta,
+        argValues: [that, clientIp],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleClearSliderProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_clear_slider_profile",
+        argNames: ["that", "clientIp"],
+      );
+
+  @override
+  ServerConfig crateApiServerHandleConfig({required ServerHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_server_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleConfigConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_config",
+        argNames: ["that"],
+      );
+
+  @override
+  Stream<ServerEvent> crateApiServerHandleCreateEventStream({
+    required ServerHandle that,
+  }) {
+    final sink = RustStreamSink<ServerEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_server_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 3,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiServerHandleCreateEventStreamConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiServerHandleCreateEventStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_create_event_stream",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Stream<SensorData> crateApiServerHandleCreateSensorStream({
+    required ServerHandle that,
+  }) {
+    final sink = RustStreamSink<SensorData>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_sensor_data_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 4,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiServerHandleCreateSensorStreamConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiServerHandleCreateSensorStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_create_sensor_stream",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  DebounceConfig crateApiServerHandleGetAirFilter({
+    required ServerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_debounce_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetAirFilterConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetAirFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_air_filter",
+        argNames: ["that"],
+      );
+
+  @override
+  AirGestureConfig crateApiServerHandleGetAirGesture({
+    required ServerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_air_gesture_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetAirGestureConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetAirGestureConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_air_gesture",
+        argNames: ["that"],
+      );
+
+  @override
+  CardMode crateApiServerHandleGetCardMode({required ServerHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_card_mode,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetCardModeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetCardModeConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_card_mode",
+        argNames: ["that"],
+      );
+
+  @override
+  DebounceConfig crateApiServerHandleGetCellFilter({
+    required ServerHandle that,
+    required FilterTarget target,
+    required int cell,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_filter_target(target, serializer);
+          sse_encode_u_8(cell, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_debounce_config,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleGetCellFilterConstMeta,
+        argValues: [that, target, cell],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetCellFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_cell_filter",
+        argNames: ["that", "target", "cell"],
+      );
+
+  @override
+  CoinCadence crateApiServerHandleGetCoinCadence({required ServerHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_coin_cadence,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetCoinCadenceConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetCoinCadenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_coin_cadence",
+        argNames: ["that"],
+      );
+
+  @override
+  QrMatrix crateApiServerHandleGetConnectionQr({
+    required ServerHandle that,
+    List<String>? hosts,
+    String? secret,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_list_String(hosts, serializer);
+          sse_encode_opt_String(secret, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_qr_matrix,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleGetConnectionQrConstMeta,
+        argValues: [that, hosts, secret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetConnectionQrConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_connection_qr",
+        argNames: ["that", "hosts", "secret"],
+      );
+
+  @override
+  LinkState crateApiServerHandleGetConnectionState({
+    required ServerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_link_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetConnectionStateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetConnectionStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_connection_state",
+        argNames: ["that"],
+      );
+
+  @override
+  List<ConnectionStats> crateApiServerHandleGetConnectionStats({
+    required ServerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_connection_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetConnectionStatsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetConnectionStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_connection_stats",
+        argNames: ["that"],
+      );
+
+  @override
+  String crateApiServerHandleGetConnectionUri({
+    required ServerHandle that,
+    List<String>? hosts,
+    String? secret,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_list_String(hosts, serializer);
+          sse_encode_opt_String(secret, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleGetConnectionUriConstMeta,
+        argValues: [that, hosts, secret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetConnectionUriConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_connection_uri",
+        argNames: ["that", "hosts", "secret"],
+      );
+
+  @override
+  FilterStats crateApiServerHandleGetFilterStats({required ServerHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_filter_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetFilterStatsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetFilterStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_filter_stats",
+        argNames: ["that"],
+      );
+
+  @override
+  FramingConfig crateApiServerHandleGetFramingConfig({
+    required ServerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_framing_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetFramingConfigConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetFramingConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_framing_config",
+        argNames: ["that"],
+      );
+
+  @override
+  List<LatencyStats> crateApiServerHandleGetLatencyStats({
+    required ServerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_latency_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetLatencyStatsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetLatencyStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_latency_stats",
+        argNames: ["that"],
+      );
+
+  @override
+  LivenessConfig crateApiServerHandleGetLivenessConfig({
+    required ServerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_liveness_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetLivenessConfigConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetLivenessConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_liveness_config",
+        argNames: ["that"],
+      );
+
+  @override
+  List<ClientSession> crateApiServerHandleGetSessions({
+    required ServerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_client_session,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetSessionsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetSessionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_sessions",
+        argNames: ["that"],
+      );
+
+  @override
+  DebounceConfig crateApiServerHandleGetSliderFilter({
+    required ServerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_debounce_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetSliderFilterConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetSliderFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_slider_filter",
+        argNames: ["that"],
+      );
+
+  @override
+  SliderProfile crateApiServerHandleGetSliderProfile({
+    required ServerHandle that,
+    String? clientIp,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_String(clientIp, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_slider_profile,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleGetSliderProfileConstMeta,
+        argValues: [that, clientIp],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetSliderProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_slider_profile",
+        argNames: ["that", "clientIp"],
+      );
+
+  @override
+  SensorData crateApiServerHandleGetState({required ServerHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sensor_data,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetStateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_state",
+        argNames: ["that"],
+      );
+
+  @override
+  SyncConfig crateApiServerHandleGetSyncConfig({required ServerHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetSyncConfigConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetSyncConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_sync_config",
+        argNames: ["that"],
+      );
+
+  @override
+  SyncState crateApiServerHandleGetSyncState({required ServerHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleGetSyncStateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleGetSyncStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_get_sync_state",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleInsertCard({
+    required ServerHandle that,
+    required String name,
+    required int durationMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_String(name, serializer);
+          sse_encode_u_32(durationMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleInsertCardConstMeta,
+        argValues: [that, name, durationMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleInsertCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_insert_card",
+        argNames: ["that", "name", "durationMs"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleInsertCredits({
+    required ServerHandle that,
+    required int count,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(count, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleInsertCreditsConstMeta,
+        argValues: [that, count],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleInsertCreditsConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_insert_credits",
+        argNames: ["that", "count"],
+      );
+
+  @override
+  bool crateApiServerHandleIsRunning({required ServerHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleIsRunningConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleIsRunningConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_is_running",
+        argNames: ["that"],
+      );
+
+  @override
+  int crateApiServerHandlePendingCredits({required ServerHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandlePendingCreditsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandlePendingCreditsConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_pending_credits",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleResetFilterStats({
+    required ServerHandle that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleResetFilterStatsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleResetFilterStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_reset_filter_stats",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleResetLatencyStats({
+    required ServerHandle that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleResetLatencyStatsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleResetLatencyStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_reset_latency_stats",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleSetAirFilter({
+    required ServerHandle that,
+    required DebounceConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_debounce_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleSetAirFilterConstMeta,
+        argValues: [that, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleSetAirFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_set_air_filter",
+        argNames: ["that", "config"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleSetAirGesture({
+    required ServerHandle that,
+    required AirGestureConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_air_gesture_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleSetAirGestureConstMeta,
+        argValues: [that, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleSetAirGestureConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_set_air_gesture",
+        argNames: ["that", "config"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleSetCardMode({
+    required ServerHandle that,
+    required CardMode mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_card_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleSetCardModeConstMeta,
+        argValues: [that, mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleSetCardModeConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_set_card_mode",
+        argNames: ["that", "mode"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleSetCellFilter({
+    required ServerHandle that,
+    required FilterTarget target,
+    required int cell,
+    DebounceConfig? config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_filter_target(target, serializer);
+          sse_encode_u_8(cell, serializer);
+          sse_encode_opt_box_autoadd_debounce_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleSetCellFilterConstMeta,
+        argValues: [that, target, cell, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleSetCellFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_set_cell_filter",
+        argNames: ["that", "target", "cell", "config"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleSetCoinCadence({
+    required ServerHandle that,
+    required CoinCadence cadence,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_coin_cadence(cadence, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleSetCoinCadenceConstMeta,
+        argValues: [that, cadence],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleSetCoinCadenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_set_coin_cadence",
+        argNames: ["that", "cadence"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleSetFramingConfig({
+    required ServerHandle that,
+    required FramingConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_framing_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleSetFramingConfigConstMeta,
+        argValues: [that, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleSetFramingConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_set_framing_config",
+        argNames: ["that", "config"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleSetLivenessConfig({
+    required ServerHandle that,
+    required LivenessConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_liveness_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleSetLivenessConfigConstMeta,
+        argValues: [that, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleSetLivenessConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_set_liveness_config",
+        argNames: ["that", "config"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleSetSliderFilter({
+    required ServerHandle that,
+    required DebounceConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_debounce_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleSetSliderFilterConstMeta,
+        argValues: [that, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleSetSliderFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_set_slider_filter",
+        argNames: ["that", "config"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleSetSliderProfile({
+    required ServerHandle that,
+    String? clientIp,
+    required SliderProfile profile,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_String(clientIp, serializer);
+          sse_encode_box_autoadd_slider_profile(profile, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleSetSliderProfileConstMeta,
+        argValues: [that, clientIp, profile],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleSetSliderProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_set_slider_profile",
+        argNames: ["that", "clientIp", "profile"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleSetSyncConfig({
+    required ServerHandle that,
+    required SyncConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_sync_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleSetSyncConfigConstMeta,
+        argValues: [that, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleSetSyncConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_set_sync_config",
+        argNames: ["that", "config"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleStart({required ServerHandle that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerHandleStartConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleStartConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_start",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiServerHandleStop({required ServerHandle that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleStopConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleStopConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_stop",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<bool> crateApiServerHandleToggleSync({required ServerHandle that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerHandleToggleSyncConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerHandleToggleSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "ServerHandle_toggle_sync",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiClearLogs() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiClearLogsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiClearLogsConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_logs",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiClearSliderProfile({required String clientIp}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(clientIp, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiClearSliderProfileConstMeta,
+        argValues: [clientIp],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiClearSliderProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_slider_profile",
+        argNames: ["clientIp"],
+      );
+
+  @override
+  Stream<ServerEvent> crateApiCreateEventStream() {
+    final sink = RustStreamSink<ServerEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_server_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 45,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiCreateEventStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCreateEventStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_event_stream",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<LogEntry> crateApiCreateLogStream() {
+    final sink = RustStreamSink<LogEntry>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_log_entry_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 46,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiCreateLogStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCreateLogStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_log_stream",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<SensorData> crateApiCreateSensorStream() {
+    final sink = RustStreamSink<SensorData>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_sensor_data_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 47,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiCreateSensorStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCreateSensorStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_sensor_stream",
+        argNames: ["sink"],
+      );
+
+  @override
+  Future<ServerHandle> crateApiCreateServer({required ServerConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_server_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCreateServerConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCreateServerConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_server",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<List<DiscoveredServer>> crateApiDiscoverServers({
+    required int timeoutMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(timeoutMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_discovered_server,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDiscoverServersConstMeta,
+        argValues: [timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDiscoverServersConstMeta =>
+      const TaskConstMeta(
+        debugName: "discover_servers",
+        argNames: ["timeoutMs"],
+      );
+
+  @override
+  String crateApiGeneratePairingSecret() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiGeneratePairingSecretConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGeneratePairingSecretConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_pairing_secret",
+        argNames: [],
+      );
+
+  @override
+  DebounceConfig crateApiGetAirFilter() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_debounce_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetAirFilterConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetAirFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_air_filter",
+        argNames: [],
+      );
+
+  @override
+  AirGestureConfig crateApiGetAirGesture() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_air_gesture_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetAirGestureConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetAirGestureConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_air_gesture",
+        argNames: [],
+      );
+
+  @override
+  CardMode crateApiGetCardMode() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_card_mode,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetCardModeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetCardModeConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_card_mode",
+        argNames: [],
+      );
+
+  @override
+  DebounceConfig crateApiGetCellFilter({
+    required FilterTarget target,
+    required int cell,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_filter_target(target, serializer);
+          sse_encode_u_8(cell, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_debounce_config,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiGetCellFilterConstMeta,
+        argValues: [target, cell],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetCellFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cell_filter",
+        argNames: ["target", "cell"],
+      );
+
+  @override
+  CoinCadence crateApiGetCoinCadence() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_coin_cadence,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetCoinCadenceConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetCoinCadenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_coin_cadence",
+        argNames: [],
+      );
+
+  @override
+  QrMatrix crateApiGetConnectionQr({List<String>? hosts, String? secret}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_String(hosts, serializer);
+          sse_encode_opt_String(secret, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_qr_matrix,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiGetConnectionQrConstMeta,
+        argValues: [hosts, secret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetConnectionQrConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_connection_qr",
+        argNames: ["hosts", "secret"],
+      );
+
+  @override
+  LinkState crateApiGetConnectionState() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_link_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetConnectionStateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetConnectionStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_connection_state",
+        argNames: [],
+      );
+
+  @override
+  List<ConnectionStats> crateApiGetConnectionStats() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_connection_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetConnectionStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetConnectionStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_connection_stats",
+        argNames: [],
+      );
+
+  @override
+  String crateApiGetConnectionUri({List<String>? hosts, String? secret}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_String(hosts, serializer);
+          sse_encode_opt_String(secret, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiGetConnectionUriConstMeta,
+        argValues: [hosts, secret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetConnectionUriConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_connection_uri",
+        argNames: ["hosts", "secret"],
+      );
+
+  @override
+  FilterStats crateApiGetFilterStats() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_filter_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetFilterStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetFilterStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_filter_stats",
+        argNames: [],
+      );
+
+  @override
+  FramingConfig crateApiGetFramingConfig() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_framing_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetFramingConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetFramingConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_framing_config",
+        argNames: [],
+      );
+
+  @override
+  List<LatencyStats> crateApiGetLatencyStats() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_latency_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetLatencyStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetLatencyStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_latency_stats",
+        argNames: [],
+      );
+
+  @override
+  LivenessConfig crateApiGetLivenessConfig() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_liveness_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetLivenessConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetLivenessConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_liveness_config",
+        argNames: [],
+      );
+
+  @override
+  LogLevel crateApiGetLogLevel() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_level,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetLogLevelConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetLogLevelConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_log_level",
+        argNames: [],
+      );
+
+  @override
+  List<LogEntry> crateApiGetRecentLogs({required int limit}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_log_entry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetRecentLogsConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetRecentLogsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_recent_logs",
+        argNames: ["limit"],
+      );
+
+  @override
+  List<ClientSession> crateApiGetSessions() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_client_session,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetSessionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetSessionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_sessions",
+        argNames: [],
+      );
+
+  @override
+  DebounceConfig crateApiGetSliderFilter() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_debounce_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetSliderFilterConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetSliderFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_slider_filter",
+        argNames: [],
+      );
+
+  @override
+  SliderProfile crateApiGetSliderProfile({String? clientIp}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(clientIp, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_slider_profile,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiGetSliderProfileConstMeta,
+        argValues: [clientIp],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetSliderProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_slider_profile",
+        argNames: ["clientIp"],
+      );
+
+  @override
+  SensorData crateApiGetState() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sensor_data,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetStateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_state",
+        argNames: [],
+      );
+
+  @override
+  SyncConfig crateApiGetSyncConfig() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetSyncConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetSyncConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_sync_config",
+        argNames: [],
+      );
+
+  @override
+  SyncState crateApiGetSyncState() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetSyncStateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetSyncStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_sync_state",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiHandleHandshake({required HandshakePayload incoming}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_handshake_payload(incoming, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHandleHandshakeConstMeta,
+        argValues: [incoming],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHandleHandshakeConstMeta =>
+      const TaskConstMeta(
+        debugName: "handle_handshake",
+        argNames: ["incoming"],
+      );
+
+  @override
+  Future<void> crateApiInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitAppConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_app",
+        argNames: [],
+      );
+
+  @override
+  Future<List<CardEntry>> crateApiInitCardLibrary({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_card_entry,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInitCardLibraryConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitCardLibraryConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_card_library",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<void> crateApiInitLastIp({required String ip}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ip, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitLastIpConstMeta,
+        argValues: [ip],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitLastIpConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_last_ip",
+        argNames: ["ip"],
+      );
+
+  @override
+  Future<void> crateApiInsertCard({
+    required String name,
+    required int durationMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_u_32(durationMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInsertCardConstMeta,
+        argValues: [name, durationMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInsertCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "insert_card",
+        argNames: ["name", "durationMs"],
+      );
+
+  @override
+  Future<void> crateApiInsertCredits({required int count}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(count, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiInsertCreditsConstMeta,
+        argValues: [count],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInsertCreditsConstMeta =>
+      const TaskConstMeta(
+        debugName: "insert_credits",
+        argNames: ["count"],
+      );
+
+  @override
+  bool crateApiIsDiscoveryEnabled() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsDiscoveryEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsDiscoveryEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_discovery_enabled",
+        argNames: [],
+      );
+
+  @override
+  List<CardEntry> crateApiListCards() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_card_entry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiListCardsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListCardsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_cards",
+        argNames: [],
+      );
+
+  @override
+  int crateApiPendingCredits() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPendingCreditsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPendingCreditsConstMeta =>
+      const TaskConstMeta(
+        debugName: "pending_credits",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiRemoveCard({required String name}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiRemoveCardConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_card",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<void> crateApiResetFilterStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiResetFilterStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResetFilterStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "reset_filter_stats",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiResetLatencyStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiResetLatencyStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResetLatencyStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "reset_latency_stats",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiSaveCard({
+    required String name,
+    required String accessCode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_String(accessCode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSaveCardConstMeta,
+        argValues: [name, accessCode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSaveCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_card",
+        argNames: ["name", "accessCode"],
+      );
+
+  @override
+  Future<void> crateApiSetAirFilter({required DebounceConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_debounce_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSetAirFilterConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetAirFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_air_filter",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<void> crateApiSetAirGesture({required AirGestureConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_air_gesture_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSetAirGestureConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetAirGestureConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_air_gesture",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<void> crateApiSetCardMode({required CardMode mode}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_card_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSetCardModeConstMeta,
+        argValues: [mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetCardModeConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_card_mode",
+        argNames: ["mode"],
+      );
+
+  @override
+  Future<void> crateApiSetCellFilter({
+    required FilterTarget target,
+    required int cell,
+    DebounceConfig? config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_filter_target(target, serializer);
+          sse_encode_u_8(cell, serializer);
+          sse_encode_opt_box_autoadd_debounce_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSetCellFilterConstMeta,
+        argValues: [target, cell, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetCellFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cell_filter",
+        argNames: ["target", "cell", "config"],
+      );
+
+  @override
+  Future<void> crateApiSetCoinCadence({required CoinCadence cadence}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_coin_cadence(cadence, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSetCoinCadenceConstMeta,
+        argValues: [cadence],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetCoinCadenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_coin_cadence",
+        argNames: ["cadence"],
+      );
+
+  @override
+  Future<void> crateApiSetDiscoveryEnabled({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSetDiscoveryEnabledConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetDiscoveryEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_discovery_enabled",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiSetFramingConfig({required FramingConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_framing_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSetFramingConfigConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetFramingConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_framing_config",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<void> crateApiSetLivenessConfig({required LivenessConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_liveness_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSetLivenessConfigConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetLivenessConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_liveness_config",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<void> crateApiSetLogFile({String? path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSetLogFileConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetLogFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_log_file",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<void> crateApiSetLogLevel({required LogLevel level}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_log_level(level, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSetLogLevelConstMeta,
+        argValues: [level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetLogLevelConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_log_level",
+        argNames: ["level"],
+      );
+
+  @override
+  Future<void> crateApiSetSliderFilter({required DebounceConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_debounce_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSetSliderFilterConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetSliderFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_slider_filter",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<void> crateApiSetSliderProfile({
+    String? clientIp,
+    required SliderProfile profile,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(clientIp, serializer);
+          sse_encode_box_autoadd_slider_profile(profile, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSetSliderProfileConstMeta,
+        argValues: [clientIp, profile],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetSliderProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_slider_profile",
+        argNames: ["clientIp", "profile"],
+      );
+
+  @override
+  Future<void> crateApiSetSyncConfig({required SyncConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_sync_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSetSyncConfigConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetSyncConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_sync_config",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<void> crateApiSyncToShmem({
+    required List<int> air,
+    required List<int> slider,
+    required int coin,
+    required int service,
+    required int test,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(air, serializer);
+          sse_encode_list_prim_u_8_loose(slider, serializer);
+          sse_encode_u_8(coin, serializer);
+          sse_encode_u_8(service, serializer);
+          sse_encode_u_8(test, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSyncToShmemConstMeta,
+        argValues: [air, slider, coin, service, test],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncToShmemConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_to_shmem",
+        argNames: ["air", "slider", "coin", "service", "test"],
+      );
+
+  @override
+  Future<bool> crateApiToggleServer({required int port, required bool isUdp}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_16(port, serializer);
+          sse_encode_bool(isUdp, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiToggleServerConstMeta,
+        argValues: [port, isUdp],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiToggleServerConstMeta =>
+      const TaskConstMeta(
+        debugName: "toggle_server",
+        argNames: ["port", "isUdp"],
+      );
+
+  @override
+  Future<bool> crateApiToggleSync() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiToggleSyncConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiToggleSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "toggle_sync",
+        argNames: [],
+      );
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ServerHandle => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ServerHandle => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  ServerHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ServerHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ServerHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ServerHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ServerHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ServerHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<SensorData> dco_decode_StreamSink_sensor_data_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ServerEvent> dco_decode_StreamSink_server_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AirGestureConfig dco_decode_air_gesture_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AirGestureConfig(
+      enabled: dco_decode_bool(arr[0]),
+      maxSwipeMs: dco_decode_u_32(arr[1]),
+      minKeys: dco_decode_u_8(arr[2]),
+      riseMs: dco_decode_u_32(arr[3]),
+      holdMs: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AirGestureConfig dco_decode_box_autoadd_air_gesture_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_air_gesture_config(raw);
+  }
+
+  @protected
+  CardMode dco_decode_box_autoadd_card_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_card_mode(raw);
+  }
+
+  @protected
+  CoinCadence dco_decode_box_autoadd_coin_cadence(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_coin_cadence(raw);
+  }
+
+  @protected
+  DebounceConfig dco_decode_box_autoadd_debounce_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_debounce_config(raw);
+  }
+
+  @protected
+  FramingConfig dco_decode_box_autoadd_framing_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_framing_config(raw);
+  }
+
+  @protected
+  HandshakePayload dco_decode_box_autoadd_handshake_payload(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_handshake_payload(raw);
+  }
+
+  @protected
+  LivenessConfig dco_decode_box_autoadd_liveness_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_liveness_config(raw);
+  }
+
+  @protected
+  ServerConfig dco_decode_box_autoadd_server_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_server_config(raw);
+  }
+
+  @protected
+  SliderProfile dco_decode_box_autoadd_slider_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_slider_profile(raw);
+  }
+
+  @protected
+  SyncConfig dco_decode_box_autoadd_sync_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_config(raw);
+  }
+
+  @protected
+  CardEntry dco_decode_card_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CardEntry(
+      name: dco_decode_String(arr[0]),
+      accessCode: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  CardMode dco_decode_card_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CardMode_FixedHold(holdMs: dco_decode_u_32(raw[1]));
+      case 1:
+        return CardMode_UntilNextPacket();
+      case 2:
+        return CardMode_Toggle();
+      case 3:
+        return CardMode_UntilAck(timeoutMs: dco_decode_u_32(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ClientSession dco_decode_client_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ClientSession(
+      client: dco_decode_String(arr[0]),
+      state: dco_decode_link_state(arr[1]),
+      lastSeenMs: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  CoinCadence dco_decode_coin_cadence(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CoinCadence(
+      pressMs: dco_decode_u_32(arr[0]),
+      releaseMs: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  ConnectionStats dco_decode_connection_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ConnectionStats(
+      client: dco_decode_String(arr[0]),
+      transport: dco_decode_String(arr[1]),
+      connected: dco_decode_bool(arr[2]),
+      framing: dco_decode_framing_stats(arr[3]),
+    );
+  }
+
+  @protected
+  DebounceConfig dco_decode_debounce_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DebounceConfig(
+      enabled: dco_decode_bool(arr[0]),
+      minPressMs: dco_decode_u_32(arr[1]),
+      minReleaseMs: dco_decode_u_32(arr[2]),
+      pressThreshold: dco_decode_u_8(arr[3]),
+      releaseThreshold: dco_decode_u_8(arr[4]),
+    );
+  }
+
+  @protected
+  DiscoveredServer dco_decode_discovered_server(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DiscoveredServer(
+      name: dco_decode_String(arr[0]),
+      addresses: dco_decode_list_String(arr[1]),
+      port: dco_decode_u_16(arr[2]),
+      transport: dco_decode_String(arr[3]),
+      version: dco_decode_u_8(arr[4]),
+    );
+  }
+
+  @protected
+  FilterStats dco_decode_filter_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FilterStats(
+      sliderSuppressed: dco_decode_u_64(arr[0]),
+      airSuppressed: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  FilterTarget dco_decode_filter_target(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FilterTarget.values[raw as int];
+  }
+
+  @protected
+  FramingConfig dco_decode_framing_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FramingConfig(
+      maxFrame: dco_decode_u_32(arr[0]),
+      policy: dco_decode_framing_policy(arr[1]),
+    );
+  }
+
+  @protected
+  FramingPolicy dco_decode_framing_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FramingPolicy.values[raw as int];
+  }
+
+  @protected
+  FramingStats dco_decode_framing_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FramingStats(
+      frames: dco_decode_u_64(arr[0]),
+      oversized: dco_decode_u_64(arr[1]),
+      resyncs: dco_decode_u_64(arr[2]),
+      discardedBytes: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  HandshakePayload dco_decode_handshake_payload(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return HandshakePayload(
+      clientCurrent: dco_decode_bool(arr[0]),
+      serverCurrent: dco_decode_bool(arr[1]),
+      clientTarget: dco_decode_bool(arr[2]),
+      serverTarget: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_i_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  LatencySource dco_decode_latency_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LatencySource.values[raw as int];
+  }
+
+  @protected
+  LatencyStats dco_decode_latency_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return LatencyStats(
+      transport: dco_decode_String(arr[0]),
+      source: dco_decode_latency_source(arr[1]),
+      count: dco_decode_u_64(arr[2]),
+      meanUs: dco_decode_u_64(arr[3]),
+      p50Us: dco_decode_u_64(arr[4]),
+      p90Us: dco_decode_u_64(arr[5]),
+      p99Us: dco_decode_u_64(arr[6]),
+      maxUs: dco_decode_u_64(arr[7]),
+    );
+  }
+
+  @protected
+  LinkState dco_decode_link_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LinkState.values[raw as int];
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<bool> dco_decode_list_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_bool).toList();
+  }
+
+  @protected
+  List<CardEntry> dco_decode_list_card_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_card_entry).toList();
+  }
+
+  @protected
+  List<ClientSession> dco_decode_list_client_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_client_session).toList();
+  }
+
+  @protected
+  List<ConnectionStats> dco_decode_list_connection_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_connection_stats).toList();
+  }
+
+  @protected
+  List<DiscoveredServer> dco_decode_list_discovered_server(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_discovered_server).toList();
+  }
+
+  @protected
+  List<LatencyStats> dco_decode_list_latency_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_latency_stats).toList();
+  }
+
+  @protected
+  List<LogEntry> dco_decode_list_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_log_entry).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  LivenessConfig dco_decode_liveness_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LivenessConfig(
+      keepaliveMs: dco_decode_u_32(arr[0]),
+      staleMs: dco_decode_u_32(arr[1]),
+      lostMs: dco_decode_u_32(arr[2]),
+      releaseOnLoss: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  LogCategory dco_decode_log_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogCategory.values[raw as int];
+  }
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LogEntry(
+      timestamp: dco_decode_String(arr[0]),
+      level: dco_decode_log_level(arr[1]),
+      category: dco_decode_log_category(arr[2]),
+      message: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogLevel.values[raw as int];
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  DebounceConfig? dco_decode_opt_box_autoadd_debounce_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_debounce_config(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  QrMatrix dco_decode_qr_matrix(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QrMatrix(
+      size: dco_decode_u_32(arr[0]),
+      modules: dco_decode_list_bool(arr[1]),
+    );
+  }
+
+  @protected
+  SensorData dco_decode_sensor_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return SensorData(
+      air: dco_decode_list_prim_u_8_strict(arr[0]),
+      slider: dco_decode_list_prim_u_8_strict(arr[1]),
+      coin: dco_decode_u_8(arr[2]),
+      service: dco_decode_u_8(arr[3]),
+      test: dco_decode_u_8(arr[4]),
+      code: dco_decode_u_8_array_10(arr[5]),
+      cardPresent: dco_decode_bool(arr[6]),
+      buttons: dco_decode_u_8(arr[7]),
+      active: dco_decode_bool(arr[8]),
+      client: dco_decode_opt_String(arr[9]),
+    );
+  }
+
+  @protected
+  ServerConfig dco_decode_server_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ServerConfig(
+      name: dco_decode_String(arr[0]),
+      port: dco_decode_u_16(arr[1]),
+      protocol: dco_decode_String(arr[2]),
+      shmemName: dco_decode_String(arr[3]),
+      shmemSize: dco_decode_usize(arr[4]),
+      socketPath: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  ServerEvent dco_decode_server_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ServerEvent_Input(dco_decode_sensor_data(raw[1]));
+      case 1:
+        return ServerEvent_Connected(client: dco_decode_String(raw[1]));
+      case 2:
+        return ServerEvent_Disconnected(client: dco_decode_String(raw[1]));
+      case 3:
+        return ServerEvent_Handshake(
+          client: dco_decode_opt_String(raw[1]),
+          active: dco_decode_bool(raw[2]),
+          acknowledged: dco_decode_bool(raw[3]),
+        );
+      case 4:
+        return ServerEvent_Error(message: dco_decode_String(raw[1]));
+      case 5:
+        return ServerEvent_SyncPending(target: dco_decode_bool(raw[1]));
+      case 6:
+        return ServerEvent_SyncConfirmed(active: dco_decode_bool(raw[1]));
+      case 7:
+        return ServerEvent_SyncFailed(
+          active: dco_decode_bool(raw[1]),
+          reason: dco_decode_String(raw[2]),
+        );
+      case 8:
+        return ServerEvent_LinkChanged(
+          client: dco_decode_String(raw[1]),
+          state: dco_decode_link_state(raw[2]),
+        );
+      case 9:
+        return ServerEvent_Lagged(missed: dco_decode_u_64(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SliderProfile dco_decode_slider_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SliderProfile(
+      maskedCells: dco_decode_list_prim_u_8_strict(arr[0]),
+      sixteenKey: dco_decode_bool(arr[1]),
+      table: dco_decode_opt_list_prim_u_8_strict(arr[2]),
+      reverse: dco_decode_bool(arr[3]),
+      offset: dco_decode_i_8(arr[4]),
+    );
+  }
+
+  @protected
+  SyncConfig dco_decode_sync_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SyncConfig(
+      retransmitMs: dco_decode_u_32(arr[0]),
+      timeoutMs: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  SyncState dco_decode_sync_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SyncState_Idle();
+      case 1:
+        return SyncState_Pending(
+          target: dco_decode_bool(raw[1]),
+          previous: dco_decode_bool(raw[2]),
+          attempts: dco_decode_u_32(raw[3]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  U8Array10 dco_decode_u_8_array_10(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return U8Array10(dco_decode_list_prim_u_8_strict(raw));
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  ServerHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(deserializer);
+    return inner;
+  }
+
+  @protected
+  ServerHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(deserializer);
+    return inner;
+  }
+
+  @protected
+  ServerHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ServerHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<SensorData> sse_decode_StreamSink_sensor_data_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ServerEvent> sse_decode_StreamSink_server_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AirGestureConfig sse_decode_air_gesture_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_maxSwipeMs = sse_decode_u_32(deserializer);
+    var var_minKeys = sse_decode_u_8(deserializer);
+    var var_riseMs = sse_decode_u_32(deserializer);
+    var var_holdMs = sse_decode_u_32(deserializer);
+    return AirGestureConfig(
+      enabled: var_enabled,
+      maxSwipeMs: var_maxSwipeMs,
+      minKeys: var_minKeys,
+      riseMs: var_riseMs,
+      holdMs: var_holdMs,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AirGestureConfig sse_decode_box_autoadd_air_gesture_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_air_gesture_config(deserializer));
+  }
+
+  @protected
+  CardMode sse_decode_box_autoadd_card_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_card_mode(deserializer));
+  }
+
+  @protected
+  CoinCadence sse_decode_box_autoadd_coin_cadence(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_coin_cadence(deserializer));
+  }
+
+  @protected
+  DebounceConfig sse_decode_box_autoadd_debounce_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_debounce_config(deserializer));
+  }
+
+  @protected
+  FramingConfig sse_decode_box_autoadd_framing_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_framing_config(deserializer));
+  }
+
+  @protected
+  HandshakePayload sse_decode_box_autoadd_handshake_payload(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_handshake_payload(deserializer));
+  }
+
+  @protected
+  LivenessConfig sse_decode_box_autoadd_liveness_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_liveness_config(deserializer));
+  }
+
+  @protected
+  ServerConfig sse_decode_box_autoadd_server_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_server_config(deserializer));
+  }
+
+  @protected
+  SliderProfile sse_decode_box_autoadd_slider_profile(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_slider_profile(deserializer));
+  }
+
+  @protected
+  SyncConfig sse_decode_box_autoadd_sync_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_config(deserializer));
+  }
+
+  @protected
+  CardEntry sse_decode_card_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_accessCode = sse_decode_String(deserializer);
+    return CardEntry(name: var_name, accessCode: var_accessCode);
+  }
+
+  @protected
+  CardMode sse_decode_card_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_holdMs = sse_decode_u_32(deserializer);
+        return CardMode_FixedHold(holdMs: var_holdMs);
+      case 1:
+        return CardMode_UntilNextPacket();
+      case 2:
+        return CardMode_Toggle();
+      case 3:
+        var var_timeoutMs = sse_decode_u_32(deserializer);
+        return CardMode_UntilAck(timeoutMs: var_timeoutMs);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ClientSession sse_decode_client_session(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_client = sse_decode_String(deserializer);
+    var var_state = sse_decode_link_state(deserializer);
+    var var_lastSeenMs = sse_decode_u_64(deserializer);
+    return ClientSession(
+      client: var_client,
+      state: var_state,
+      lastSeenMs: var_lastSeenMs,
+    );
+  }
+
+  @protected
+  CoinCadence sse_decode_coin_cadence(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pressMs = sse_decode_u_32(deserializer);
+    var var_releaseMs = sse_decode_u_32(deserializer);
+    return CoinCadence(pressMs: var_pressMs, releaseMs: var_releaseMs);
+  }
+
+  @protected
+  ConnectionStats sse_decode_connection_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_client = sse_decode_String(deserializer);
+    var var_transport = sse_decode_String(deserializer);
+    var var_connected = sse_decode_bool(deserializer);
+    var var_framing = sse_decode_framing_stats(deserializer);
+    return ConnectionStats(
+      client: var_client,
+      transport: var_transport,
+      connected: var_connected,
+      framing: var_framing,
+    );
+  }
+
+  @protected
+  DebounceConfig sse_decode_debounce_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_minPressMs = sse_decode_u_32(deserializer);
+    var var_minReleaseMs = sse_decode_u_32(deserializer);
+    var var_pressThreshold = sse_decode_u_8(deserializer);
+    var var_releaseThreshold = sse_decode_u_8(deserializer);
+    return DebounceConfig(
+      enabled: var_enabled,
+      minPressMs: var_minPressMs,
+      minReleaseMs: var_minReleaseMs,
+      pressThreshold: var_pressThreshold,
+      releaseThreshold: var_releaseThreshold,
+    );
+  }
+
+  @protected
+  DiscoveredServer sse_decode_discovered_server(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_addresses = sse_decode_list_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    var var_transport = sse_decode_String(deserializer);
+    var var_version = sse_decode_u_8(deserializer);
+    return DiscoveredServer(
+      name: var_name,
+      addresses: var_addresses,
+      port: var_port,
+      transport: var_transport,
+      version: var_version,
+    );
+  }
+
+  @protected
+  FilterStats sse_decode_filter_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sliderSuppressed = sse_decode_u_64(deserializer);
+    var var_airSuppressed = sse_decode_u_64(deserializer);
+    return FilterStats(
+      sliderSuppressed: var_sliderSuppressed,
+      airSuppressed: var_airSuppressed,
+    );
+  }
+
+  @protected
+  FilterTarget sse_decode_filter_target(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FilterTarget.values[inner];
+  }
+
+  @protected
+  FramingConfig sse_decode_framing_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxFrame = sse_decode_u_32(deserializer);
+    var var_policy = sse_decode_framing_policy(deserializer);
+    return FramingConfig(maxFrame: var_maxFrame, policy: var_policy);
+  }
+
+  @protected
+  FramingPolicy sse_decode_framing_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FramingPolicy.values[inner];
+  }
+
+  @protected
+  FramingStats sse_decode_framing_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_frames = sse_decode_u_64(deserializer);
+    var var_oversized = sse_decode_u_64(deserializer);
+    var var_resyncs = sse_decode_u_64(deserializer);
+    var var_discardedBytes = sse_decode_u_64(deserializer);
+    return FramingStats(
+      frames: var_frames,
+      oversized: var_oversized,
+      resyncs: var_resyncs,
+      discardedBytes: var_discardedBytes,
+    );
+  }
+
+  @protected
+  HandshakePayload sse_decode_handshake_payload(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_clientCurrent = sse_decode_bool(deserializer);
+    var var_serverCurrent = sse_decode_bool(deserializer);
+    var var_clientTarget = sse_decode_bool(deserializer);
+    var var_serverTarget = sse_decode_bool(deserializer);
+    return HandshakePayload(
+      clientCurrent: var_clientCurrent,
+      serverCurrent: var_serverCurrent,
+      clientTarget: var_clientTarget,
+      serverTarget: var_serverTarget,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  int sse_decode_i_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt8();
+  }
+
+  @protected
+  LatencySource sse_decode_latency_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LatencySource.values[inner];
+  }
+
+  @protected
+  LatencyStats sse_decode_latency_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_transport = sse_decode_String(deserializer);
+    var var_source = sse_decode_latency_source(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    var var_meanUs = sse_decode_u_64(deserializer);
+    var var_p50Us = sse_decode_u_64(deserializer);
+    var var_p90Us = sse_decode_u_64(deserializer);
+    var var_p99Us = sse_decode_u_64(deserializer);
+    var var_maxUs = sse_decode_u_64(deserializer);
+    return LatencyStats(
+      transport: var_transport,
+      source: var_source,
+      count: var_count,
+      meanUs: var_meanUs,
+      p50Us: var_p50Us,
+      p90Us: var_p90Us,
+      p99Us: var_p99Us,
+      maxUs: var_maxUs,
+    );
+  }
+
+  @protected
+  LinkState sse_decode_link_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LinkState.values[inner];
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<bool> sse_decode_list_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <bool>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_bool(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CardEntry> sse_decode_list_card_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CardEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_card_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ClientSession> sse_decode_list_client_session(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ClientSession>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_client_session(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConnectionStats> sse_decode_list_connection_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConnectionStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_connection_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DiscoveredServer> sse_decode_list_discovered_server(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DiscoveredServer>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_discovered_server(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LatencyStats> sse_decode_list_latency_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LatencyStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_latency_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LogEntry> sse_decode_list_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LogEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_log_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  LivenessConfig sse_decode_liveness_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_keepaliveMs = sse_decode_u_32(deserializer);
+    var var_staleMs = sse_decode_u_32(deserializer);
+    var var_lostMs = sse_decode_u_32(deserializer);
+    var var_releaseOnLoss = sse_decode_bool(deserializer);
+    return LivenessConfig(
+      keepaliveMs: var_keepaliveMs,
+      staleMs: var_staleMs,
+      lostMs: var_lostMs,
+      releaseOnLoss: var_releaseOnLoss,
+    );
+  }
+
+  @protected
+  LogCategory sse_decode_log_category(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LogCategory.values[inner];
+  }
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timestamp = sse_decode_String(deserializer);
+    var var_level = sse_decode_log_level(deserializer);
+    var var_category = sse_decode_log_category(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return LogEntry(
+      timestamp: var_timestamp,
+      level: var_level,
+      category: var_category,
+      message: var_message,
+    );
+  }
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LogLevel.values[inner];
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DebounceConfig? sse_decode_opt_box_autoadd_debounce_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_debounce_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  QrMatrix sse_decode_qr_matrix(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_size = sse_decode_u_32(deserializer);
+    var var_modules = sse_decode_list_bool(deserializer);
+    return QrMatrix(size: var_size, modules: var_modules);
+  }
+
+  @protected
+  SensorData sse_decode_sensor_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_air = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_slider = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_coin = sse_decode_u_8(deserializer);
+    var var_service = sse_decode_u_8(deserializer);
+    var var_test = sse_decode_u_8(deserializer);
+    var var_code = sse_decode_u_8_array_10(deserializer);
+    var var_cardPresent = sse_decode_bool(deserializer);
+    var var_buttons = sse_decode_u_8(deserializer);
+    var var_active = sse_decode_bool(deserializer);
+    var var_client = sse_decode_opt_String(deserializer);
+    return SensorData(
+      air: var_air,
+      slider: var_slider,
+      coin: var_coin,
+      service: var_service,
+      test: var_test,
+      code: var_code,
+      cardPresent: var_cardPresent,
+      buttons: var_buttons,
+      active: var_active,
+      client: var_client,
+    );
+  }
+
+  @protected
+  ServerConfig sse_decode_server_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    var var_protocol = sse_decode_String(deserializer);
+    var var_shmemName = sse_decode_String(deserializer);
+    var var_shmemSize = sse_decode_usize(deserializer);
+    var var_socketPath = sse_decode_opt_String(deserializer);
+    return ServerConfig(
+      name: var_name,
+      port: var_port,
+      protocol: var_protocol,
+      shmemName: var_shmemName,
+      shmemSize: var_shmemSize,
+      socketPath: var_socketPath,
+    );
+  }
+
+  @protected
+  ServerEvent sse_decode_server_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_sensor_data(deserializer);
+        return ServerEvent_Input(var_field0);
+      case 1:
+        var var_client = sse_decode_String(deserializer);
+        return ServerEvent_Connected(client: var_client);
+      case 2:
+        var var_client = sse_decode_String(deserializer);
+        return ServerEvent_Disconnected(client: var_client);
+      case 3:
+        var var_client = sse_decode_opt_String(deserializer);
+        var var_active = sse_decode_bool(deserializer);
+        var var_acknowledged = sse_decode_bool(deserializer);
+        return ServerEvent_Handshake(
+          client: var_client,
+          active: var_active,
+          acknowledged: var_acknowledged,
+        );
+      case 4:
+        var var_message = sse_decode_String(deserializer);
+        return ServerEvent_Error(message: var_message);
+      case 5:
+        var var_target = sse_decode_bool(deserializer);
+        return ServerEvent_SyncPending(target: var_target);
+      case 6:
+        var var_active = sse_decode_bool(deserializer);
+        return ServerEvent_SyncConfirmed(active: var_active);
+      case 7:
+        var var_active = sse_decode_bool(deserializer);
+        var var_reason = sse_decode_String(deserializer);
+        return ServerEvent_SyncFailed(active: var_active, reason: var_reason);
+      case 8:
+        var var_client = sse_decode_String(deserializer);
+        var var_state = sse_decode_link_state(deserializer);
+        return ServerEvent_LinkChanged(client: var_client, state: var_state);
+      case 9:
+        var var_missed = sse_decode_u_64(deserializer);
+        return ServerEvent_Lagged(missed: var_missed);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  SliderProfile sse_decode_slider_profile(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maskedCells = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_sixteenKey = sse_decode_bool(deserializer);
+    var var_table = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_reverse = sse_decode_bool(deserializer);
+    var var_offset = sse_decode_i_8(deserializer);
+    return SliderProfile(
+      maskedCells: var_maskedCells,
+      sixteenKey: var_sixteenKey,
+      table: var_table,
+      reverse: var_reverse,
+      offset: var_offset,
+    );
+  }
+
+  @protected
+  SyncConfig sse_decode_sync_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_retransmitMs = sse_decode_u_32(deserializer);
+    var var_timeoutMs = sse_decode_u_32(deserializer);
+    return SyncConfig(retransmitMs: var_retransmitMs, timeoutMs: var_timeoutMs);
+  }
+
+  @protected
+  SyncState sse_decode_sync_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return SyncState_Idle();
+      case 1:
+        var var_target = sse_decode_bool(deserializer);
+        var var_previous = sse_decode_bool(deserializer);
+        var var_attempts = sse_decode_u_32(deserializer);
+        return SyncState_Pending(
+          target: var_target,
+          previous: var_previous,
+          attempts: var_attempts,
+        );
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  U8Array10 sse_decode_u_8_array_10(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return U8Array10(inner);
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    ServerHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ServerHandleImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    ServerHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ServerHandleImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    ServerHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ServerHandleImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_log_entry_Sse(
+    RustStreamSink<LogEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_sensor_data_Sse(
+    RustStreamSink<SensorData> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sensor_data,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_server_event_Sse(
+    RustStreamSink<ServerEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_server_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_air_gesture_config(
+    AirGestureConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_u_32(self.maxSwipeMs, serializer);
+    sse_encode_u_8(self.minKeys, serializer);
+    sse_encode_u_32(self.riseMs, serializer);
+    sse_encode_u_32(self.holdMs, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_air_gesture_config(
+    AirGestureConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_air_gesture_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_card_mode(
+    CardMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_card_mode(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_coin_cadence(
+    CoinCadence self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_coin_cadence(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_debounce_config(
+    DebounceConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_debounce_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_framing_config(
+    FramingConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_framing_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_handshake_payload(
+    HandshakePayload self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_handshake_payload(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_liveness_config(
+    LivenessConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_liveness_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_server_config(
+    ServerConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_server_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_slider_profile(
+    SliderProfile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_slider_profile(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sync_config(
+    SyncConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_card_entry(CardEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.accessCode, serializer);
+  }
+
+  @protected
+  void sse_encode_card_mode(CardMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case CardMode_FixedHold(holdMs: final holdMs):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_32(holdMs, serializer);
+      case CardMode_UntilNextPacket():
+        sse_encode_i_32(1, serializer);
+      case CardMode_Toggle():
+        sse_encode_i_32(2, serializer);
+      case CardMode_UntilAck(timeoutMs: final timeoutMs):
+        sse_encode_i_32(3, serializer);
+        sse_encode_u_32(timeoutMs, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_client_session(ClientSession self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.client, serializer);
+    sse_encode_link_state(self.state, serializer);
+    sse_encode_u_64(self.lastSeenMs, serializer);
+  }
+
+  @protected
+  void sse_encode_coin_cadence(CoinCadence self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.pressMs, serializer);
+    sse_encode_u_32(self.releaseMs, serializer);
+  }
+
+  @protected
+  void sse_encode_connection_stats(
+    ConnectionStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.client, serializer);
+    sse_encode_String(self.transport, serializer);
+    sse_encode_bool(self.connected, serializer);
+    sse_encode_framing_stats(self.framing, serializer);
+  }
+
+  @protected
+  void sse_encode_debounce_config(
+    DebounceConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_u_32(self.minPressMs, serializer);
+    sse_encode_u_32(self.minReleaseMs, serializer);
+    sse_encode_u_8(self.pressThreshold, serializer);
+    sse_encode_u_8(self.releaseThreshold, serializer);
+  }
+
+  @protected
+  void sse_encode_discovered_server(
+    DiscoveredServer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_list_String(self.addresses, serializer);
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_String(self.transport, serializer);
+    sse_encode_u_8(self.version, serializer);
+  }
+
+  @protected
+  void sse_encode_filter_stats(FilterStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.sliderSuppressed, serializer);
+    sse_encode_u_64(self.airSuppressed, serializer);
+  }
+
+  @protected
+  void sse_encode_filter_target(FilterTarget self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_framing_config(FramingConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxFrame, serializer);
+    sse_encode_framing_policy(self.policy, serializer);
+  }
+
+  @protected
+  void sse_encode_framing_policy(FramingPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_framing_stats(FramingStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.frames, serializer);
+    sse_encode_u_64(self.oversized, serializer);
+    sse_encode_u_64(self.resyncs, serializer);
+    sse_encode_u_64(self.discardedBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_handshake_payload(
+    HandshakePayload self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.clientCurrent, serializer);
+    sse_encode_bool(self.serverCurrent, serializer);
+    sse_encode_bool(self.clientTarget, serializer);
+    sse_encode_bool(self.serverTarget, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt8(self);
+  }
+
+  @protected
+  void sse_encode_latency_source(LatencySource self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_latency_stats(LatencyStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.transport, serializer);
+    sse_encode_latency_source(self.source, serializer);
+    sse_encode_u_64(self.count, serializer);
+    sse_encode_u_64(self.meanUs, serializer);
+    sse_encode_u_64(self.p50Us, serializer);
+    sse_encode_u_64(self.p90Us, serializer);
+    sse_encode_u_64(self.p99Us, serializer);
+    sse_encode_u_64(self.maxUs, serializer);
+  }
+
+  @protected
+  void sse_encode_link_state(LinkState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_bool(List<bool> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_bool(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_card_entry(
+    List<CardEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_card_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_client_session(
+    List<ClientSession> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_client_session(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_connection_stats(
+    List<ConnectionStats> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_connection_stats(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_discovered_server(
+    List<DiscoveredServer> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_discovered_server(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_latency_stats(
+    List<LatencyStats> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_latency_stats(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_log_entry(
+    List<LogEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_log_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
+  }
+
+  @protected
+  void sse_encode_liveness_config(
+    LivenessConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.keepaliveMs, serializer);
+    sse_encode_u_32(self.staleMs, serializer);
+    sse_encode_u_32(self.lostMs, serializer);
+    sse_encode_bool(self.releaseOnLoss, serializer);
+  }
+
+  @protected
+  void sse_encode_log_category(LogCategory self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.timestamp, serializer);
+    sse_encode_log_level(self.level, serializer);
+    sse_encode_log_category(self.category, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_debounce_config(
+    DebounceConfig? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_debounce_config(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_String(
+    List<String>? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_qr_matrix(QrMatrix self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.size, serializer);
+    sse_encode_list_bool(self.modules, serializer);
+  }
+
+  @protected
+  void sse_encode_sensor_data(SensorData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.air, serializer);
+    sse_encode_list_prim_u_8_strict(self.slider, serializer);
+    sse_encode_u_8(self.coin, serializer);
+    sse_encode_u_8(self.service, serializer);
+    sse_encode_u_8(self.test, serializer);
+    sse_encode_u_8_array_10(self.code, serializer);
+    sse_encode_bool(self.cardPresent, serializer);
+    sse_encode_u_8(self.buttons, serializer);
+    sse_encode_bool(self.active, serializer);
+    sse_encode_opt_String(self.client, serializer);
+  }
+
+  @protected
+  void sse_encode_server_config(ServerConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_String(self.protocol, serializer);
+    sse_encode_String(self.shmemName, serializer);
+    sse_encode_usize(self.shmemSize, serializer);
+    sse_encode_opt_String(self.socketPath, serializer);
+  }
+
+  @protected
+  void sse_encode_server_event(ServerEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ServerEvent_Input(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_sensor_data(field0, serializer);
+      case ServerEvent_Connected(client: final client):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(client, serializer);
+      case ServerEvent_Disconnected(client: final client):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(client, serializer);
+      case ServerEvent_Handshake(
+        client: final client,
+        active: final active,
+        acknowledged: final acknowledged,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_opt_String(client, serializer);
+        sse_encode_bool(active, serializer);
+        sse_encode_bool(acknowledged, serializer);
+      case ServerEvent_Error(message: final message):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(message, serializer);
+      case ServerEvent_SyncPending(target: final target):
+        sse_encode_i_32(5, serializer);
+        sse_encode_bool(target, serializer);
+      case ServerEvent_SyncConfirmed(active: final active):
+        sse_encode_i_32(6, serializer);
+        sse_encode_bool(active, serializer);
+      case ServerEvent_SyncFailed(active: final active, reason: final reason):
+        sse_encode_i_32(7, serializer);
+        sse_encode_bool(active, serializer);
+        sse_encode_String(reason, serializer);
+      case ServerEvent_LinkChanged(client: final client, state: final state):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(client, serializer);
+        sse_encode_link_state(state, serializer);
+      case ServerEvent_Lagged(missed: final missed):
+        sse_encode_i_32(9, serializer);
+        sse_encode_u_64(missed, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_slider_profile(SliderProfile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.maskedCells, serializer);
+    sse_encode_bool(self.sixteenKey, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.table, serializer);
+    sse_encode_bool(self.reverse, serializer);
+    sse_encode_i_8(self.offset, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_config(SyncConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.retransmitMs, serializer);
+    sse_encode_u_32(self.timeoutMs, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_state(SyncState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SyncState_Idle():
+        sse_encode_i_32(0, serializer);
+      case SyncState_Pending(
+        target: final target,
+        previous: final previous,
+        attempts: final attempts,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_bool(target, serializer);
+        sse_encode_bool(previous, serializer);
+        sse_encode_u_32(attempts, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self);
+  }
+
+  @protected
+  void sse_encode_u_8_array_10(U8Array10 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.inner, serializer);
+  }
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+}
+
+@sealed
+class ServerHandleImpl extends RustOpaque implements ServerHandle {
+  // Not to be used by end users
+  ServerHandleImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  ServerHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_ServerHandle,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ServerHandle,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ServerHandlePtr,
+  );
+
+  ServerConfig config() =>
+      RustLib.instance.api.crateApiServerHandleConfig(that: this);
+
+  Future<void> start() =>
+      RustLib.instance.api.crateApiServerHandleStart(that: this);
+
+  Future<void> stop() =>
+      RustLib.instance.api.crateApiServerHandleStop(that: this);
+
+  bool isRunning() =>
+      RustLib.instance.api.crateApiServerHandleIsRunning(that: this);
+
+  /// Adds a subscriber for input snapshots; earlier streams keep receiving until Dart closes them.
+  Stream<SensorData> createSensorStream() =>
+      RustLib.instance.api.crateApiServerHandleCreateSensorStream(that: this);
+
+  Stream<ServerEvent> createEventStream() =>
+      RustLib.instance.api.crateApiServerHandleCreateEventStream(that: this);
+
+  SensorData getState() =>
+      RustLib.instance.api.crateApiServerHandleGetState(that: this);
+
+  /// Returns whether a sync was started; the outcome is reported on the event stream.
+  Future<bool> toggleSync() =>
+      RustLib.instance.api.crateApiServerHandleToggleSync(that: this);
+
+  /// URI for the client to scan; `hosts` defaults to this machine's addresses.
+  String getConnectionUri({List<String>? hosts, String? secret}) =>
+      RustLib.instance.api.crateApiServerHandleGetConnectionUri(
+        that: this,
+        hosts: hosts,
+        secret: secret,
+      );
+
+  QrMatrix getConnectionQr({List<String>? hosts, String? secret}) =>
+      RustLib.instance.api.crateApiServerHandleGetConnectionQr(
+        that: this,
+        hosts: hosts,
+        secret: secret,
+      );
+
+  LinkState getConnectionState() =>
+      RustLib.instance.api.crateApiServerHandleGetConnectionState(that: this);
+
+  List<ClientSession> getSessions() =>
+      RustLib.instance.api.crateApiServerHandleGetSessions(that: this);
+
+  Future<void> setLivenessConfig({required LivenessConfig config}) =>
+      RustLib.instance.api.crateApiServerHandleSetLivenessConfig(
+        that: this,
+        config: config,
+      );
+
+  LivenessConfig getLivenessConfig() =>
+      RustLib.instance.api.crateApiServerHandleGetLivenessConfig(that: this);
+
+  SyncState getSyncState() =>
+      RustLib.instance.api.crateApiServerHandleGetSyncState(that: this);
+
+  Future<void> setSyncConfig({required SyncConfig config}) =>
+      RustLib.instance.api.crateApiServerHandleSetSyncConfig(
+        that: this,
+        config: config,
+      );
+
+  SyncConfig getSyncConfig() =>
+      RustLib.instance.api.crateApiServerHandleGetSyncConfig(that: this);
+
+  Future<void> insertCard({required String name, required int durationMs}) =>
+      RustLib.instance.api.crateApiServerHandleInsertCard(
+        that: this,
+        name: name,
+        durationMs: durationMs,
+      );
+
+  Future<void> insertCredits({required int count}) =>
+      RustLib.instance.api.crateApiServerHandleInsertCredits(
+        that: this,
+        count: count,
+      );
+
+  int pendingCredits() =>
+      RustLib.instance.api.crateApiServerHandlePendingCredits(that: this);
+
+  Future<void> setCoinCadence({required CoinCadence cadence}) =>
+      RustLib.instance.api.crateApiServerHandleSetCoinCadence(
+        that: this,
+        cadence: cadence,
+      );
+
+  CoinCadence getCoinCadence() =>
+      RustLib.instance.api.crateApiServerHandleGetCoinCadence(that: this);
+
+  Future<void> setCardMode({required CardMode mode}) =>
+      RustLib.instance.api.crateApiServerHandleSetCardMode(
+        that: this,
+        mode: mode,
+      );
+
+  CardMode getCardMode() =>
+      RustLib.instance.api.crateApiServerHandleGetCardMode(that: this);
+
+  Future<void> setAirGesture({required AirGestureConfig config}) =>
+      RustLib.instance.api.crateApiServerHandleSetAirGesture(
+        that: this,
+        config: config,
+      );
+
+  AirGestureConfig getAirGesture() =>
+      RustLib.instance.api.crateApiServerHandleGetAirGesture(that: this);
+
+  Future<void> setSliderProfile({
+    String? clientIp,
+    required SliderProfile profile,
+  }) => RustLib.instance.api.crateApiServerHandleSetSliderProfile(
+    that: this,
+    clientIp: clientIp,
+    profile: profile,
+  );
+
+  SliderProfile getSliderProfile({String? clientIp}) =>
+      RustLib.instance.api.crateApiServerHandleGetSliderProfile(
+        that: this,
+        clientIp: clientIp,
+      );
+
+  Future<bool> clearSliderProfile({required String clientIp}) =>
+      RustLib.instance.api.crateApiServerHandleClearSliderProfile(
+        that: this,
+        clientIp: clientIp,
+      );
+
+  Future<void> setSliderFilter({required DebounceConfig config}) =>
+      RustLib.instance.api.crateApiServerHandleSetSliderFilter(
+        that: this,
+        config: config,
+      );
+
+  Future<void> setAirFilter({required DebounceConfig config}) =>
+      RustLib.instance.api.crateApiServerHandleSetAirFilter(
+        that: this,
+        config: config,
+      );
+
+  DebounceConfig getSliderFilter() =>
+      RustLib.instance.api.crateApiServerHandleGetSliderFilter(that: this);
+
+  DebounceConfig getAirFilter() =>
+      RustLib.instance.api.crateApiServerHandleGetAirFilter(that: this);
+
+  /// Overrides one slider (0..32) or air (0..6) cell; `None` restores the shared config.
+  Future<void> setCellFilter({
+    required FilterTarget target,
+    required int cell,
+    DebounceConfig? config,
+  }) => RustLib.instance.api.crateApiServerHandleSetCellFilter(
+    that: this,
+    target: target,
+    cell: cell,
+    config: config,
+  );
+
+  DebounceConfig getCellFilter({
+    required FilterTarget target,
+    required int cell,
+  }) => RustLib.instance.api.crateApiServerHandleGetCellFilter(
+    that: this,
+    target: target,
+    cell: cell,
+  );
+
+  FilterStats getFilterStats() =>
+      RustLib.instance.api.crateApiServerHandleGetFilterStats(that: this);
+
+  Future<void> resetFilterStats() =>
+      RustLib.instance.api.crateApiServerHandleResetFilterStats(that: this);
+
+  List<LatencyStats> getLatencyStats() =>
+      RustLib.instance.api.crateApiServerHandleGetLatencyStats(that: this);
+
+  Future<void> resetLatencyStats() =>
+      RustLib.instance.api.crateApiServerHandleResetLatencyStats(that: this);
+
+  Future<void> setFramingConfig({required FramingConfig config}) =>
+      RustLib.instance.api.crateApiServerHandleSetFramingConfig(
+        that: this,
+        config: config,
+      );
+
+  FramingConfig getFramingConfig() =>
+      RustLib.instance.api.crateApiServerHandleGetFramingConfig(that: this);
+
+  List<ConnectionStats> getConnectionStats() =>
+      RustLib.instance.api.crateApiServerHandleGetConnectionStats(that: this);
+}
@@ -0,0 +1,832 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
+
+// Static analysis wrongly picks the IO variant, thus ignore this
+// ignore_for_file: argument_type_not_assignable
+
+import 'api.dart';
+import 'card_library.dart';
+import 'coin.dart';
+import 'dart:async';
+import 'dart:convert';
+import 'dart:js_interop';
+import 'discovery.dart';
+import 'events.dart';
+import 'filter.dart';
+import 'framing.dart';
+import 'frb_generated.dart';
+import 'gesture.dart';
+import 'latency.dart';
+import 'liveness.dart';
+import 'logging.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'pairing.dart';
+import 'protocol.dart';
+import 'remap.dart';
+import 'server.dart';
+import 'state.dart';
+import 'sync.dart';
+
+abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+  RustLibApiImplPlatform({
+    required super.handler,
+    required super.wire,
+    required super.generalizedFrbRustBinding,
+    required super.portManager,
+  });
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ServerHandlePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  ServerHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    dynamic raw,
+  );
+
+  @protected
+  ServerHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    dynamic raw,
+  );
+
+  @protected
+  ServerHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<SensorData> dco_decode_StreamSink_sensor_data_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<ServerEvent> dco_decode_StreamSink_server_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  AirGestureConfig dco_decode_air_gesture_config(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  AirGestureConfig dco_decode_box_autoadd_air_gesture_config(dynamic raw);
+
+  @protected
+  CardMode dco_decode_box_autoadd_card_mode(dynamic raw);
+
+  @protected
+  CoinCadence dco_decode_box_autoadd_coin_cadence(dynamic raw);
+
+  @protected
+  DebounceConfig dco_decode_box_autoadd_debounce_config(dynamic raw);
+
+  @protected
+  FramingConfig dco_decode_box_autoadd_framing_config(dynamic raw);
+
+  @protected
+  HandshakePayload dco_decode_box_autoadd_handshake_payload(dynamic raw);
+
+  @protected
+  LivenessConfig dco_decode_box_autoadd_liveness_config(dynamic raw);
+
+  @protected
+  ServerConfig dco_decode_box_autoadd_server_config(dynamic raw);
+
+  @protected
+  SliderProfile dco_decode_box_autoadd_slider_profile(dynamic raw);
+
+  @protected
+  SyncConfig dco_decode_box_autoadd_sync_config(dynamic raw);
+
+  @protected
+  CardEntry dco_decode_card_entry(dynamic raw);
+
+  @protected
+  CardMode dco_decode_card_mode(dynamic raw);
+
+  @protected
+  ClientSession dco_decode_client_session(dynamic raw);
+
+  @protected
+  CoinCadence dco_decode_coin_cadence(dynamic raw);
+
+  @protected
+  ConnectionStats dco_decode_connection_stats(dynamic raw);
+
+  @protected
+  DebounceConfig dco_decode_debounce_config(dynamic raw);
+
+  @protected
+  DiscoveredServer dco_decode_discovered_server(dynamic raw);
+
+  @protected
+  FilterStats dco_decode_filter_stats(dynamic raw);
+
+  @protected
+  FilterTarget dco_decode_filter_target(dynamic raw);
+
+  @protected
+  FramingConfig dco_decode_framing_config(dynamic raw);
+
+  @protected
+  FramingPolicy dco_decode_framing_policy(dynamic raw);
+
+  @protected
+  FramingStats dco_decode_framing_stats(dynamic raw);
+
+  @protected
+  HandshakePayload dco_decode_handshake_payload(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  int dco_decode_i_8(dynamic raw);
+
+  @protected
+  LatencySource dco_decode_latency_source(dynamic raw);
+
+  @protected
+  LatencyStats dco_decode_latency_stats(dynamic raw);
+
+  @protected
+  LinkState dco_decode_link_state(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<bool> dco_decode_list_bool(dynamic raw);
+
+  @protected
+  List<CardEntry> dco_decode_list_card_entry(dynamic raw);
+
+  @protected
+  List<ClientSession> dco_decode_list_client_session(dynamic raw);
+
+  @protected
+  List<ConnectionStats> dco_decode_list_connection_stats(dynamic raw);
+
+  @protected
+  List<DiscoveredServer> dco_decode_list_discovered_server(dynamic raw);
+
+  @protected
+  List<LatencyStats> dco_decode_list_latency_stats(dynamic raw);
+
+  @protected
+  List<LogEntry> dco_decode_list_log_entry(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  LivenessConfig dco_decode_liveness_config(dynamic raw);
+
+  @protected
+  LogCategory dco_decode_log_category(dynamic raw);
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw);
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  DebounceConfig? dco_decode_opt_box_autoadd_debounce_config(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  QrMatrix dco_decode_qr_matrix(dynamic raw);
+
+  @protected
+  SensorData dco_decode_sensor_data(dynamic raw);
+
+  @protected
+  ServerConfig dco_decode_server_config(dynamic raw);
+
+  @protected
+  ServerEvent dco_decode_server_event(dynamic raw);
+
+  @protected
+  SliderProfile dco_decode_slider_profile(dynamic raw);
+
+  @protected
+  SyncConfig dco_decode_sync_config(dynamic raw);
+
+  @protected
+  SyncState dco_decode_sync_state(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  U8Array10 dco_decode_u_8_array_10(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  ServerHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ServerHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ServerHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<SensorData> sse_decode_StreamSink_sensor_data_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ServerEvent> sse_decode_StreamSink_server_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AirGestureConfig sse_decode_air_gesture_config(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AirGestureConfig sse_decode_box_autoadd_air_gesture_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CardMode sse_decode_box_autoadd_card_mode(SseDeserializer deserializer);
+
+  @protected
+  CoinCadence sse_decode_box_autoadd_coin_cadence(SseDeserializer deserializer);
+
+  @protected
+  DebounceConfig sse_decode_box_autoadd_debounce_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FramingConfig sse_decode_box_autoadd_framing_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HandshakePayload sse_decode_box_autoadd_handshake_payload(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LivenessConfig sse_decode_box_autoadd_liveness_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ServerConfig sse_decode_box_autoadd_server_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SliderProfile sse_decode_box_autoadd_slider_profile(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SyncConfig sse_decode_box_autoadd_sync_config(SseDeserializer deserializer);
+
+  @protected
+  CardEntry sse_decode_card_entry(SseDeserializer deserializer);
+
+  @protected
+  CardMode sse_decode_card_mode(SseDeserializer deserializer);
+
+  @protected
+  ClientSession sse_decode_client_session(SseDeserializer deserializer);
+
+  @protected
+  CoinCadence sse_decode_coin_cadence(SseDeserializer deserializer);
+
+  @protected
+  ConnectionStats sse_decode_connection_stats(SseDeserializer deserializer);
+
+  @protected
+  DebounceConfig sse_decode_debounce_config(SseDeserializer deserializer);
+
+  @protected
+  DiscoveredServer sse_decode_discovered_server(SseDeserializer deserializer);
+
+  @protected
+  FilterStats sse_decode_filter_stats(SseDeserializer deserializer);
+
+  @protected
+  FilterTarget sse_decode_filter_target(SseDeserializer deserializer);
+
+  @protected
+  FramingConfig sse_decode_framing_config(SseDeserializer deserializer);
+
+  @protected
+  FramingPolicy sse_decode_framing_policy(SseDeserializer deserializer);
+
+  @protected
+  FramingStats sse_decode_framing_stats(SseDeserializer deserializer);
+
+  @protected
+  HandshakePayload sse_decode_handshake_payload(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_8(SseDeserializer deserializer);
+
+  @protected
+  LatencySource sse_decode_latency_source(SseDeserializer deserializer);
+
+  @protected
+  LatencyStats sse_decode_latency_stats(SseDeserializer deserializer);
+
+  @protected
+  LinkState sse_decode_link_state(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<bool> sse_decode_list_bool(SseDeserializer deserializer);
+
+  @protected
+  List<CardEntry> sse_decode_list_card_entry(SseDeserializer deserializer);
+
+  @protected
+  List<ClientSession> sse_decode_list_client_session(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ConnectionStats> sse_decode_list_connection_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DiscoveredServer> sse_decode_list_discovered_server(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LatencyStats> sse_decode_list_latency_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LogEntry> sse_decode_list_log_entry(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  LivenessConfig sse_decode_liveness_config(SseDeserializer deserializer);
+
+  @protected
+  LogCategory sse_decode_log_category(SseDeserializer deserializer);
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  DebounceConfig? sse_decode_opt_box_autoadd_debounce_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  QrMatrix sse_decode_qr_matrix(SseDeserializer deserializer);
+
+  @protected
+  SensorData sse_decode_sensor_data(SseDeserializer deserializer);
+
+  @protected
+  ServerConfig sse_decode_server_config(SseDeserializer deserializer);
+
+  @protected
+  ServerEvent sse_decode_server_event(SseDeserializer deserializer);
+
+  @protected
+  SliderProfile sse_decode_slider_profile(SseDeserializer deserializer);
+
+  @protected
+  SyncConfig sse_decode_sync_config(SseDeserializer deserializer);
+
+  @protected
+  SyncState sse_decode_sync_state(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  U8Array10 sse_decode_u_8_array_10(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    ServerHandle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    ServerHandle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(
+    ServerHandle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_log_entry_Sse(
+    RustStreamSink<LogEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_sensor_data_Sse(
+    RustStreamSink<SensorData> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_server_event_Sse(
+    RustStreamSink<ServerEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_air_gesture_config(
+    AirGestureConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_air_gesture_config(
+    AirGestureConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_card_mode(
+    CardMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_coin_cadence(
+    CoinCadence self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_debounce_config(
+    DebounceConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_framing_config(
+    FramingConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_handshake_payload(
+    HandshakePayload self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_liveness_config(
+    LivenessConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_server_config(
+    ServerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_slider_profile(
+    SliderProfile self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_sync_config(
+    SyncConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_card_entry(CardEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_card_mode(CardMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_client_session(ClientSession self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_coin_cadence(CoinCadence self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_connection_stats(
+    ConnectionStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_debounce_config(
+    DebounceConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_discovered_server(
+    DiscoveredServer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_filter_stats(FilterStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_filter_target(FilterTarget self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_framing_config(FramingConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_framing_policy(FramingPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_framing_stats(FramingStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_handshake_payload(
+    HandshakePayload self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_latency_source(LatencySource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_latency_stats(LatencyStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_link_state(LinkState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_bool(List<bool> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_card_entry(
+    List<CardEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_client_session(
+    List<ClientSession> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_connection_stats(
+    List<ConnectionStats> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_discovered_server(
+    List<DiscoveredServer> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_latency_stats(
+    List<LatencyStats> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_log_entry(List<LogEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_liveness_config(
+    LivenessConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_log_category(LogCategory self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_debounce_config(
+    DebounceConfig? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_qr_matrix(QrMatrix self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sensor_data(SensorData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_server_config(ServerConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_server_event(ServerEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_slider_profile(SliderProfile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_config(SyncConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_state(SyncState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8_array_10(U8Array10 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+}
+
+// Section: wire_class
+
+class RustLibWire implements BaseWire {
+  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(ptr);
+}
+
+@JS('wasm_bindgen')
+external RustLibWasmModule get wasmModule;
+
+@JS()
+@anonymous
+extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(int ptr);
+
+  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerServerHandle(int ptr);
+}
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class AirGestureConfig {
+  final bool enabled;
+  /// Longest time from the bottom cell to the top cell of a key that still counts as a swipe.
+  final int maxSwipeMs;
+  /// Keys that must swipe within one `max_swipe_ms` window to trigger.
+  final int minKeys;
+  /// Time for the synthesised hand to rise from the lowest to the highest sensor.
+  final int riseMs;
+  /// Time all sensors stay blocked after the rise.
+  final int holdMs;
+
+  const AirGestureConfig({
+    required this.enabled,
+    required this.maxSwipeMs,
+    required this.minKeys,
+    required this.riseMs,
+    required this.holdMs,
+  });
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^
+      maxSwipeMs.hashCode ^
+      minKeys.hashCode ^
+      riseMs.hashCode ^
+      holdMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AirGestureConfig &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          maxSwipeMs == other.maxSwipeMs &&
+          minKeys == other.minKeys &&
+          riseMs == other.riseMs &&
+          holdMs == other.holdMs;
+}
@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+enum LatencySource {
+  /// Socket receive to shared-memory write.
+  receive,
+  /// Client timestamp to shared-memory write, less the fastest transit seen from that
+  /// client: the fixed part of the one-way delay cannot be told apart from clock offset.
+  client,
+  ;
+}
+
+class LatencyStats {
+  /// "udp", "tcp", "tcp-loopback" (adb reverse and local relays) or "unix".
+  final String transport;
+  final LatencySource source;
+  final BigInt count;
+  final BigInt meanUs;
+  final BigInt p50Us;
+  final BigInt p90Us;
+  final BigInt p99Us;
+  final BigInt maxUs;
+
+  const LatencyStats({
+    required this.transport,
+    required this.source,
+    required this.count,
+    required this.meanUs,
+    required this.p50Us,
+    required this.p90Us,
+    required this.p99Us,
+    required this.maxUs,
+  });
+
+  @override
+  int get hashCode =>
+      transport.hashCode ^
+      source.hashCode ^
+      count.hashCode ^
+      meanUs.hashCode ^
+      p50Us.hashCode ^
+      p90Us.hashCode ^
+      p99Us.hashCode ^
+      maxUs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LatencyStats &&
+          runtimeType == other.runtimeType &&
+          transport == other.transport &&
+          source == other.source &&
+          count == other.count &&
+          meanUs == other.meanUs &&
+          p50Us == other.p50Us &&
+          p90Us == other.p90Us &&
+          p99Us == other.p99Us &&
+          maxUs == other.maxUs;
+}
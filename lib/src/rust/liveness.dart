@@ -0,0 +1,76 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class ClientSession {
+  final String client;
+  final LinkState state;
+  final BigInt lastSeenMs;
+
+  const ClientSession({
+    required this.client,
+    required this.state,
+    required this.lastSeenMs,
+  });
+
+  @override
+  int get hashCode => client.hashCode ^ state.hashCode ^ lastSeenMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ClientSession &&
+          runtimeType == other.runtimeType &&
+          client == other.client &&
+          state == other.state &&
+          lastSeenMs == other.lastSeenMs;
+}
+
+enum LinkState {
+  /// No client has been seen yet.
+  disconnected,
+  connected,
+  /// Quiet for longer than `stale_ms`.
+  stale,
+  /// A keepalive client quiet for longer than `lost_ms`, or the TCP connection closed.
+  lost,
+  ;
+}
+
+class LivenessConfig {
+  /// Interval between server keepalives to each client that sends keepalives.
+  final int keepaliveMs;
+  final int staleMs;
+  /// Only applies to clients that send keepalives; others stay stale until they disconnect.
+  final int lostMs;
+  /// Release held inputs when the client that sent them is lost.
+  final bool releaseOnLoss;
+
+  const LivenessConfig({
+    required this.keepaliveMs,
+    required this.staleMs,
+    required this.lostMs,
+    required this.releaseOnLoss,
+  });
+
+  @override
+  int get hashCode =>
+      keepaliveMs.hashCode ^
+      staleMs.hashCode ^
+      lostMs.hashCode ^
+      releaseOnLoss.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LivenessConfig &&
+          runtimeType == other.runtimeType &&
+          keepaliveMs == other.keepaliveMs &&
+          staleMs == other.staleMs &&
+          lostMs == other.lostMs &&
+          releaseOnLoss == other.releaseOnLoss;
+}
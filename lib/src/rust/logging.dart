@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+enum LogCategory {
+  connection,
+  handshake,
+  transport,
+  protocol,
+  shmem,
+  ;
+}
+
+class LogEntry {
+  /// Local time, RFC 3339 with milliseconds.
+  final String timestamp;
+  final LogLevel level;
+  final LogCategory category;
+  final String message;
+
+  const LogEntry({
+    required this.timestamp,
+    required this.level,
+    required this.category,
+    required this.message,
+  });
+
+  @override
+  int get hashCode =>
+      timestamp.hashCode ^
+      level.hashCode ^
+      category.hashCode ^
+      message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogEntry &&
+          runtimeType == other.runtimeType &&
+          timestamp == other.timestamp &&
+          level == other.level &&
+          category == other.category &&
+          message == other.message;
+}
+
+enum LogLevel {
+  debug,
+  info,
+  warn,
+  error,
+  ;
+}
@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class QrMatrix {
+  /// Modules per side, including the quiet-zone border.
+  final int size;
+  /// Row-major, `true` for a dark module.
+  final List<bool> modules;
+
+  const QrMatrix({
+    required this.size,
+    required this.modules,
+  });
+
+  @override
+  int get hashCode => size.hashCode ^ modules.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QrMatrix &&
+          runtimeType == other.runtimeType &&
+          size == other.size &&
+          modules == other.modules;
+}
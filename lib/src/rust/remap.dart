@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class SliderProfile {
+  /// Input cells ignored as dead touch zones, before any other stage.
+  final Uint8List maskedCells;
+  /// Treat input cells 0..16 as keys, each driving output cells `2k` and `2k + 1`.
+  final bool sixteenKey;
+  /// Output cell `i` takes input cell `table[i]`; `REMAP_NONE` leaves it released.
+  final Uint8List? table;
+  final bool reverse;
+  /// Shifts cells towards higher indices; cells shifted past either end are dropped.
+  final int offset;
+
+  const SliderProfile({
+    required this.maskedCells,
+    required this.sixteenKey,
+    required this.table,
+    required this.reverse,
+    required this.offset,
+  });
+
+  @override
+  int get hashCode =>
+      maskedCells.hashCode ^
+      sixteenKey.hashCode ^
+      table.hashCode ^
+      reverse.hashCode ^
+      offset.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SliderProfile &&
+          runtimeType == other.runtimeType &&
+          maskedCells == other.maskedCells &&
+          sixteenKey == other.sixteenKey &&
+          table == other.table &&
+          reverse == other.reverse &&
+          offset == other.offset;
+}
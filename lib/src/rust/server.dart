@@ -0,0 +1,68 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'server.freezed.dart';
+
+@freezed
+sealed class CardMode with _$CardMode {
+  const CardMode._();
+
+  /// Hold the card for a fixed time after each tap.
+  const factory CardMode.fixedHold({
+    required int holdMs,
+  }) = CardMode_FixedHold;
+  /// Hold the card until the client sends any non-card packet.
+  const factory CardMode.untilNextPacket() = CardMode_UntilNextPacket;
+  /// Each tap of the same card alternates between insert and remove.
+  const factory CardMode.toggle() = CardMode_Toggle;
+  /// Hold the card until the game sets the acknowledge byte, or until the timeout.
+  const factory CardMode.untilAck({
+    required int timeoutMs,
+  }) = CardMode_UntilAck;
+}
+
+class ServerConfig {
+  final String name;
+  final int port;
+  /// "udp", "tcp" or "unix".
+  final String protocol;
+  final String shmemName;
+  final BigInt shmemSize;
+  /// Socket path for the "unix" protocol; `None` for `rustnithm-<name>.sock` in the temp directory.
+  final String? socketPath;
+
+  const ServerConfig({
+    required this.name,
+    required this.port,
+    required this.protocol,
+    required this.shmemName,
+    required this.shmemSize,
+    required this.socketPath,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      port.hashCode ^
+      protocol.hashCode ^
+      shmemName.hashCode ^
+      shmemSize.hashCode ^
+      socketPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ServerConfig &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          port == other.port &&
+          protocol == other.protocol &&
+          shmemName == other.shmemName &&
+          shmemSize == other.shmemSize &&
+          socketPath == other.socketPath;
+}
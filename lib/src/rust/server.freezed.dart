@@ -0,0 +1,378 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'server.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$CardMode {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CardMode);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'CardMode()';
+}
+
+
+}
+
+/// @nodoc
+class $CardModeCopyWith<$Res>  {
+$CardModeCopyWith(CardMode _, $Res Function(CardMode) __);
+}
+
+
+
+/// Adds pattern-matching-related methods to [CardMode].
+extension CardModePatterns on CardMode {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( CardMode_FixedHold value)?  fixedHold,TResult Function( CardMode_UntilNextPacket value)?  untilNextPacket,TResult Function( CardMode_Toggle value)?  toggle,TResult Function( CardMode_UntilAck value)?  untilAck,required TResult orElse(),){
+final _that = this;
+switch (_that) {
+case CardMode_FixedHold() when fixedHold != null:
+return fixedHold(_that);case CardMode_UntilNextPacket() when untilNextPacket != null:
+return untilNextPacket(_that);case CardMode_Toggle() when toggle != null:
+return toggle(_that);case CardMode_UntilAck() when untilAck != null:
+return untilAck(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(required TResult Function( CardMode_FixedHold value)  fixedHold,required TResult Function( CardMode_UntilNextPacket value)  untilNextPacket,required TResult Function( CardMode_Toggle value)  toggle,required TResult Function( CardMode_UntilAck value)  untilAck,){
+final _that = this;
+switch (_that) {
+case CardMode_FixedHold():
+return fixedHold(_that);case CardMode_UntilNextPacket():
+return untilNextPacket(_that);case CardMode_Toggle():
+return toggle(_that);case CardMode_UntilAck():
+return untilAck(_that);case _:
+  throw StateError('Unexpected subclass');
+
+}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult Function( CardMode_FixedHold value)?  fixedHold,TResult Function( CardMode_UntilNextPacket value)?  untilNextPacket,TResult Function( CardMode_Toggle value)?  toggle,TResult Function( CardMode_UntilAck value)?  untilAck,){
+final _that = this;
+switch (_that) {
+case CardMode_FixedHold() when fixedHold != null:
+return fixedHold(_that);case CardMode_UntilNextPacket() when untilNextPacket != null:
+return untilNextPacket(_that);case CardMode_Toggle() when toggle != null:
+return toggle(_that);case CardMode_UntilAck() when untilAck != null:
+return untilAck(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int holdMs)?  fixedHold,TResult Function()?  untilNextPacket,TResult Function()?  toggle,TResult Function( int timeoutMs)?  untilAck,required TResult orElse(),) {final _that = this;
+switch (_that) {
+case CardMode_FixedHold() when fixedHold != null:
+return fixedHold(_that.holdMs);case CardMode_UntilNextPacket() when untilNextPacket != null:
+return untilNextPacket();case CardMode_Toggle() when toggle != null:
+return toggle();case CardMode_UntilAck() when untilAck != null:
+return untilAck(_that.timeoutMs);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(required TResult Function( int holdMs)  fixedHold,required TResult Function()  untilNextPacket,required TResult Function()  toggle,required TResult Function( int timeoutMs)  untilAck,) {final _that = this;
+switch (_that) {
+case CardMode_FixedHold():
+return fixedHold(_that.holdMs);case CardMode_UntilNextPacket():
+return untilNextPacket();case CardMode_Toggle():
+return toggle();case CardMode_UntilAck():
+return untilAck(_that.timeoutMs);case _:
+  throw StateError('Unexpected subclass');
+
+}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult Function( int holdMs)?  fixedHold,TResult Function()?  untilNextPacket,TResult Function()?  toggle,TResult Function( int timeoutMs)?  untilAck,) {final _that = this;
+switch (_that) {
+case CardMode_FixedHold() when fixedHold != null:
+return fixedHold(_that.holdMs);case CardMode_UntilNextPacket() when untilNextPacket != null:
+return untilNextPacket();case CardMode_Toggle() when toggle != null:
+return toggle();case CardMode_UntilAck() when untilAck != null:
+return untilAck(_that.timeoutMs);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class CardMode_FixedHold extends CardMode {
+  const CardMode_FixedHold({required this.holdMs}): super._();
+  
+
+ final  int holdMs;
+
+/// Create a copy of CardMode
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CardMode_FixedHoldCopyWith<CardMode_FixedHold> get copyWith => _$CardMode_FixedHoldCopyWithImpl<CardMode_FixedHold>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CardMode_FixedHold&&(identical(other.holdMs, holdMs) || other.holdMs == holdMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,holdMs);
+
+@override
+String toString() {
+  return 'CardMode.fixedHold(holdMs: $holdMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $CardMode_FixedHoldCopyWith<$Res> implements $CardModeCopyWith<$Res> {
+  factory $CardMode_FixedHoldCopyWith(CardMode_FixedHold value, $Res Function(CardMode_FixedHold) _then) = _$CardMode_FixedHoldCopyWithImpl;
+@useResult
+$Res call({
+ int holdMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$CardMode_FixedHoldCopyWithImpl<$Res>
+    implements $CardMode_FixedHoldCopyWith<$Res> {
+  _$CardMode_FixedHoldCopyWithImpl(this._self, this._then);
+
+  final CardMode_FixedHold _self;
+  final $Res Function(CardMode_FixedHold) _then;
+
+/// Create a copy of CardMode
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? holdMs = null,}) {
+  return _then(CardMode_FixedHold(
+holdMs: null == holdMs ? _self.holdMs : holdMs // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class CardMode_UntilNextPacket extends CardMode {
+  const CardMode_UntilNextPacket(): super._();
+  
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CardMode_UntilNextPacket);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'CardMode.untilNextPacket()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class CardMode_Toggle extends CardMode {
+  const CardMode_Toggle(): super._();
+  
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CardMode_Toggle);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'CardMode.toggle()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class CardMode_UntilAck extends CardMode {
+  const CardMode_UntilAck({required this.timeoutMs}): super._();
+  
+
+ final  int timeoutMs;
+
+/// Create a copy of CardMode
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CardMode_UntilAckCopyWith<CardMode_UntilAck> get copyWith => _$CardMode_UntilAckCopyWithImpl<CardMode_UntilAck>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CardMode_UntilAck&&(identical(other.timeoutMs, timeoutMs) || other.timeoutMs == timeoutMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,timeoutMs);
+
+@override
+String toString() {
+  return 'CardMode.untilAck(timeoutMs: $timeoutMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $CardMode_UntilAckCopyWith<$Res> implements $CardModeCopyWith<$Res> {
+  factory $CardMode_UntilAckCopyWith(CardMode_UntilAck value, $Res Function(CardMode_UntilAck) _then) = _$CardMode_UntilAckCopyWithImpl;
+@useResult
+$Res call({
+ int timeoutMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$CardMode_UntilAckCopyWithImpl<$Res>
+    implements $CardMode_UntilAckCopyWith<$Res> {
+  _$CardMode_UntilAckCopyWithImpl(this._self, this._then);
+
+  final CardMode_UntilAck _self;
+  final $Res Function(CardMode_UntilAck) _then;
+
+/// Create a copy of CardMode
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? timeoutMs = null,}) {
+  return _then(CardMode_UntilAck(
+timeoutMs: null == timeoutMs ? _self.timeoutMs : timeoutMs // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,76 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class SensorData {
+  final Uint8List air;
+  final Uint8List slider;
+  final int coin;
+  final int service;
+  final int test;
+  final U8Array10 code;
+  /// Set while a card is held, even one whose identifier is all zeros.
+  final bool cardPresent;
+  final int buttons;
+  final bool active;
+  final String? client;
+
+  const SensorData({
+    required this.air,
+    required this.slider,
+    required this.coin,
+    required this.service,
+    required this.test,
+    required this.code,
+    required this.cardPresent,
+    required this.buttons,
+    required this.active,
+    required this.client,
+  });
+
+  @override
+  int get hashCode =>
+      air.hashCode ^
+      slider.hashCode ^
+      coin.hashCode ^
+      service.hashCode ^
+      test.hashCode ^
+      code.hashCode ^
+      cardPresent.hashCode ^
+      buttons.hashCode ^
+      active.hashCode ^
+      client.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SensorData &&
+          runtimeType == other.runtimeType &&
+          air == other.air &&
+          slider == other.slider &&
+          coin == other.coin &&
+          service == other.service &&
+          test == other.test &&
+          code == other.code &&
+          cardPresent == other.cardPresent &&
+          buttons == other.buttons &&
+          active == other.active &&
+          client == other.client;
+}
+
+class U8Array10 extends NonGrowableListView<int> {
+  static const arraySize = 10;
+
+  @internal
+  Uint8List get inner => _inner;
+  final Uint8List _inner;
+
+  U8Array10(this._inner) : assert(_inner.length == arraySize), super(_inner);
+
+  U8Array10.init() : this(Uint8List(arraySize));
+}
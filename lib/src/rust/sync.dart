@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'sync.freezed.dart';
+
+class SyncConfig {
+  /// Interval between handshake retransmissions over UDP.
+  final int retransmitMs;
+  /// Time to wait for the client before rolling back.
+  final int timeoutMs;
+
+  const SyncConfig({
+    required this.retransmitMs,
+    required this.timeoutMs,
+  });
+
+  @override
+  int get hashCode => retransmitMs.hashCode ^ timeoutMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncConfig &&
+          runtimeType == other.runtimeType &&
+          retransmitMs == other.retransmitMs &&
+          timeoutMs == other.timeoutMs;
+}
+
+@freezed
+sealed class SyncState with _$SyncState {
+  const SyncState._();
+
+  const factory SyncState.idle() = SyncState_Idle;
+  const factory SyncState.pending({
+    required bool target,
+    required bool previous,
+    required int attempts,
+  }) = SyncState_Pending;
+}
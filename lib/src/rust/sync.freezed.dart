@@ -0,0 +1,277 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'sync.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$SyncState {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SyncState);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SyncState()';
+}
+
+
+}
+
+/// @nodoc
+class $SyncStateCopyWith<$Res>  {
+$SyncStateCopyWith(SyncState _, $Res Function(SyncState) __);
+}
+
+
+
+/// Adds pattern-matching-related methods to [SyncState].
+extension SyncStatePatterns on SyncState {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( SyncState_Idle value)?  idle,TResult Function( SyncState_Pending value)?  pending,required TResult orElse(),){
+final _that = this;
+switch (_that) {
+case SyncState_Idle() when idle != null:
+return idle(_that);case SyncState_Pending() when pending != null:
+return pending(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(required TResult Function( SyncState_Idle value)  idle,required TResult Function( SyncState_Pending value)  pending,){
+final _that = this;
+switch (_that) {
+case SyncState_Idle():
+return idle(_that);case SyncState_Pending():
+return pending(_that);case _:
+  throw StateError('Unexpected subclass');
+
+}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult Function( SyncState_Idle value)?  idle,TResult Function( SyncState_Pending value)?  pending,){
+final _that = this;
+switch (_that) {
+case SyncState_Idle() when idle != null:
+return idle(_that);case SyncState_Pending() when pending != null:
+return pending(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function()?  idle,TResult Function( bool target,  bool previous,  int attempts)?  pending,required TResult orElse(),) {final _that = this;
+switch (_that) {
+case SyncState_Idle() when idle != null:
+return idle();case SyncState_Pending() when pending != null:
+return pending(_that.target,_that.previous,_that.attempts);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(required TResult Function()  idle,required TResult Function( bool target,  bool previous,  int attempts)  pending,) {final _that = this;
+switch (_that) {
+case SyncState_Idle():
+return idle();case SyncState_Pending():
+return pending(_that.target,_that.previous,_that.attempts);case _:
+  throw StateError('Unexpected subclass');
+
+}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult Function()?  idle,TResult Function( bool target,  bool previous,  int attempts)?  pending,) {final _that = this;
+switch (_that) {
+case SyncState_Idle() when idle != null:
+return idle();case SyncState_Pending() when pending != null:
+return pending(_that.target,_that.previous,_that.attempts);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class SyncState_Idle extends SyncState {
+  const SyncState_Idle(): super._();
+  
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SyncState_Idle);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SyncState.idle()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SyncState_Pending extends SyncState {
+  const SyncState_Pending({required this.target, required this.previous, required this.attempts}): super._();
+  
+
+ final  bool target;
+ final  bool previous;
+ final  int attempts;
+
+/// Create a copy of SyncState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SyncState_PendingCopyWith<SyncState_Pending> get copyWith => _$SyncState_PendingCopyWithImpl<SyncState_Pending>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SyncState_Pending&&(identical(other.target, target) || other.target == target)&&(identical(other.previous, previous) || other.previous == previous)&&(identical(other.attempts, attempts) || other.attempts == attempts));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,target,previous,attempts);
+
+@override
+String toString() {
+  return 'SyncState.pending(target: $target, previous: $previous, attempts: $attempts)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SyncState_PendingCopyWith<$Res> implements $SyncStateCopyWith<$Res> {
+  factory $SyncState_PendingCopyWith(SyncState_Pending value, $Res Function(SyncState_Pending) _then) = _$SyncState_PendingCopyWithImpl;
+@useResult
+$Res call({
+ bool target, bool previous, int attempts
+});
+
+
+
+
+}
+/// @nodoc
+class _$SyncState_PendingCopyWithImpl<$Res>
+    implements $SyncState_PendingCopyWith<$Res> {
+  _$SyncState_PendingCopyWithImpl(this._self, this._then);
+
+  final SyncState_Pending _self;
+  final $Res Function(SyncState_Pending) _then;
+
+/// Create a copy of SyncState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? target = null,Object? previous = null,Object? attempts = null,}) {
+  return _then(SyncState_Pending(
+target: null == target ? _self.target : target // ignore: cast_nullable_to_non_nullable
+as bool,previous: null == previous ? _self.previous : previous // ignore: cast_nullable_to_non_nullable
+as bool,attempts: null == attempts ? _self.attempts : attempts // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+// dart format on
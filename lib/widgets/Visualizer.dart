@@ -38,14 +38,6 @@ class Visualizer extends StatelessWidget {
   }
 
   String _decodeBcd(Uint8List bcdBytes) {
-    bool hasData = false;
-    for (int i = 0; i < bcdBytes.length; i++) {
-      if (bcdBytes[i] != 0) {
-        hasData = true;
-        break;
-      }
-    }
-    if (!hasData) return "";
     final List<int> chars = List<int>.filled(20, 0);
 
     for (int i = 0; i < 10 && i < bcdBytes.length; i++) {
@@ -62,13 +54,14 @@ class Visualizer extends StatelessWidget {
 
   Widget _buildAirSection(
       BuildContext context, ServerState state, bool isDark) {
-    final String decodedCode = _decodeBcd(state.code);
+    final String decodedCode =
+        state.cardPresent ? _decodeBcd(state.code) : "";
 
     final sideButtons = [
       {'label': 'COIN', 'val': state.coin},
       {'label': 'SERV', 'val': state.service},
       {'label': 'TEST', 'val': state.test},
-      {'label': 'CODE', 'val': decodedCode.isNotEmpty ? 1 : 0}
+      {'label': 'CODE', 'val': state.cardPresent ? 1 : 0}
     ];
 
     final baseColor = isDark
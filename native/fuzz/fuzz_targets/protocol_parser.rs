@@ -0,0 +1,52 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rustnithm_native::delta::DeltaDecoder;
+use rustnithm_native::protocol::{PacketType, ProtocolParser, CONTROL_FLAG_BATCH};
+use std::net::SocketAddr;
+
+// A sequence of packets, each `len` then `len` bytes, dispatched like the server does.
+fuzz_target!(|data: &[u8]| {
+    let src = SocketAddr::from(([127, 0, 0, 1], 1));
+    let mut decoder = DeltaDecoder::new();
+
+    let mut rest = data;
+    while let Some((&len, tail)) = rest.split_first() {
+        let take = std::cmp::min(len as usize, tail.len());
+        let (packet, next) = tail.split_at(take);
+        rest = next;
+
+        let (&header_byte, payload) = match packet.split_first() {
+            Some(split) => split,
+            None => continue,
+        };
+        let header = match ProtocolParser::parse_header(header_byte) {
+            Some(h) => h,
+            None => continue,
+        };
+        match header.packet_type {
+            PacketType::Handshake => {
+                if let Some(&byte) = payload.first() {
+                    ProtocolParser::parse_handshake(byte);
+                }
+            }
+            PacketType::Button => {
+                ProtocolParser::parse_button(header.flags, payload);
+            }
+            PacketType::Control => {
+                let payload = match ProtocolParser::split_timestamp(header.flags, payload) {
+                    Ok((_, payload)) => payload,
+                    Err(_) => continue,
+                };
+                if header.flags & CONTROL_FLAG_BATCH != 0 {
+                    let _ = ProtocolParser::parse_control_batch(payload);
+                } else if let Ok(frame) = ProtocolParser::parse_control_frame(header.flags, payload) {
+                    decoder.decode(src, frame);
+                }
+            }
+            PacketType::Card => {
+                let _ = ProtocolParser::parse_card_id(header.flags, payload);
+            }
+        }
+    }
+});
@@ -0,0 +1,29 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rustnithm_native::framing::{FrameDecoder, FramingConfig, FramingPolicy};
+
+// data[0..2]: max frame, data[2]: policy; the rest is fed in reads sized by its own bytes.
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 3 {
+        return;
+    }
+    let max_frame = u16::from_le_bytes([data[0], data[1]]) as u32 % 2048;
+    let policy = if data[2] & 1 == 0 { FramingPolicy::Disconnect } else { FramingPolicy::Resync };
+    let mut decoder = FrameDecoder::new(FramingConfig { max_frame, policy });
+
+    let mut rest = &data[3..];
+    while let Some((&size, tail)) = rest.split_first() {
+        let take = std::cmp::min(size as usize % 64 + 1, tail.len());
+        decoder.push(&tail[..take]);
+        rest = &tail[take..];
+        loop {
+            match decoder.next_frame() {
+                Ok(Some(frame)) => assert!(!frame.is_empty() && frame.len() <= max_frame as usize),
+                Ok(None) => break,
+                Err(_) => return,
+            }
+        }
+        assert!(decoder.buffered() <= max_frame as usize + 4 + 64);
+    }
+});
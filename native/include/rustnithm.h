@@ -0,0 +1,159 @@
+#ifndef RUSTNITHM_H
+#define RUSTNITHM_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Opaque server instance.
+ */
+typedef struct RnsServer RnsServer;
+
+typedef struct RnsConfig {
+  /**
+   * Server name; null for "default".
+   */
+  const char *name;
+  uint16_t port;
+  /**
+   * Non-zero for TCP, zero for UDP.
+   */
+  uint8_t tcp;
+  /**
+   * Shared-memory name; null for the default mapping.
+   */
+  const char *shmem_name;
+  /**
+   * Shared-memory size in bytes; 0 for the default size.
+   */
+  size_t shmem_size;
+  /**
+   * Unix socket path; non-null selects the Unix transport and `port` and `tcp` are ignored.
+   */
+  const char *socket_path;
+} RnsConfig;
+
+typedef struct RnsState {
+  uint8_t air[6];
+  uint8_t slider[32];
+  uint8_t coin;
+  uint8_t service;
+  uint8_t test;
+  /**
+   * Full operator button mask.
+   */
+  uint8_t buttons;
+  uint8_t card_present;
+  uint8_t code[10];
+  uint8_t active;
+  uint8_t running;
+} RnsState;
+
+/**
+ * Called from server threads with a state that is only valid for the duration of the call.
+ */
+typedef void (*RnsStateCallback)(const struct RnsState *state, void *user_data);
+
+/**
+ * Message of the last failed call on this thread, or null. Valid until the next call that fails.
+ */
+const char *rns_last_error(void);
+
+/**
+ * Creates a server; `config` may be null for the defaults. Returns null on failure.
+ *
+ * # Safety
+ * `config` must be null or point to a valid `RnsConfig` whose strings are null or NUL-terminated.
+ */
+struct RnsServer *rns_server_create(const struct RnsConfig *config);
+
+/**
+ * Returns 0 on success, -1 on failure.
+ *
+ * # Safety
+ * `server` must come from `rns_server_create` and not have been destroyed.
+ */
+int32_t rns_server_start(struct RnsServer *server);
+
+/**
+ * # Safety
+ * `server` must come from `rns_server_create` and not have been destroyed.
+ */
+int32_t rns_server_stop(struct RnsServer *server);
+
+/**
+ * Stops the server if needed and frees it.
+ *
+ * # Safety
+ * `server` must be null or come from `rns_server_create`; it must not be used afterwards.
+ */
+void rns_server_destroy(struct RnsServer *server);
+
+/**
+ * # Safety
+ * `server` must come from `rns_server_create`.
+ */
+uint8_t rns_server_is_running(const struct RnsServer *server);
+
+/**
+ * UDP or TCP port the running server is bound to, which differs from `RnsConfig::port`
+ * when that is 0. Returns -1 when the server is stopped or on a Unix socket.
+ *
+ * # Safety
+ * `server` must come from `rns_server_create`.
+ */
+int32_t rns_server_bound_port(const struct RnsServer *server);
+
+/**
+ * Copies the current input state into `out`. Returns 0 on success, -1 on a null argument.
+ *
+ * # Safety
+ * `server` must come from `rns_server_create`; `out` must point to an `RnsState`.
+ */
+int32_t rns_server_poll_state(const struct RnsServer *server, struct RnsState *out);
+
+/**
+ * Registers a callback for every published state, replacing any previous one; pass null to remove it.
+ * Once this returns, a replaced callback is no longer running. The callback may call this
+ * function itself; it then finishes its current call and is not invoked again.
+ *
+ * # Safety
+ * `server` must come from `rns_server_create`; `user_data` must stay valid while registered.
+ */
+int32_t rns_server_set_state_callback(const struct RnsServer *server,
+                                      RnsStateCallback callback,
+                                      void *user_data);
+
+/**
+ * Loads the card library used by `rns_server_insert_card` from the JSON file at `path`,
+ * where a missing file is an empty library. Returns the number of cards, or -1 on failure.
+ *
+ * # Safety
+ * `path` must be a NUL-terminated string.
+ */
+int32_t rns_card_library_init(const char *path);
+
+/**
+ * Inserts a card from the card library by name for `duration_ms`.
+ *
+ * # Safety
+ * `server` must come from `rns_server_create`; `name` must be NUL-terminated.
+ */
+int32_t rns_server_insert_card(const struct RnsServer *server,
+                               const char *name,
+                               uint32_t duration_ms);
+
+/**
+ * Queues `count` coin presses; more than 99 is rejected. Returns 0 on success, -1 on failure.
+ *
+ * # Safety
+ * `server` must come from `rns_server_create`.
+ */
+int32_t rns_server_insert_credits(const struct RnsServer *server, uint32_t count);
+
+#endif  /* RUSTNITHM_H */
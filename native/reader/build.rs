@@ -0,0 +1,28 @@
+use std::env;
+use std::path::PathBuf;
+
+// The header is always generated into OUT_DIR; the checked-in copy under
+// include/ is only refreshed when building with `--features header`.
+fn main() {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=src/reader.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap_or_default();
+    match cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(crate_dir.join("src/lib.rs"))
+        .with_src(crate_dir.join("src/reader.rs"))
+        .generate()
+    {
+        Ok(bindings) => {
+            bindings.write_to_file(out_dir.join("rustnithm_reader.h"));
+            if env::var_os("CARGO_FEATURE_HEADER").is_some() {
+                bindings.write_to_file(crate_dir.join("include/rustnithm_reader.h"));
+            }
+        }
+        Err(e) => println!("cargo:warning=Failed to generate rustnithm_reader.h: {}", e),
+    }
+}
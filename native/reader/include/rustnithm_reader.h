@@ -0,0 +1,111 @@
+#ifndef RUSTNITHM_READER_H
+#define RUSTNITHM_READER_H
+
+/* Generated by cbindgen from src/lib.rs; do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * `RnsReaderCardSlot::kind` values; the same markers as `layout::CARD_KIND_*`.
+ */
+#define RNR_CARD_KIND_ACCESS_CODE 1
+
+#define RNR_CARD_KIND_FELICA 2
+
+#define RNR_CARD_KIND_MIFARE 3
+
+/**
+ * RGB triplets accepted by `rnr_write_leds`; `layout::LED_COUNT`.
+ */
+#define RNR_LED_COUNT 31
+
+/**
+ * Game-side view of the mapping the server writes.
+ */
+typedef struct RnsReader RnsReader;
+
+typedef struct RnsReaderButtons {
+  uint8_t test;
+  uint8_t service;
+  uint8_t coin;
+  uint8_t extra;
+} RnsReaderButtons;
+
+typedef struct RnsReaderCardSlot {
+  /**
+   * One of the `layout::CARD_KIND_*` markers.
+   */
+  uint8_t kind;
+  /**
+   * Identifier bytes used in `id`; 10 for access codes, 8 for FeliCa IDm.
+   */
+  uint8_t len;
+  uint8_t id[10];
+  uint8_t pmm[8];
+} RnsReaderCardSlot;
+
+/**
+ * Opens the mapping; `name` may be null for the default name. Returns null on failure.
+ *
+ * # Safety
+ * `name` must be null or a valid NUL-terminated string.
+ */
+struct RnsReader *rnr_open(const char *name);
+
+/**
+ * # Safety
+ * `reader` must be null or a pointer returned by `rnr_open` that has not been closed.
+ */
+void rnr_close(struct RnsReader *reader);
+
+/**
+ * Copies the 6 air sensors into `out`. Returns 0 on success, -1 on a null argument.
+ *
+ * # Safety
+ * `reader` must come from `rnr_open`; `out` must hold 6 bytes.
+ */
+int32_t rnr_poll_air(const struct RnsReader *reader, uint8_t *out);
+
+/**
+ * Copies the 32 slider cells into `out`. Returns 0 on success, -1 on a null argument.
+ *
+ * # Safety
+ * `reader` must come from `rnr_open`; `out` must hold 32 bytes.
+ */
+int32_t rnr_poll_slider(const struct RnsReader *reader, uint8_t *out);
+
+/**
+ * # Safety
+ * `reader` must come from `rnr_open`; `out` must point to a `RnsReaderButtons`.
+ */
+int32_t rnr_poll_buttons(const struct RnsReader *reader, struct RnsReaderButtons *out);
+
+/**
+ * Returns 1 and fills `out` when a card is present, 0 when none is, -1 on a null argument.
+ *
+ * # Safety
+ * `reader` must come from `rnr_open`; `out` must point to a `RnsReaderCardSlot`.
+ */
+int32_t rnr_poll_card(const struct RnsReader *reader, struct RnsReaderCardSlot *out);
+
+/**
+ * Tells the server the current card has been read.
+ *
+ * # Safety
+ * `reader` must come from `rnr_open`.
+ */
+int32_t rnr_ack_card(const struct RnsReader *reader);
+
+/**
+ * Writes `count` RGB triplets (at most 31) from `rgb` to the LED region.
+ *
+ * # Safety
+ * `reader` must come from `rnr_open`; `rgb` must hold `count * 3` bytes.
+ */
+int32_t rnr_write_leds(const struct RnsReader *reader, const uint8_t *rgb, size_t count);
+
+#endif  /* RUSTNITHM_READER_H */
@@ -0,0 +1,32 @@
+//! Byte layout of the shared-memory mapping written by the server and read by the game IO.
+
+use std::ops::Range;
+
+pub const DEFAULT_NAME: &str = "RustnithmSharedMemory";
+pub const DEFAULT_SIZE: usize = 1024;
+
+pub const AIR: Range<usize> = 0..6;
+pub const SLIDER: Range<usize> = 6..38;
+/// Slider LED colours written by the game: `LED_COUNT` RGB triplets.
+pub const LEDS: Range<usize> = 38..131;
+pub const LED_COUNT: usize = 31;
+
+pub const TEST: usize = 134;
+pub const SERVICE: usize = 135;
+pub const COIN: usize = 136;
+/// Operator buttons beyond test/service/coin, as the button mask shifted right by 3.
+pub const EXTRA_BUTTONS: usize = 137;
+
+pub const CARD_PRESENT: usize = 138;
+pub const CARD_KIND: usize = 139;
+pub const CARD_KIND_ACCESS_CODE: u8 = 1;
+pub const CARD_KIND_FELICA: u8 = 2;
+pub const CARD_KIND_MIFARE: u8 = 3;
+pub const CARD_ID: Range<usize> = 140..150;
+pub const CARD_PMM: Range<usize> = 150..158;
+pub const CARD_LEN: usize = 158;
+/// Set by the game once it has read the card.
+pub const CARD_ACK: usize = 159;
+
+/// Smallest mapping that holds every region above.
+pub const MIN_SIZE: usize = 160;
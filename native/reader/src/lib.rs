@@ -0,0 +1,175 @@
+pub mod layout;
+mod reader;
+
+pub use reader::{Buttons, CardSlot, ShmemReader};
+
+use std::ffi::{c_char, CStr};
+use std::ptr;
+
+/// `RnsReaderCardSlot::kind` values; the same markers as `layout::CARD_KIND_*`.
+pub const RNR_CARD_KIND_ACCESS_CODE: u8 = 1;
+pub const RNR_CARD_KIND_FELICA: u8 = 2;
+pub const RNR_CARD_KIND_MIFARE: u8 = 3;
+/// RGB triplets accepted by `rnr_write_leds`; `layout::LED_COUNT`.
+pub const RNR_LED_COUNT: usize = 31;
+
+/// Opens the mapping; `name` may be null for the default name. Returns null on failure.
+///
+/// # Safety
+/// `name` must be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn rnr_open(name: *const c_char) -> *mut ShmemReader {
+    let name = if name.is_null() {
+        layout::DEFAULT_NAME.to_string()
+    } else {
+        match CStr::from_ptr(name).to_str() {
+            Ok(s) => s.to_string(),
+            Err(_) => return ptr::null_mut(),
+        }
+    };
+    match ShmemReader::open(&name) {
+        Ok(reader) => Box::into_raw(Box::new(reader)),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// # Safety
+/// `reader` must be null or a pointer returned by `rnr_open` that has not been closed.
+#[no_mangle]
+pub unsafe extern "C" fn rnr_close(reader: *mut ShmemReader) {
+    if !reader.is_null() {
+        drop(Box::from_raw(reader));
+    }
+}
+
+/// Copies the 6 air sensors into `out`. Returns 0 on success, -1 on a null argument.
+///
+/// # Safety
+/// `reader` must come from `rnr_open`; `out` must hold 6 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rnr_poll_air(reader: *const ShmemReader, out: *mut u8) -> i32 {
+    match (reader.as_ref(), out.is_null()) {
+        (Some(r), false) => {
+            ptr::copy_nonoverlapping(r.air().as_ptr(), out, 6);
+            0
+        }
+        _ => -1,
+    }
+}
+
+/// Copies the 32 slider cells into `out`. Returns 0 on success, -1 on a null argument.
+///
+/// # Safety
+/// `reader` must come from `rnr_open`; `out` must hold 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rnr_poll_slider(reader: *const ShmemReader, out: *mut u8) -> i32 {
+    match (reader.as_ref(), out.is_null()) {
+        (Some(r), false) => {
+            ptr::copy_nonoverlapping(r.slider().as_ptr(), out, 32);
+            0
+        }
+        _ => -1,
+    }
+}
+
+/// # Safety
+/// `reader` must come from `rnr_open`; `out` must point to a `RnsReaderButtons`.
+#[no_mangle]
+pub unsafe extern "C" fn rnr_poll_buttons(reader: *const ShmemReader, out: *mut Buttons) -> i32 {
+    match (reader.as_ref(), out.as_mut()) {
+        (Some(r), Some(out)) => {
+            *out = r.buttons();
+            0
+        }
+        _ => -1,
+    }
+}
+
+/// Returns 1 and fills `out` when a card is present, 0 when none is, -1 on a null argument.
+///
+/// # Safety
+/// `reader` must come from `rnr_open`; `out` must point to a `RnsReaderCardSlot`.
+#[no_mangle]
+pub unsafe extern "C" fn rnr_poll_card(reader: *const ShmemReader, out: *mut CardSlot) -> i32 {
+    match (reader.as_ref(), out.as_mut()) {
+        (Some(r), Some(out)) => match r.card() {
+            Some(card) => {
+                *out = card;
+                1
+            }
+            None => 0,
+        },
+        _ => -1,
+    }
+}
+
+/// Tells the server the current card has been read.
+///
+/// # Safety
+/// `reader` must come from `rnr_open`.
+#[no_mangle]
+pub unsafe extern "C" fn rnr_ack_card(reader: *const ShmemReader) -> i32 {
+    match reader.as_ref() {
+        Some(r) => {
+            r.ack_card();
+            0
+        }
+        None => -1,
+    }
+}
+
+/// Writes `count` RGB triplets (at most 31) from `rgb` to the LED region.
+///
+/// # Safety
+/// `reader` must come from `rnr_open`; `rgb` must hold `count * 3` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rnr_write_leds(reader: *const ShmemReader, rgb: *const u8, count: usize) -> i32 {
+    match (reader.as_ref(), rgb.is_null()) {
+        (Some(r), false) => {
+            let count = std::cmp::min(count, layout::LED_COUNT);
+            r.write_leds(std::slice::from_raw_parts(rgb, count * 3));
+            0
+        }
+        _ => -1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    #[test]
+    fn c_constants_match_the_layout() {
+        assert_eq!(RNR_CARD_KIND_ACCESS_CODE, layout::CARD_KIND_ACCESS_CODE);
+        assert_eq!(RNR_CARD_KIND_FELICA, layout::CARD_KIND_FELICA);
+        assert_eq!(RNR_CARD_KIND_MIFARE, layout::CARD_KIND_MIFARE);
+        assert_eq!(RNR_LED_COUNT, layout::LED_COUNT);
+    }
+
+    #[test]
+    fn c_entry_points_round_trip() {
+        let name = CString::new(format!("rustnithm_test_reader_{}", std::process::id())).unwrap();
+        unsafe {
+            let reader = rnr_open(name.as_ptr());
+            assert!(!reader.is_null());
+
+            let mut buttons = Buttons { test: 9, ..Buttons::default() };
+            assert_eq!(rnr_poll_buttons(reader, &mut buttons), 0);
+            assert_eq!(buttons, Buttons::default());
+            let mut card = CardSlot::default();
+            assert_eq!(rnr_poll_card(reader, &mut card), 0);
+
+            let rgb: Vec<u8> = (0..(RNR_LED_COUNT as u8 + 1) * 3).collect();
+            assert_eq!(rnr_write_leds(reader, rgb.as_ptr(), RNR_LED_COUNT + 1), 0);
+            let leds = (*reader).leds();
+            assert_eq!(leds[0], [0, 1, 2]);
+            assert_eq!(leds[RNR_LED_COUNT - 1], [90, 91, 92]);
+
+            assert_eq!(rnr_poll_air(reader, ptr::null_mut()), -1);
+            assert_eq!(rnr_poll_card(ptr::null(), &mut card), -1);
+            assert_eq!(rnr_ack_card(ptr::null()), -1);
+            rnr_close(reader);
+        }
+    }
+}
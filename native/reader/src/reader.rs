@@ -0,0 +1,119 @@
+use shared_memory::*;
+use std::slice;
+use crate::layout;
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct Buttons {
+    pub test: u8,
+    pub service: u8,
+    pub coin: u8,
+    pub extra: u8,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct CardSlot {
+    /// One of the `layout::CARD_KIND_*` markers.
+    pub kind: u8,
+    /// Identifier bytes used in `id`; 10 for access codes, 8 for FeliCa IDm.
+    pub len: u8,
+    pub id: [u8; 10],
+    pub pmm: [u8; 8],
+}
+
+/// Game-side view of the mapping the server writes.
+pub struct ShmemReader {
+    shmem: Shmem,
+}
+unsafe impl Send for ShmemReader {}
+unsafe impl Sync for ShmemReader {}
+
+impl ShmemReader {
+    pub fn open(name: &str) -> Result<Self, String> {
+        let shmem = match ShmemConf::new().os_id(name).open() {
+            Ok(m) => m,
+            Err(_) => ShmemConf::new()
+                .size(layout::DEFAULT_SIZE)
+                .os_id(name)
+                .create()
+                .map_err(|e| format!("Shmem Open Error: {}", e))?,
+        };
+        if shmem.len() < layout::MIN_SIZE {
+            return Err(format!("Shmem '{}' is {} bytes, need {}", name, shmem.len(), layout::MIN_SIZE));
+        }
+        Ok(Self { shmem })
+    }
+
+    fn data(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.shmem.as_ptr(), self.shmem.len()) }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    fn data_mut(&self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.shmem.as_ptr(), self.shmem.len()) }
+    }
+
+    pub fn air(&self) -> [u8; 6] {
+        let mut air = [0u8; 6];
+        air.copy_from_slice(&self.data()[layout::AIR]);
+        air
+    }
+
+    pub fn slider(&self) -> [u8; 32] {
+        let mut slider = [0u8; 32];
+        slider.copy_from_slice(&self.data()[layout::SLIDER]);
+        slider
+    }
+
+    pub fn buttons(&self) -> Buttons {
+        let data = self.data();
+        Buttons {
+            test: data[layout::TEST],
+            service: data[layout::SERVICE],
+            coin: data[layout::COIN],
+            extra: data[layout::EXTRA_BUTTONS],
+        }
+    }
+
+    pub fn card(&self) -> Option<CardSlot> {
+        let data = self.data();
+        if data[layout::CARD_PRESENT] == 0 {
+            return None;
+        }
+        let mut slot = CardSlot {
+            kind: data[layout::CARD_KIND],
+            len: data[layout::CARD_LEN],
+            ..CardSlot::default()
+        };
+        // Older servers only wrote the access code, without a kind marker.
+        if slot.kind == 0 {
+            slot.kind = layout::CARD_KIND_ACCESS_CODE;
+        }
+        if slot.len == 0 {
+            slot.len = 10;
+        }
+        slot.id.copy_from_slice(&data[layout::CARD_ID]);
+        slot.pmm.copy_from_slice(&data[layout::CARD_PMM]);
+        Some(slot)
+    }
+
+    pub fn ack_card(&self) {
+        self.data_mut()[layout::CARD_ACK] = 1;
+    }
+
+    pub fn leds(&self) -> [[u8; 3]; layout::LED_COUNT] {
+        let mut leds = [[0u8; 3]; layout::LED_COUNT];
+        for (led, rgb) in leds.iter_mut().zip(self.data()[layout::LEDS].chunks_exact(3)) {
+            led.copy_from_slice(rgb);
+        }
+        leds
+    }
+
+    /// Writes up to `LED_COUNT` RGB triplets starting at the first slider LED.
+    pub fn write_leds(&self, rgb: &[u8]) {
+        let region = &mut self.data_mut()[layout::LEDS];
+        let len = std::cmp::min(rgb.len() / 3 * 3, region.len());
+        region[..len].copy_from_slice(&rgb[..len]);
+    }
+}
@@ -1,132 +1,572 @@
-use crate::SERVER_INSTANCE;
-use crate::shmem::GLOBAL_SHMEM;
 pub use crate::frb_generated::StreamSink;
-use std::sync::{RwLock, LazyLock};
+use flutter_rust_bridge::frb;
+use std::sync::{Arc, LazyLock};
 use std::net::SocketAddr;
 use crate::protocol::HandshakePayload;
+use crate::card_library::{lookup_card, CardEntry, CARD_LIBRARY};
+use crate::registry;
+use crate::discovery;
+pub use crate::discovery::DiscoveredServer;
+use crate::pairing::{self, ConnectionInfo};
+pub use crate::pairing::QrMatrix;
+use crate::server::{CardMode, SensorServer, ServerConfig};
+pub use crate::state::SensorData;
+pub use crate::events::ServerEvent;
+pub use crate::logging::{LogCategory, LogEntry, LogLevel};
+use crate::logging::{self, LOGGER};
+use crate::sync::{SyncConfig, SyncState};
+pub use crate::liveness::{ClientSession, LinkState, LivenessConfig};
+use crate::coin::CoinCadence;
+use crate::gesture::AirGestureConfig;
+use crate::remap::SliderProfile;
+use crate::filter::{DebounceConfig, FilterStats, FilterTarget};
+pub use crate::latency::{LatencySource, LatencyStats};
+pub use crate::framing::{ConnectionStats, FramingConfig, FramingPolicy, FramingStats};
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
 
-pub struct SensorData {
-    pub air: Vec<u8>,
-    pub slider: Vec<u8>,
-    pub coin: u8,
-    pub service: u8,
-    pub test: u8,
-    pub code: [u8; 10],
+/// A server instance with its own port, shared-memory mapping and sensor stream.
+pub struct ServerHandle {
+    server: Arc<SensorServer>,
 }
 
-pub static SENSOR_SINK: LazyLock<RwLock<Option<StreamSink<SensorData>>>> = LazyLock::new(|| {
-    RwLock::new(None)
+static DEFAULT_HANDLE: LazyLock<ServerHandle> = LazyLock::new(|| {
+    ServerHandle { server: registry::default_server().clone() }
 });
 
-pub fn create_sensor_stream(sink: StreamSink<SensorData>) {
-    if let Ok(mut guard) = SENSOR_SINK.write() {
-        *guard = Some(sink);
+fn default_server() -> &'static ServerHandle {
+    &DEFAULT_HANDLE
+}
+
+/// Runs when the Dart side loads the library, before any other call.
+#[frb(init)]
+pub fn init_app() {
+    if let Err(e) = crate::init_native_backend() {
+        logging::error(LogCategory::Shmem, e);
     }
 }
 
-pub fn init_last_ip(ip: String) {
-    if let Ok(addr) = ip.parse::<SocketAddr>() {
-        if let Ok(lock) = SERVER_INSTANCE.lock() {
-            if let Ok(mut addr_guard) = lock.last_client_addr.lock() {
-                *addr_guard = Some(addr);
+pub fn create_server(config: ServerConfig) -> Result<ServerHandle, String> {
+    registry::create_server(config).map(|server| ServerHandle { server })
+}
+
+impl ServerHandle {
+    #[frb(sync)]
+    pub fn config(&self) -> ServerConfig {
+        self.server.config()
+    }
+
+    pub fn start(&self) -> Result<(), String> {
+        registry::start_server(&self.server)
+    }
+
+    pub fn stop(&self) {
+        registry::stop_server(&self.server);
+    }
+
+    #[frb(sync)]
+    pub fn is_running(&self) -> bool {
+        self.server.is_running_status()
+    }
+
+    /// Adds a subscriber for input snapshots; earlier streams keep receiving until Dart closes them.
+    pub fn create_sensor_stream(&self, sink: StreamSink<SensorData>) {
+        self.server.events().spawn_listener(move |event| match event {
+            ServerEvent::Input(data) => sink.add(data).is_ok(),
+            _ => true,
+        });
+    }
+
+    pub fn create_event_stream(&self, sink: StreamSink<ServerEvent>) {
+        self.server.events().spawn_listener(move |event| sink.add(event).is_ok());
+    }
+
+    #[frb(sync)]
+    pub fn get_state(&self) -> SensorData {
+        self.server.snapshot()
+    }
+
+    /// Returns whether a sync was started; the outcome is reported on the event stream.
+    pub fn toggle_sync(&self) -> bool {
+        match self.server.request_sync() {
+            Ok(()) => true,
+            Err(e) => {
+                logging::warn(LogCategory::Handshake, format!("Sync not started: {}", e));
+                false
             }
         }
     }
-}
 
-pub fn toggle_server(port: u16, is_udp: bool) -> bool {
-    let _ = crate::shmem::init_shmem();
-    match SERVER_INSTANCE.lock() {
-        Ok(lock) => {
-            if lock.is_running_status() {
-                lock.stop();
-                lock.set_active(false);
-                true
-            } else {
-                lock.set_active(true);
-                lock.start(port, !is_udp);
-                true
-            }
+    /// URI for the client to scan; `hosts` defaults to this machine's addresses.
+    #[frb(sync)]
+    pub fn get_connection_uri(&self, hosts: Option<Vec<String>>, secret: Option<String>) -> Result<String, String> {
+        let hosts = hosts.unwrap_or_else(|| {
+            discovery::local_addresses().iter().map(|ip| ip.to_string()).collect()
+        });
+        ConnectionInfo::for_server(&self.server, hosts, secret).map(|info| info.to_uri())
+    }
+
+    #[frb(sync)]
+    pub fn get_connection_qr(&self, hosts: Option<Vec<String>>, secret: Option<String>) -> Result<QrMatrix, String> {
+        pairing::qr_matrix(&self.get_connection_uri(hosts, secret)?, 4)
+    }
+
+    #[frb(sync)]
+    pub fn get_connection_state(&self) -> LinkState {
+        self.server.link_state()
+    }
+
+    #[frb(sync)]
+    pub fn get_sessions(&self) -> Vec<ClientSession> {
+        self.server.sessions()
+    }
+
+    pub fn set_liveness_config(&self, config: LivenessConfig) -> Result<(), String> {
+        self.server.set_liveness_config(config)
+    }
+
+    #[frb(sync)]
+    pub fn get_liveness_config(&self) -> LivenessConfig {
+        self.server.liveness_config()
+    }
+
+    #[frb(sync)]
+    pub fn get_sync_state(&self) -> SyncState {
+        self.server.sync_state()
+    }
+
+    pub fn set_sync_config(&self, config: SyncConfig) -> Result<(), String> {
+        self.server.set_sync_config(config)
+    }
+
+    #[frb(sync)]
+    pub fn get_sync_config(&self) -> SyncConfig {
+        self.server.sync_config()
+    }
+
+    pub fn insert_card(&self, name: String, duration_ms: u32) -> Result<(), String> {
+        let card = lookup_card(&name)?;
+        if let Err(e) = self.server.init_shmem() {
+            logging::error(LogCategory::Shmem, e);
         }
-        Err(_) => false,
+        self.server.insert_card(card, Duration::from_millis(duration_ms as u64));
+        Ok(())
     }
-}
 
-pub fn handle_handshake(incoming: HandshakePayload) {
-    if let Ok(server) = SERVER_INSTANCE.lock() {
-        if !server.is_running_status() { return; }
-
-        let current_s = server.is_active_status();
-        let new_state = incoming.client_target;
-        if new_state != current_s {
-            server.set_active(new_state);
-            report_to_flutter(vec![0; 6], vec![0; 32], 0, 0, 0, [0u8; 10]);
+    pub fn insert_credits(&self, count: u32) -> Result<(), String> {
+        if let Err(e) = self.server.init_shmem() {
+            logging::error(LogCategory::Shmem, e);
         }
+        self.server.insert_credits(count)
+    }
+
+    #[frb(sync)]
+    pub fn pending_credits(&self) -> u32 {
+        self.server.pending_credits()
+    }
+
+    pub fn set_coin_cadence(&self, cadence: CoinCadence) -> Result<(), String> {
+        self.server.set_coin_cadence(cadence)
+    }
+
+    #[frb(sync)]
+    pub fn get_coin_cadence(&self) -> CoinCadence {
+        self.server.coin_cadence()
+    }
+
+    pub fn set_card_mode(&self, mode: CardMode) -> Result<(), String> {
+        self.server.set_card_mode(mode)
+    }
+
+    #[frb(sync)]
+    pub fn get_card_mode(&self) -> CardMode {
+        self.server.card_mode()
+    }
 
-        let response = HandshakePayload {
-            client_current: incoming.client_current,
-            server_current: new_state,
-            client_target: incoming.client_target,
-            server_target: new_state,
-        };
-        server.send_handshake(response);
+    pub fn set_air_gesture(&self, config: AirGestureConfig) -> Result<(), String> {
+        self.server.set_air_gesture(config)
+    }
+
+    #[frb(sync)]
+    pub fn get_air_gesture(&self) -> AirGestureConfig {
+        self.server.air_gesture()
+    }
+
+    pub fn set_slider_profile(&self, client_ip: Option<String>, profile: SliderProfile) -> Result<(), String> {
+        self.server.set_slider_profile(parse_client_ip(client_ip)?, profile)
+    }
+
+    #[frb(sync)]
+    pub fn get_slider_profile(&self, client_ip: Option<String>) -> Result<SliderProfile, String> {
+        Ok(self.server.slider_profile(parse_client_ip(client_ip)?))
+    }
+
+    pub fn clear_slider_profile(&self, client_ip: String) -> Result<bool, String> {
+        let client = parse_client_ip(Some(client_ip))?;
+        Ok(client.map(|ip| self.server.clear_slider_profile(ip)).unwrap_or(false))
+    }
+
+    pub fn set_slider_filter(&self, config: DebounceConfig) -> Result<(), String> {
+        self.server.set_filter(FilterTarget::Slider, config)
+    }
+
+    pub fn set_air_filter(&self, config: DebounceConfig) -> Result<(), String> {
+        self.server.set_filter(FilterTarget::Air, config)
+    }
+
+    #[frb(sync)]
+    pub fn get_slider_filter(&self) -> DebounceConfig {
+        self.server.filter_configs().0
+    }
+
+    #[frb(sync)]
+    pub fn get_air_filter(&self) -> DebounceConfig {
+        self.server.filter_configs().1
+    }
+
+    /// Overrides one slider (0..32) or air (0..6) cell; `None` restores the shared config.
+    pub fn set_cell_filter(&self, target: FilterTarget, cell: u8, config: Option<DebounceConfig>) -> Result<(), String> {
+        self.server.set_cell_filter(target, cell as usize, config)
+    }
+
+    #[frb(sync)]
+    pub fn get_cell_filter(&self, target: FilterTarget, cell: u8) -> Result<DebounceConfig, String> {
+        self.server.cell_filter(target, cell as usize)
+    }
+
+    #[frb(sync)]
+    pub fn get_filter_stats(&self) -> FilterStats {
+        self.server.filter_stats()
+    }
+
+    pub fn reset_filter_stats(&self) {
+        self.server.reset_filter_stats();
+    }
+
+    #[frb(sync)]
+    pub fn get_latency_stats(&self) -> Vec<LatencyStats> {
+        self.server.latency_stats()
+    }
+
+    pub fn reset_latency_stats(&self) {
+        self.server.reset_latency_stats();
+    }
+
+    pub fn set_framing_config(&self, config: FramingConfig) {
+        self.server.set_framing_config(config);
+    }
+
+    #[frb(sync)]
+    pub fn get_framing_config(&self) -> FramingConfig {
+        self.server.framing_config()
+    }
+
+    #[frb(sync)]
+    pub fn get_connection_stats(&self) -> Vec<ConnectionStats> {
+        self.server.connection_stats()
     }
 }
 
-pub fn toggle_sync() -> bool {
-    match SERVER_INSTANCE.lock() {
-        Ok(lock) => {
-            if !lock.is_running_status() {
-                return false;
+pub fn create_sensor_stream(sink: StreamSink<SensorData>) {
+    default_server().create_sensor_stream(sink);
+}
+
+pub fn create_event_stream(sink: StreamSink<ServerEvent>) {
+    default_server().create_event_stream(sink);
+}
+
+pub fn create_log_stream(sink: StreamSink<LogEntry>) {
+    let mut receiver = LOGGER.subscribe();
+    std::thread::spawn(move || {
+        loop {
+            match receiver.blocking_recv() {
+                Ok(entry) => {
+                    if sink.add(entry).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
             }
-            let current_active = lock.is_active_status();
-            let next_state = !current_active;
-
-            let payload = HandshakePayload {
-                client_current: false,
-                server_current: current_active,
-                client_target: next_state,
-                server_target: next_state,
-            };
-            lock.send_handshake(payload)
         }
-        Err(_) => false,
+    });
+}
+
+#[frb(sync)]
+pub fn get_recent_logs(limit: u32) -> Vec<LogEntry> {
+    LOGGER.recent(limit as usize)
+}
+
+pub fn clear_logs() {
+    LOGGER.clear();
+}
+
+pub fn set_log_file(path: Option<String>) -> Result<(), String> {
+    LOGGER.set_file(path.map(PathBuf::from))
+}
+
+pub fn set_log_level(level: LogLevel) {
+    LOGGER.set_level(level);
+}
+
+#[frb(sync)]
+pub fn get_log_level() -> LogLevel {
+    LOGGER.level()
+}
+
+pub fn init_last_ip(ip: String) {
+    match ip.parse::<SocketAddr>() {
+        Ok(addr) => default_server().server.set_last_client(addr),
+        Err(e) => logging::warn(LogCategory::Connection, format!("Ignoring invalid last client '{}': {}", ip, e)),
     }
 }
 
-pub fn sync_to_shmem(
-    air: Vec<u8>,
-    slider: Vec<u8>,
-    coin: u8,
-    service: u8,
-    test: u8,
-) {
-    if let Ok(lock) = GLOBAL_SHMEM.lock() {
-        if let Some(manager) = lock.as_ref() {
-            manager.write_data(&air, &slider);
-            manager.write_status(coin, service, test);
+pub fn toggle_server(port: u16, is_udp: bool) -> bool {
+    let server = &default_server().server;
+    if server.is_running_status() {
+        registry::stop_server(server);
+        return true;
+    }
+    server.set_transport(port, !is_udp);
+    match registry::start_server(server) {
+        Ok(()) => true,
+        Err(e) => {
+            logging::error(LogCategory::Transport, format!("Failed to start server: {}", e));
+            false
         }
     }
 }
 
-pub fn report_to_flutter(
+pub fn handle_handshake(incoming: HandshakePayload) {
+    default_server().server.handle_handshake(incoming);
+}
+
+pub fn toggle_sync() -> bool {
+    default_server().toggle_sync()
+}
+
+#[frb(sync)]
+pub fn get_connection_uri(hosts: Option<Vec<String>>, secret: Option<String>) -> Result<String, String> {
+    default_server().get_connection_uri(hosts, secret)
+}
+
+#[frb(sync)]
+pub fn get_connection_qr(hosts: Option<Vec<String>>, secret: Option<String>) -> Result<QrMatrix, String> {
+    default_server().get_connection_qr(hosts, secret)
+}
+
+#[frb(sync)]
+pub fn generate_pairing_secret() -> Result<String, String> {
+    pairing::generate_secret()
+}
+
+#[frb(sync)]
+pub fn get_connection_state() -> LinkState {
+    default_server().get_connection_state()
+}
+
+#[frb(sync)]
+pub fn get_sessions() -> Vec<ClientSession> {
+    default_server().get_sessions()
+}
+
+pub fn set_liveness_config(config: LivenessConfig) -> Result<(), String> {
+    default_server().set_liveness_config(config)
+}
+
+#[frb(sync)]
+pub fn get_liveness_config() -> LivenessConfig {
+    default_server().get_liveness_config()
+}
+
+#[frb(sync)]
+pub fn get_sync_state() -> SyncState {
+    default_server().get_sync_state()
+}
+
+pub fn set_sync_config(config: SyncConfig) -> Result<(), String> {
+    default_server().set_sync_config(config)
+}
+
+#[frb(sync)]
+pub fn get_sync_config() -> SyncConfig {
+    default_server().get_sync_config()
+}
+
+pub fn sync_to_shmem(
     air: Vec<u8>,
     slider: Vec<u8>,
     coin: u8,
     service: u8,
     test: u8,
-    code: [u8; 10],
 ) {
-    if let Ok(guard) = SENSOR_SINK.read() {
-        if let Some(sink) = guard.as_ref() {
-            let _ = sink.add(SensorData {
-                air,
-                slider,
-                coin,
-                service,
-                test,
-                code,
-            });
-        }
+    default_server().server.sync_input(&air, &slider, coin, service, test);
+}
+
+pub fn set_discovery_enabled(enabled: bool) {
+    discovery::set_enabled(enabled);
+    if enabled && !registry::running_servers().is_empty() {
+        discovery::ensure_responder();
     }
-}
\ No newline at end of file
+}
+
+#[frb(sync)]
+pub fn is_discovery_enabled() -> bool {
+    discovery::is_enabled()
+}
+
+/// Broadcasts a discovery probe and lists the servers that answered within `timeout_ms`.
+pub fn discover_servers(timeout_ms: u32) -> Result<Vec<DiscoveredServer>, String> {
+    let target = SocketAddr::from(([255, 255, 255, 255], discovery::DISCOVERY_PORT));
+    discovery::probe(target, Duration::from_millis(timeout_ms as u64))
+}
+
+pub fn init_card_library(path: String) -> Result<Vec<CardEntry>, String> {
+    let mut library = CARD_LIBRARY.lock().map_err(|_| "Failed to lock CARD_LIBRARY")?;
+    library.load(PathBuf::from(path))?;
+    Ok(library.entries())
+}
+
+#[frb(sync)]
+pub fn list_cards() -> Vec<CardEntry> {
+    match CARD_LIBRARY.lock() {
+        Ok(library) => library.entries(),
+        Err(_) => Vec::new(),
+    }
+}
+
+pub fn save_card(name: String, access_code: String) -> Result<(), String> {
+    let mut library = CARD_LIBRARY.lock().map_err(|_| "Failed to lock CARD_LIBRARY")?;
+    library.upsert(name, access_code)
+}
+
+pub fn remove_card(name: String) -> Result<bool, String> {
+    let mut library = CARD_LIBRARY.lock().map_err(|_| "Failed to lock CARD_LIBRARY")?;
+    library.remove(&name)
+}
+
+pub fn insert_card(name: String, duration_ms: u32) -> Result<(), String> {
+    default_server().insert_card(name, duration_ms)
+}
+
+pub fn set_card_mode(mode: CardMode) -> Result<(), String> {
+    default_server().set_card_mode(mode)
+}
+
+#[frb(sync)]
+pub fn get_card_mode() -> CardMode {
+    default_server().get_card_mode()
+}
+
+pub fn insert_credits(count: u32) -> Result<(), String> {
+    default_server().insert_credits(count)
+}
+
+#[frb(sync)]
+pub fn pending_credits() -> u32 {
+    default_server().pending_credits()
+}
+
+pub fn set_coin_cadence(cadence: CoinCadence) -> Result<(), String> {
+    default_server().set_coin_cadence(cadence)
+}
+
+#[frb(sync)]
+pub fn get_coin_cadence() -> CoinCadence {
+    default_server().get_coin_cadence()
+}
+
+pub fn set_air_gesture(config: AirGestureConfig) -> Result<(), String> {
+    default_server().set_air_gesture(config)
+}
+
+#[frb(sync)]
+pub fn get_air_gesture() -> AirGestureConfig {
+    default_server().get_air_gesture()
+}
+
+fn parse_client_ip(client_ip: Option<String>) -> Result<Option<IpAddr>, String> {
+    match client_ip {
+        Some(ip) => ip.parse::<IpAddr>().map(Some).map_err(|e| format!("Invalid client IP '{}': {}", ip, e)),
+        None => Ok(None),
+    }
+}
+
+pub fn set_slider_profile(client_ip: Option<String>, profile: SliderProfile) -> Result<(), String> {
+    default_server().set_slider_profile(client_ip, profile)
+}
+
+#[frb(sync)]
+pub fn get_slider_profile(client_ip: Option<String>) -> Result<SliderProfile, String> {
+    default_server().get_slider_profile(client_ip)
+}
+
+pub fn clear_slider_profile(client_ip: String) -> Result<bool, String> {
+    default_server().clear_slider_profile(client_ip)
+}
+
+pub fn set_slider_filter(config: DebounceConfig) -> Result<(), String> {
+    default_server().set_slider_filter(config)
+}
+
+pub fn set_air_filter(config: DebounceConfig) -> Result<(), String> {
+    default_server().set_air_filter(config)
+}
+
+#[frb(sync)]
+pub fn get_slider_filter() -> DebounceConfig {
+    default_server().get_slider_filter()
+}
+
+#[frb(sync)]
+pub fn get_air_filter() -> DebounceConfig {
+    default_server().get_air_filter()
+}
+
+pub fn set_cell_filter(target: FilterTarget, cell: u8, config: Option<DebounceConfig>) -> Result<(), String> {
+    default_server().set_cell_filter(target, cell, config)
+}
+
+#[frb(sync)]
+pub fn get_cell_filter(target: FilterTarget, cell: u8) -> Result<DebounceConfig, String> {
+    default_server().get_cell_filter(target, cell)
+}
+
+#[frb(sync)]
+pub fn get_filter_stats() -> FilterStats {
+    default_server().get_filter_stats()
+}
+
+pub fn reset_filter_stats() {
+    default_server().reset_filter_stats();
+}
+
+#[frb(sync)]
+pub fn get_latency_stats() -> Vec<LatencyStats> {
+    default_server().get_latency_stats()
+}
+
+pub fn reset_latency_stats() {
+    default_server().reset_latency_stats();
+}
+
+pub fn set_framing_config(config: FramingConfig) {
+    default_server().set_framing_config(config);
+}
+
+#[frb(sync)]
+pub fn get_framing_config() -> FramingConfig {
+    default_server().get_framing_config()
+}
+
+#[frb(sync)]
+pub fn get_connection_stats() -> Vec<ConnectionStats> {
+    default_server().get_connection_stats()
+}
+
+#[frb(sync)]
+pub fn get_state() -> SensorData {
+    default_server().get_state()
+}
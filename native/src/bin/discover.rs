@@ -0,0 +1,40 @@
+use rustnithm_native::discovery::{probe, DISCOVERY_PORT};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+fn main() {
+    let host: IpAddr = match std::env::args().nth(1) {
+        Some(arg) => match arg.parse() {
+            Ok(ip) => ip,
+            Err(_) => {
+                eprintln!("Usage: discover [HOST] [PORT]  (defaults to broadcast on {})", DISCOVERY_PORT);
+                std::process::exit(2);
+            }
+        },
+        None => IpAddr::V4(Ipv4Addr::BROADCAST),
+    };
+    let port = std::env::args()
+        .nth(2)
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(DISCOVERY_PORT);
+
+    match probe(SocketAddr::new(host, port), Duration::from_millis(1000)) {
+        Ok(servers) if servers.is_empty() => println!("No servers found"),
+        Ok(servers) => {
+            for server in servers {
+                println!(
+                    "{}  {}:{}  {}  v{}",
+                    server.name,
+                    server.addresses.join(","),
+                    server.port,
+                    server.transport,
+                    server.version
+                );
+            }
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
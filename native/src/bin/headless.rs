@@ -0,0 +1,115 @@
+use rustnithm_native::latency::LatencyStats;
+use rustnithm_native::registry;
+use rustnithm_native::server::ServerConfig;
+use std::thread;
+use std::time::Duration;
+
+struct Options {
+    config: ServerConfig,
+    json: bool,
+    interval_ms: u64,
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        config: ServerConfig::default(),
+        json: false,
+        interval_ms: 5000,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--name" => options.config.name = args.next().ok_or("--name needs a value")?,
+            "--port" => {
+                let value = args.next().ok_or("--port needs a value")?;
+                options.config.port = value.parse().map_err(|_| format!("Invalid port '{}'", value))?;
+            }
+            "--protocol" => {
+                let value = args.next().ok_or("--protocol needs a value")?;
+                if !["udp", "tcp", "unix"].contains(&value.as_str()) {
+                    return Err(format!("Unknown protocol '{}'", value));
+                }
+                options.config.protocol = value;
+            }
+            "--socket" => options.config.socket_path = Some(args.next().ok_or("--socket needs a value")?),
+            "--shmem" => options.config.shmem_name = args.next().ok_or("--shmem needs a value")?,
+            "--interval" => {
+                let value = args.next().ok_or("--interval needs a value")?;
+                options.interval_ms = value.parse().map_err(|_| format!("Invalid interval '{}'", value))?;
+            }
+            "--json" => options.json = true,
+            "-h" | "--help" => {
+                println!(
+                    "Usage: headless --port PORT [--protocol udp|tcp|unix] [--socket PATH] [--name NAME] \
+                     [--shmem NAME] [--interval MS] [--json]"
+                );
+                std::process::exit(0);
+            }
+            other => return Err(format!("Unknown argument '{}'", other)),
+        }
+    }
+    if options.config.port == 0 && !options.config.is_unix() {
+        return Err("--port is required".to_string());
+    }
+    Ok(options)
+}
+
+fn render(stats: &[LatencyStats]) -> String {
+    let mut out = format!(
+        "{:<13} {:<8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}\n",
+        "transport", "source", "count", "mean", "p50", "p90", "p99", "max"
+    );
+    for s in stats {
+        out.push_str(&format!(
+            "{:<13} {:<8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}\n",
+            s.transport,
+            format!("{:?}", s.source),
+            s.count,
+            s.mean_us,
+            s.p50_us,
+            s.p90_us,
+            s.p99_us,
+            s.max_us
+        ));
+    }
+    out
+}
+
+fn main() {
+    let options = match parse_args() {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+    let server = match registry::create_server(options.config).and_then(|server| {
+        registry::start_server(&server)?;
+        Ok(server)
+    }) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("Failed to start server: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    loop {
+        thread::sleep(Duration::from_millis(options.interval_ms));
+        if !server.is_running_status() {
+            eprintln!("Server stopped");
+            std::process::exit(1);
+        }
+        let stats = server.latency_stats();
+        if options.json {
+            match serde_json::to_string(&stats) {
+                Ok(line) => println!("{}", line),
+                Err(e) => eprintln!("Serialize error: {}", e),
+            }
+        } else if stats.is_empty() {
+            println!("No control input yet");
+        } else {
+            println!("latency (us)\n{}", render(&stats));
+        }
+    }
+}
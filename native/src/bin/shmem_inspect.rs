@@ -0,0 +1,103 @@
+use rustnithm_native::shmem::{ShmemManager, ShmemSnapshot, DEFAULT_SHMEM_NAME};
+use std::thread;
+use std::time::Duration;
+
+struct Options {
+    name: String,
+    json: bool,
+    once: bool,
+    interval_ms: u64,
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        name: DEFAULT_SHMEM_NAME.to_string(),
+        json: false,
+        once: false,
+        interval_ms: 50,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--name" => options.name = args.next().ok_or("--name needs a value")?,
+            "--interval" => {
+                let value = args.next().ok_or("--interval needs a value")?;
+                options.interval_ms = value.parse().map_err(|_| format!("Invalid interval '{}'", value))?;
+            }
+            "--json" => options.json = true,
+            "--once" => options.once = true,
+            "-h" | "--help" => {
+                println!("Usage: shmem_inspect [--name NAME] [--interval MS] [--json] [--once]");
+                std::process::exit(0);
+            }
+            other => return Err(format!("Unknown argument '{}'", other)),
+        }
+    }
+    Ok(options)
+}
+
+fn cells(values: &[u8]) -> String {
+    values.iter().map(|&v| if v != 0 { '#' } else { '.' }).collect()
+}
+
+fn render(snapshot: &ShmemSnapshot, name: &str) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("shmem: {}\n\n", name));
+    out.push_str(&format!("air     {}  {:?}\n", cells(&snapshot.air), snapshot.air));
+    let top: Vec<u8> = snapshot.slider.iter().step_by(2).copied().collect();
+    let bottom: Vec<u8> = snapshot.slider.iter().skip(1).step_by(2).copied().collect();
+    out.push_str(&format!("slider  {}\n        {}\n", cells(&top), cells(&bottom)));
+    out.push_str(&format!(
+        "buttons test={} service={} coin={} extra={:#04x}\n",
+        snapshot.test, snapshot.service, snapshot.coin, snapshot.extra_buttons
+    ));
+    match (&snapshot.card_kind, &snapshot.card) {
+        (Some(kind), Some(card)) if snapshot.card_present => {
+            out.push_str(&format!("card    {} {} ack={}\n", kind, card, snapshot.card_ack));
+        }
+        _ => out.push_str(&format!("card    none ack={}\n", snapshot.card_ack)),
+    }
+    out.push_str("leds   ");
+    for led in &snapshot.leds {
+        out.push_str(&format!(" {:02X}{:02X}{:02X}", led[0], led[1], led[2]));
+    }
+    out.push('\n');
+    out
+}
+
+fn main() {
+    let options = match parse_args() {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+    let shmem = match ShmemManager::open_existing(&options.name) {
+        Ok(m) => m,
+        Err(e) => {
+            eprintln!("Failed to open shared memory '{}': {}", options.name, e);
+            std::process::exit(1);
+        }
+    };
+
+    let mut last: Option<ShmemSnapshot> = None;
+    loop {
+        let snapshot = ShmemSnapshot::decode(&shmem.read_bytes());
+        if last.as_ref() != Some(&snapshot) {
+            if options.json {
+                match serde_json::to_string(&snapshot) {
+                    Ok(line) => println!("{}", line),
+                    Err(e) => eprintln!("Serialize error: {}", e),
+                }
+            } else {
+                print!("\x1b[2J\x1b[H{}", render(&snapshot, &options.name));
+            }
+            last = Some(snapshot);
+        }
+        if options.once {
+            break;
+        }
+        thread::sleep(Duration::from_millis(options.interval_ms));
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt;
+use rustnithm_reader::layout;
+
+/// Discriminants are the `layout::CARD_KIND_*` markers written to shared memory.
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CardKind {
+    AccessCode = layout::CARD_KIND_ACCESS_CODE,
+    Felica = layout::CARD_KIND_FELICA,
+    Mifare = layout::CARD_KIND_MIFARE,
+}
+
+impl CardKind {
+    pub fn from_marker(marker: u8) -> Option<Self> {
+        match marker {
+            layout::CARD_KIND_ACCESS_CODE => Some(CardKind::AccessCode),
+            layout::CARD_KIND_FELICA => Some(CardKind::Felica),
+            layout::CARD_KIND_MIFARE => Some(CardKind::Mifare),
+            _ => None,
+        }
+    }
+
+    pub fn marker(self) -> u8 {
+        self as u8
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CardId {
+    AccessCode([u8; 10]),
+    Felica { idm: [u8; 8], pmm: [u8; 8] },
+    Mifare { uid: [u8; 10], len: u8 },
+}
+
+impl CardId {
+    pub fn kind(&self) -> CardKind {
+        match self {
+            CardId::AccessCode(_) => CardKind::AccessCode,
+            CardId::Felica { .. } => CardKind::Felica,
+            CardId::Mifare { .. } => CardKind::Mifare,
+        }
+    }
+
+    pub fn access_code_from_bcd(raw: &[u8]) -> Result<Self, String> {
+        if raw.len() != 10 {
+            return Err(format!("Access code must be 10 bytes, got {}", raw.len()));
+        }
+        if let Some(pos) = raw.iter().position(|&b| (b >> 4) > 9 || (b & 0x0F) > 9) {
+            return Err(format!("Invalid BCD digit at byte {}", pos));
+        }
+        let mut code = [0u8; 10];
+        code.copy_from_slice(raw);
+        Ok(CardId::AccessCode(code))
+    }
+
+    pub fn access_code_from_digits(digits: &str) -> Result<Self, String> {
+        let digits: Vec<u8> = digits
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| c.to_digit(10).map(|d| d as u8).ok_or_else(|| format!("Invalid digit '{}'", c)))
+            .collect::<Result<_, _>>()?;
+        if digits.len() != 20 {
+            return Err(format!("Access code must have 20 digits, got {}", digits.len()));
+        }
+        let mut code = [0u8; 10];
+        for (i, pair) in digits.chunks(2).enumerate() {
+            code[i] = (pair[0] << 4) | pair[1];
+        }
+        Ok(CardId::AccessCode(code))
+    }
+
+    pub fn felica(idm: &[u8], pmm: &[u8]) -> Result<Self, String> {
+        if idm.len() != 8 {
+            return Err(format!("FeliCa IDm must be 8 bytes, got {}", idm.len()));
+        }
+        let mut idm_buf = [0u8; 8];
+        idm_buf.copy_from_slice(idm);
+        let mut pmm_buf = [0u8; 8];
+        let copy_len = std::cmp::min(pmm.len(), 8);
+        pmm_buf[..copy_len].copy_from_slice(&pmm[..copy_len]);
+        Ok(CardId::Felica { idm: idm_buf, pmm: pmm_buf })
+    }
+
+    pub fn mifare(uid: &[u8]) -> Result<Self, String> {
+        if !matches!(uid.len(), 4 | 7 | 10) {
+            return Err(format!("MIFARE UID must be 4, 7 or 10 bytes, got {}", uid.len()));
+        }
+        let mut uid_buf = [0u8; 10];
+        uid_buf[..uid.len()].copy_from_slice(uid);
+        Ok(CardId::Mifare { uid: uid_buf, len: uid.len() as u8 })
+    }
+
+    pub fn from_bytes(kind: CardKind, raw: &[u8]) -> Result<Self, String> {
+        match kind {
+            CardKind::AccessCode => Self::access_code_from_bcd(raw),
+            CardKind::Felica => {
+                if raw.len() < 8 {
+                    return Err(format!("FeliCa payload too short: {} bytes", raw.len()));
+                }
+                Self::felica(&raw[..8], &raw[8..])
+            }
+            CardKind::Mifare => Self::mifare(raw),
+        }
+    }
+
+    pub fn from_text(kind: CardKind, text: &str) -> Result<Self, String> {
+        match kind {
+            CardKind::AccessCode => Self::access_code_from_digits(text),
+            CardKind::Felica | CardKind::Mifare => Self::from_bytes(kind, &decode_hex(text)?),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            CardId::AccessCode(code) => code.to_vec(),
+            CardId::Felica { idm, pmm } => {
+                let mut out = idm.to_vec();
+                out.extend_from_slice(pmm);
+                out
+            }
+            CardId::Mifare { uid, len } => uid[..*len as usize].to_vec(),
+        }
+    }
+
+    /// Primary identifier padded into the 10-byte card slot.
+    pub fn slot_bytes(&self) -> [u8; 10] {
+        let mut slot = [0u8; 10];
+        match self {
+            CardId::AccessCode(code) => slot = *code,
+            CardId::Felica { idm, .. } => slot[..8].copy_from_slice(idm),
+            CardId::Mifare { uid, .. } => slot = *uid,
+        }
+        slot
+    }
+
+    pub fn payload_len(&self) -> u8 {
+        match self {
+            CardId::AccessCode(_) => 10,
+            CardId::Felica { .. } => 8,
+            CardId::Mifare { len, .. } => *len,
+        }
+    }
+
+    pub fn pmm(&self) -> Option<[u8; 8]> {
+        match self {
+            CardId::Felica { pmm, .. } => Some(*pmm),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CardId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardId::AccessCode(code) => {
+                for b in code {
+                    write!(f, "{}{}", b >> 4, b & 0x0F)?;
+                }
+                Ok(())
+            }
+            // `IDM:PMM`, which `from_text` reads back.
+            CardId::Felica { idm, pmm } => {
+                write_hex(f, idm)?;
+                f.write_str(":")?;
+                write_hex(f, pmm)
+            }
+            CardId::Mifare { uid, len } => write_hex(f, &uid[..*len as usize]),
+        }
+    }
+}
+
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    for b in bytes {
+        write!(f, "{:02X}", b)?;
+    }
+    Ok(())
+}
+
+fn decode_hex(text: &str) -> Result<Vec<u8>, String> {
+    let digits: Vec<char> = text.chars().filter(|c| !c.is_whitespace() && *c != ':').collect();
+    if !digits.len().is_multiple_of(2) {
+        return Err("Hex string must have an even number of digits".to_string());
+    }
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let hi = pair[0].to_digit(16).ok_or_else(|| format!("Invalid hex digit '{}'", pair[0]))?;
+            let lo = pair[1].to_digit(16).ok_or_else(|| format!("Invalid hex digit '{}'", pair[1]))?;
+            Ok(((hi << 4) | lo) as u8)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_code_digits_pack_into_bcd() {
+        let card = CardId::access_code_from_digits("0123-4567-8901-2345-6789").unwrap();
+        assert_eq!(
+            card.to_bytes(),
+            vec![0x01, 0x23, 0x45, 0x67, 0x89, 0x01, 0x23, 0x45, 0x67, 0x89]
+        );
+        assert_eq!(card.to_string(), "01234567890123456789");
+        assert_eq!(CardId::from_text(CardKind::AccessCode, &card.to_string()), Ok(card));
+    }
+
+    #[test]
+    fn access_code_rejects_bad_bcd_and_lengths() {
+        let mut raw = [0x12u8; 10];
+        raw[3] = 0x1A;
+        assert!(CardId::access_code_from_bcd(&raw).is_err());
+        assert!(CardId::access_code_from_bcd(&[0x12; 9]).is_err());
+        assert!(CardId::access_code_from_digits("1234").is_err());
+        assert!(CardId::access_code_from_digits("0123456789012345678x").is_err());
+    }
+
+    #[test]
+    fn felica_round_trips_through_text() {
+        let idm = [0x01, 0x2E, 0x4C, 0xD8, 0x12, 0x34, 0x56, 0x78];
+        let pmm = [0x00, 0xF1, 0x00, 0x00, 0x00, 0x01, 0x43, 0x00];
+        let card = CardId::felica(&idm, &pmm).unwrap();
+        assert_eq!(card.slot_bytes()[..8], idm);
+        assert_eq!(card.pmm(), Some(pmm));
+        assert_eq!(card.payload_len(), 8);
+        assert_eq!(CardId::from_text(CardKind::Felica, &card.to_string()), Ok(card));
+        assert!(CardId::felica(&idm[..7], &pmm).is_err());
+    }
+
+    #[test]
+    fn mifare_uid_lengths() {
+        for len in [4, 7, 10] {
+            let uid: Vec<u8> = (1..=len as u8).collect();
+            let card = CardId::mifare(&uid).unwrap();
+            assert_eq!(card.payload_len() as usize, len);
+            assert_eq!(card.to_bytes(), uid);
+            assert_eq!(CardId::from_text(CardKind::Mifare, &card.to_string()), Ok(card));
+        }
+        assert!(CardId::mifare(&[1, 2, 3, 4, 5]).is_err());
+        assert!(CardId::from_text(CardKind::Mifare, "0A0B0C0").is_err());
+    }
+
+    #[test]
+    fn kind_markers_match_layout() {
+        for kind in [CardKind::AccessCode, CardKind::Felica, CardKind::Mifare] {
+            assert_eq!(CardKind::from_marker(kind.marker()), Some(kind));
+        }
+        assert_eq!(CardKind::from_marker(0), None);
+    }
+}
@@ -0,0 +1,189 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+use serde::{Deserialize, Serialize};
+use crate::card::CardId;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CardEntry {
+    pub name: String,
+    pub access_code: String,
+}
+
+impl CardEntry {
+    pub fn card_id(&self) -> Result<CardId, String> {
+        CardId::access_code_from_digits(&self.access_code)
+    }
+}
+
+pub struct CardLibrary {
+    path: Option<PathBuf>,
+    entries: Vec<CardEntry>,
+}
+
+impl CardLibrary {
+    pub fn new() -> Self {
+        Self { path: None, entries: Vec::new() }
+    }
+
+    pub fn load(&mut self, path: PathBuf) -> Result<(), String> {
+        let entries = if path.exists() {
+            let content = fs::read_to_string(&path)
+                .map_err(|e| format!("Card library read error: {}", e))?;
+            serde_json::from_str(&content)
+                .map_err(|e| format!("Card library parse error: {}", e))?
+        } else {
+            Vec::new()
+        };
+        self.entries = entries;
+        self.path = Some(path);
+        Ok(())
+    }
+
+    fn path(&self) -> Result<&PathBuf, String> {
+        self.path
+            .as_ref()
+            .ok_or_else(|| "Card library is not initialised; call init_card_library first".to_string())
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        self.write(&self.entries)
+    }
+
+    fn write(&self, entries: &[CardEntry]) -> Result<(), String> {
+        let path = self.path()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Card library dir error: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(entries)
+            .map_err(|e| format!("Card library encode error: {}", e))?;
+        fs::write(path, content).map_err(|e| format!("Card library write error: {}", e))
+    }
+
+    pub fn entries(&self) -> Vec<CardEntry> {
+        self.entries.clone()
+    }
+
+    pub fn find(&self, name: &str) -> Option<&CardEntry> {
+        self.entries.iter().find(|e| e.name == name)
+    }
+
+    /// Adds or replaces `name`; the library is unchanged if the file cannot be written.
+    pub fn upsert(&mut self, name: String, access_code: String) -> Result<(), String> {
+        if name.trim().is_empty() {
+            return Err("Card name must not be empty".to_string());
+        }
+        self.path()?;
+        let card = CardId::access_code_from_digits(&access_code)?;
+        let entry = CardEntry { name, access_code: card.to_string() };
+        let mut entries = self.entries.clone();
+        match entries.iter_mut().find(|e| e.name == entry.name) {
+            Some(existing) => *existing = entry,
+            None => entries.push(entry),
+        }
+        self.write(&entries)?;
+        self.entries = entries;
+        Ok(())
+    }
+
+    /// Removes `name`; the library is unchanged if the file cannot be written.
+    pub fn remove(&mut self, name: &str) -> Result<bool, String> {
+        self.path()?;
+        let mut entries = self.entries.clone();
+        entries.retain(|e| e.name != name);
+        if entries.len() == self.entries.len() {
+            return Ok(false);
+        }
+        self.write(&entries)?;
+        self.entries = entries;
+        Ok(true)
+    }
+}
+
+impl Default for CardLibrary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub static CARD_LIBRARY: LazyLock<Mutex<CardLibrary>> = LazyLock::new(|| {
+    Mutex::new(CardLibrary::new())
+});
+
+pub fn lookup_card(name: &str) -> Result<CardId, String> {
+    let library = CARD_LIBRARY.lock().map_err(|_| "Failed to lock CARD_LIBRARY")?;
+    library
+        .find(name)
+        .ok_or_else(|| format!("Card '{}' not found", name))?
+        .card_id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rustnithm-cards-{}-{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn entries_persist_across_loads() {
+        let path = temp_path("persist");
+        let _ = fs::remove_file(&path);
+        let mut library = CardLibrary::new();
+        library.load(path.clone()).unwrap();
+        assert!(library.entries().is_empty());
+        library.upsert("alice".to_string(), "0123 4567 8901 2345 6789".to_string()).unwrap();
+        library.upsert("bob".to_string(), "11111111111111111111".to_string()).unwrap();
+        library.upsert("alice".to_string(), "22222222222222222222".to_string()).unwrap();
+
+        let mut reloaded = CardLibrary::new();
+        reloaded.load(path.clone()).unwrap();
+        let names: Vec<String> = reloaded.entries().into_iter().map(|e| e.name).collect();
+        assert_eq!(names, vec!["alice", "bob"]);
+        assert_eq!(reloaded.find("alice").unwrap().access_code, "22222222222222222222");
+
+        assert_eq!(reloaded.remove("bob"), Ok(true));
+        assert_eq!(reloaded.remove("bob"), Ok(false));
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn rejects_bad_entries_and_missing_init() {
+        let mut library = CardLibrary::new();
+        assert!(library.upsert("alice".to_string(), "01234567890123456789".to_string()).is_err());
+        assert!(library.remove("alice").is_err());
+        assert!(library.save().is_err());
+
+        let path = temp_path("invalid");
+        let _ = fs::remove_file(&path);
+        library.load(path.clone()).unwrap();
+        assert!(library.upsert(" ".to_string(), "01234567890123456789".to_string()).is_err());
+        assert!(library.upsert("alice".to_string(), "1234".to_string()).is_err());
+        assert!(library.entries().is_empty());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn failed_writes_leave_the_library_unchanged() {
+        let path = temp_path("rollback");
+        let _ = fs::remove_file(&path);
+        let mut library = CardLibrary::new();
+        library.load(path.clone()).unwrap();
+        library.upsert("alice".to_string(), "01234567890123456789".to_string()).unwrap();
+
+        // A file where the directory should be makes every write fail.
+        let blocked = temp_path("rollback-blocker");
+        fs::write(&blocked, "").unwrap();
+        library.path = Some(blocked.join("cards.json"));
+        assert!(library.upsert("bob".to_string(), "11111111111111111111".to_string()).is_err());
+        assert!(library.upsert("alice".to_string(), "22222222222222222222".to_string()).is_err());
+        assert!(library.remove("alice").is_err());
+        let entries = library.entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].access_code, "01234567890123456789");
+
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(&blocked);
+    }
+}
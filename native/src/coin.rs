@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Most credits one request may insert, and the most that can be queued at once.
+pub const MAX_CREDITS: u32 = 99;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct CoinCadence {
+    pub press_ms: u32,
+    pub release_ms: u32,
+}
+
+impl Default for CoinCadence {
+    fn default() -> Self {
+        Self { press_ms: 50, release_ms: 50 }
+    }
+}
+
+impl CoinCadence {
+    pub fn press(&self) -> Duration {
+        Duration::from_millis(self.press_ms as u64)
+    }
+
+    pub fn release(&self) -> Duration {
+        Duration::from_millis(self.release_ms as u64)
+    }
+}
+
+struct QueueState {
+    pending: u32,
+    worker_running: bool,
+    /// Clients whose last stateful button packet held the coin bit.
+    client_held: HashSet<SocketAddr>,
+    cadence: CoinCadence,
+}
+
+/// Pending coin credits, each played back as one press/release cycle of the
+/// coin bit by a single worker.
+pub struct CoinQueue {
+    inner: Mutex<QueueState>,
+}
+
+impl CoinQueue {
+    pub fn new() -> Self {
+        Self {
+            inner: Mutex::new(QueueState {
+                pending: 0,
+                worker_running: false,
+                client_held: HashSet::new(),
+                cadence: CoinCadence::default(),
+            }),
+        }
+    }
+
+    /// Adds credits and returns true if the caller must start a worker.
+    /// Nothing is queued if the total would exceed `MAX_CREDITS`.
+    pub fn push(&self, count: u32) -> Result<bool, String> {
+        let mut inner = match self.inner.lock() {
+            Ok(g) => g,
+            Err(_) => return Err("Coin queue lock poisoned".to_string()),
+        };
+        let total = inner.pending.saturating_add(count);
+        if total > MAX_CREDITS {
+            return Err(format!(
+                "Cannot queue {} credits with {} pending; the limit is {}",
+                count, inner.pending, MAX_CREDITS
+            ));
+        }
+        inner.pending = total;
+        if inner.pending > 0 && !inner.worker_running {
+            inner.worker_running = true;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Takes one credit for the worker, or marks the worker stopped when empty.
+    pub fn take(&self) -> Option<CoinCadence> {
+        let mut inner = self.inner.lock().ok()?;
+        if inner.pending == 0 {
+            inner.worker_running = false;
+            return None;
+        }
+        inner.pending -= 1;
+        Some(inner.cadence)
+    }
+
+    /// Tracks `src`'s held coin bit and reports a rising edge.
+    pub fn client_edge(&self, src: SocketAddr, pressed: bool) -> bool {
+        match self.inner.lock() {
+            Ok(mut inner) if pressed => inner.client_held.insert(src),
+            Ok(mut inner) => {
+                inner.client_held.remove(&src);
+                false
+            }
+            Err(_) => false,
+        }
+    }
+
+    pub fn pending(&self) -> u32 {
+        self.inner.lock().map(|inner| inner.pending).unwrap_or(0)
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.pending = 0;
+            inner.client_held.clear();
+        }
+    }
+
+    pub fn cadence(&self) -> CoinCadence {
+        self.inner.lock().map(|inner| inner.cadence).unwrap_or_default()
+    }
+
+    pub fn set_cadence(&self, cadence: CoinCadence) -> Result<(), String> {
+        let mut inner = self.inner.lock().map_err(|_| "Coin queue lock poisoned")?;
+        inner.cadence = cadence;
+        Ok(())
+    }
+}
+
+impl Default for CoinQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_worker_plays_every_credit() {
+        let queue = CoinQueue::new();
+        assert_eq!(queue.push(2), Ok(true));
+        assert_eq!(queue.push(1), Ok(false));
+        assert_eq!(queue.pending(), 3);
+        for _ in 0..3 {
+            assert_eq!(queue.take(), Some(CoinCadence::default()));
+        }
+        assert_eq!(queue.take(), None);
+        assert_eq!(queue.push(1), Ok(true));
+    }
+
+    #[test]
+    fn overflow_is_rejected_without_queueing() {
+        let queue = CoinQueue::new();
+        assert_eq!(queue.push(MAX_CREDITS - 1), Ok(true));
+        assert!(queue.push(2).is_err());
+        assert_eq!(queue.pending(), MAX_CREDITS - 1);
+        assert_eq!(queue.push(1), Ok(false));
+        assert_eq!(queue.pending(), MAX_CREDITS);
+    }
+
+    fn addr(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    #[test]
+    fn client_edge_fires_once_per_press() {
+        let queue = CoinQueue::new();
+        assert!(queue.client_edge(addr(1), true));
+        assert!(!queue.client_edge(addr(1), true));
+        assert!(!queue.client_edge(addr(1), false));
+        assert!(queue.client_edge(addr(1), true));
+        queue.clear();
+        assert!(queue.client_edge(addr(1), true));
+    }
+
+    #[test]
+    fn each_client_holds_its_own_coin() {
+        let queue = CoinQueue::new();
+        assert!(queue.client_edge(addr(1), true));
+        assert!(queue.client_edge(addr(2), true));
+        assert!(!queue.client_edge(addr(2), false));
+        assert!(!queue.client_edge(addr(1), true));
+        assert!(queue.client_edge(addr(2), true));
+    }
+}
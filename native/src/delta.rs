@@ -0,0 +1,211 @@
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use crate::protocol::{ControlFrame, ControlPayload, ProtocolParser};
+
+/// Applied frames kept as possible delta baselines; the client only diffs against acked ones,
+/// so this just has to cover the acks still in flight.
+pub const BASELINE_HISTORY: usize = 32;
+
+/// Clients with baselines kept at once; the least recently seen is forgotten beyond this.
+pub const DECODER_CLIENTS: usize = 16;
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum DeltaResult {
+    /// Reconstructed frame; `seq` is `None` for legacy frames, which are not acked.
+    Applied { seq: Option<u8>, control: ControlPayload },
+    /// The delta's base is not (or no longer) known; the client must send a keyframe.
+    UnknownBase { base: u8 },
+}
+
+struct Baselines {
+    last_used: u64,
+    history: VecDeque<(u8, ControlPayload)>,
+}
+
+/// Rebuilds full control frames from keyframes and deltas, with separate baselines per client
+/// so clients sharing a UDP socket do not invalidate each other.
+pub struct DeltaDecoder {
+    clients: HashMap<SocketAddr, Baselines>,
+    uses: u64,
+}
+
+impl DeltaDecoder {
+    pub fn new() -> Self {
+        Self { clients: HashMap::new(), uses: 0 }
+    }
+
+    pub fn decode(&mut self, client: SocketAddr, frame: ControlFrame) -> DeltaResult {
+        match frame {
+            ControlFrame::Full(control) => DeltaResult::Applied { seq: None, control },
+            ControlFrame::Keyframe { seq, control } => {
+                let baselines = self.baselines(client);
+                baselines.history.clear();
+                Self::remember(baselines, seq, control);
+                DeltaResult::Applied { seq: Some(seq), control }
+            }
+            ControlFrame::Delta { seq, base, changes } => {
+                let baselines = self.baselines(client);
+                let baseline = match baselines.history.iter().rev().find(|(s, _)| *s == base) {
+                    Some((_, control)) => *control,
+                    None => return DeltaResult::UnknownBase { base },
+                };
+                let control = ProtocolParser::apply_delta(&baseline, &changes);
+                Self::remember(baselines, seq, control);
+                DeltaResult::Applied { seq: Some(seq), control }
+            }
+        }
+    }
+
+    fn baselines(&mut self, client: SocketAddr) -> &mut Baselines {
+        self.uses += 1;
+        if !self.clients.contains_key(&client) && self.clients.len() >= DECODER_CLIENTS {
+            let oldest = self.clients.iter().min_by_key(|(_, b)| b.last_used).map(|(addr, _)| *addr);
+            if let Some(oldest) = oldest {
+                self.clients.remove(&oldest);
+            }
+        }
+        let baselines = self.clients.entry(client).or_insert_with(|| Baselines {
+            last_used: 0,
+            history: VecDeque::with_capacity(BASELINE_HISTORY),
+        });
+        baselines.last_used = self.uses;
+        baselines
+    }
+
+    fn remember(baselines: &mut Baselines, seq: u8, control: ControlPayload) {
+        // A wrapped sequence number replaces the stale entry.
+        baselines.history.retain(|(s, _)| *s != seq);
+        if baselines.history.len() == BASELINE_HISTORY {
+            baselines.history.pop_front();
+        }
+        baselines.history.push_back((seq, control));
+    }
+
+    /// Drops the baselines of a client that went away.
+    pub fn forget(&mut self, client: SocketAddr) {
+        self.clients.remove(&client);
+    }
+
+    pub fn clear(&mut self) {
+        self.clients.clear();
+    }
+}
+
+impl Default for DeltaDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::{CONTROL_FLAG_DELTA, CONTROL_FLAG_KEYFRAME};
+
+    fn addr(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    fn control(air: u8) -> ControlPayload {
+        ProtocolParser::parse_control(&[air, 0, 0, 0, 0]).unwrap()
+    }
+
+    fn keyframe(seq: u8, air: u8) -> ControlFrame {
+        ControlFrame::Keyframe { seq, control: control(air) }
+    }
+
+    fn delta(seq: u8, base: u8, changes: &[(u8, u8)]) -> ControlFrame {
+        ControlFrame::Delta { seq, base, changes: changes.to_vec() }
+    }
+
+    fn applied(seq: u8, control: ControlPayload) -> DeltaResult {
+        DeltaResult::Applied { seq: Some(seq), control }
+    }
+
+    #[test]
+    fn delta_rebuilds_the_frame_from_its_keyframe() {
+        let mut decoder = DeltaDecoder::new();
+        assert_eq!(decoder.decode(addr(1), keyframe(1, 0b01)), applied(1, control(0b01)));
+
+        let mut expected = control(0b11);
+        expected.slider[31] = 1;
+        assert_eq!(decoder.decode(addr(1), delta(2, 1, &[(1, 1), (37, 1)])), applied(2, expected));
+
+        let mut chained = expected;
+        chained.air[0] = 0;
+        assert_eq!(decoder.decode(addr(1), delta(3, 2, &[(0, 0)])), applied(3, chained));
+        assert_eq!(decoder.decode(addr(1), delta(4, 1, &[])), applied(4, control(0b01)));
+    }
+
+    #[test]
+    fn unknown_base_asks_for_a_keyframe() {
+        let mut decoder = DeltaDecoder::new();
+        assert_eq!(decoder.decode(addr(1), delta(2, 1, &[])), DeltaResult::UnknownBase { base: 1 });
+
+        decoder.decode(addr(1), keyframe(1, 0));
+        decoder.decode(addr(1), keyframe(5, 0));
+        assert_eq!(decoder.decode(addr(1), delta(6, 1, &[])), DeltaResult::UnknownBase { base: 1 });
+    }
+
+    #[test]
+    fn wrapped_seq_replaces_the_old_baseline() {
+        let mut decoder = DeltaDecoder::new();
+        decoder.decode(addr(1), keyframe(254, 0b01));
+        decoder.decode(addr(1), delta(255, 254, &[(1, 1)]));
+        assert_eq!(decoder.decode(addr(1), delta(0, 255, &[(2, 1)])), applied(0, control(0b111)));
+
+        decoder.decode(addr(1), keyframe(0, 0b01));
+        decoder.decode(addr(1), delta(1, 0, &[(1, 1)]));
+        assert_eq!(decoder.decode(addr(1), delta(0, 1, &[(5, 1)])), applied(0, control(0b10_0011)));
+        assert_eq!(decoder.decode(addr(1), delta(2, 0, &[])), applied(2, control(0b10_0011)));
+    }
+
+    #[test]
+    fn history_keeps_the_latest_baselines() {
+        let mut decoder = DeltaDecoder::new();
+        decoder.decode(addr(1), keyframe(0, 0));
+        for seq in 1..=BASELINE_HISTORY as u8 {
+            decoder.decode(addr(1), delta(seq, seq - 1, &[]));
+        }
+        assert_eq!(decoder.decode(addr(1), delta(100, 0, &[])), DeltaResult::UnknownBase { base: 0 });
+        assert_eq!(decoder.decode(addr(1), delta(101, 1, &[])), applied(101, control(0)));
+    }
+
+    #[test]
+    fn interleaved_clients_keep_their_own_baselines() {
+        let mut decoder = DeltaDecoder::new();
+        decoder.decode(addr(1), keyframe(1, 0b01));
+        decoder.decode(addr(2), keyframe(1, 0b10));
+        assert_eq!(decoder.decode(addr(1), delta(2, 1, &[])), applied(2, control(0b01)));
+        assert_eq!(decoder.decode(addr(2), delta(2, 1, &[])), applied(2, control(0b10)));
+        assert_eq!(decoder.decode(addr(1), delta(3, 2, &[(2, 1)])), applied(3, control(0b101)));
+        assert_eq!(decoder.decode(addr(2), delta(3, 2, &[(2, 1)])), applied(3, control(0b110)));
+
+        decoder.forget(addr(2));
+        assert_eq!(decoder.decode(addr(2), delta(4, 3, &[])), DeltaResult::UnknownBase { base: 3 });
+    }
+
+    #[test]
+    fn least_recently_seen_client_is_evicted() {
+        let mut decoder = DeltaDecoder::new();
+        for port in 0..DECODER_CLIENTS as u16 {
+            decoder.decode(addr(port), keyframe(1, 0));
+        }
+        decoder.decode(addr(0), delta(2, 1, &[]));
+        decoder.decode(addr(100), keyframe(1, 0));
+        assert_eq!(decoder.decode(addr(0), delta(3, 2, &[])), applied(3, control(0)));
+        assert_eq!(decoder.decode(addr(1), delta(2, 1, &[])), DeltaResult::UnknownBase { base: 1 });
+    }
+
+    #[test]
+    fn parsed_frames_decode() {
+        let mut decoder = DeltaDecoder::new();
+        let key = ProtocolParser::parse_control_frame(CONTROL_FLAG_KEYFRAME, &[7, 0b01, 0, 0, 0, 0x80]).unwrap();
+        let mut expected = control(0b01);
+        expected.slider[31] = 1;
+        assert_eq!(decoder.decode(addr(1), key), applied(7, expected));
+
+        let delta = ProtocolParser::parse_control_frame(CONTROL_FLAG_DELTA, &[8, 7, 37, 0]).unwrap();
+        assert_eq!(decoder.decode(addr(1), delta), applied(8, control(0b01)));
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::HashSet;
+use std::net::{IpAddr, SocketAddr, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::logging::{self, LogCategory};
+use crate::protocol::PROTOCOL_VERSION;
+use crate::registry;
+use crate::server::SensorServer;
+
+pub const DISCOVERY_PORT: u16 = 52468;
+pub const DISCOVERY_PROBE: &[u8] = b"RUSTNITHM_DISCOVER";
+pub const DISCOVERY_MAGIC: &str = "RUSTNITHM";
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct DiscoveredServer {
+    pub name: String,
+    pub addresses: Vec<String>,
+    pub port: u16,
+    pub transport: String,
+    pub version: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DiscoveryReply {
+    magic: String,
+    servers: Vec<DiscoveredServer>,
+}
+
+/// Answers probes on one UDP port for the servers `servers` returns at probe time.
+pub struct Responder {
+    port: u16,
+    running: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+impl Responder {
+    /// Binds `port` (0 for any free port) and answers probes until shut down.
+    pub fn bind<F>(port: u16, servers: F) -> Result<Self, String>
+    where
+        F: Fn() -> Vec<Arc<SensorServer>> + Send + 'static,
+    {
+        let socket = UdpSocket::bind(("0.0.0.0", port))
+            .map_err(|e| format!("Discovery bind on port {} failed: {}", port, e))?;
+        let port = socket
+            .local_addr()
+            .map_err(|e| format!("Discovery socket setup failed: {}", e))?
+            .port();
+        socket
+            .set_read_timeout(Some(Duration::from_millis(200)))
+            .map_err(|e| format!("Discovery socket setup failed: {}", e))?;
+        let running = Arc::new(AtomicBool::new(true));
+        let flag = running.clone();
+        let thread = thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            while flag.load(Ordering::SeqCst) {
+                let (amt, src) = match socket.recv_from(&mut buf) {
+                    Ok(r) => r,
+                    Err(_) => continue,
+                };
+                if !buf[..amt].starts_with(DISCOVERY_PROBE) {
+                    continue;
+                }
+                if let Some(reply) = build_reply(&servers()) {
+                    logging::debug(LogCategory::Connection, format!("Answering discovery probe from {}", src));
+                    if let Err(e) = socket.send_to(&reply, src) {
+                        logging::warn(LogCategory::Connection, format!("Discovery reply to {} failed: {}", src, e));
+                    }
+                }
+            }
+        });
+        logging::info(LogCategory::Connection, format!("Discovery responder listening on port {}", port));
+        Ok(Self { port, running, thread })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Waits for the thread so the port is free again on return.
+    pub fn shutdown(self) {
+        self.running.store(false, Ordering::SeqCst);
+        let _ = self.thread.join();
+    }
+}
+
+static RESPONDER: LazyLock<Mutex<Option<Responder>>> = LazyLock::new(|| Mutex::new(None));
+/// Off until the host opts in with `set_enabled(true)`.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Addresses of every interface, IPv4 first; loopback and link-local addresses are skipped.
+pub fn local_addresses() -> Vec<IpAddr> {
+    let mut addresses: Vec<IpAddr> = Vec::new();
+    match if_addrs::get_if_addrs() {
+        Ok(interfaces) => {
+            for iface in interfaces {
+                let ip = iface.ip();
+                if !iface.is_loopback() && !iface.is_link_local() && !addresses.contains(&ip) {
+                    addresses.push(ip);
+                }
+            }
+        }
+        Err(e) => logging::warn(LogCategory::Connection, format!("Failed to list network interfaces: {}", e)),
+    }
+    if addresses.is_empty() {
+        addresses.extend(primary_address());
+    }
+    addresses.sort_by_key(|ip| ip.is_ipv6());
+    addresses
+}
+
+/// Primary IPv4 address used for outbound traffic; connecting a UDP socket sends nothing.
+fn primary_address() -> Option<IpAddr> {
+    UdpSocket::bind("0.0.0.0:0")
+        .and_then(|socket| {
+            socket.connect("192.0.2.1:9")?;
+            socket.local_addr()
+        })
+        .map(|addr| addr.ip())
+        .ok()
+        .filter(|ip| !ip.is_unspecified())
+}
+
+fn build_reply(running: &[Arc<SensorServer>]) -> Option<Vec<u8>> {
+    let addresses: Vec<String> = local_addresses().iter().map(|ip| ip.to_string()).collect();
+    let servers: Vec<DiscoveredServer> = running
+        .iter()
+        // Unix sockets have no port and are not reachable from the LAN.
+        .filter_map(|server| Some((server.config(), server.bound_port()?)))
+        .map(|(config, port)| {
+            DiscoveredServer {
+                name: config.name.clone(),
+                addresses: addresses.clone(),
+                port,
+                transport: config.transport().to_string(),
+                version: PROTOCOL_VERSION,
+            }
+        })
+        .collect();
+    if servers.is_empty() {
+        return None;
+    }
+    serde_json::to_vec(&DiscoveryReply { magic: DISCOVERY_MAGIC.to_string(), servers }).ok()
+}
+
+/// Runs the shared responder on `port` for every running server until stopped.
+pub fn start_responder(port: u16) -> Result<(), String> {
+    let mut guard = RESPONDER.lock().map_err(|_| "Failed to lock discovery responder")?;
+    if let Some(existing) = guard.as_ref() {
+        if existing.port == port && existing.running.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+    }
+    if let Some(existing) = guard.take() {
+        existing.shutdown();
+    }
+    *guard = Some(Responder::bind(port, registry::running_servers)?);
+    Ok(())
+}
+
+pub fn stop_responder() {
+    if let Ok(mut guard) = RESPONDER.lock() {
+        if let Some(responder) = guard.take() {
+            responder.shutdown();
+        }
+    }
+}
+
+pub fn responder_port() -> Option<u16> {
+    RESPONDER.lock().ok().and_then(|guard| guard.as_ref().map(|r| r.port))
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::SeqCst);
+    if !enabled {
+        stop_responder();
+    }
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+/// Starts the responder on the well-known port when discovery is enabled and it is not running yet.
+pub fn ensure_responder() {
+    if !is_enabled() || responder_port().is_some() {
+        return;
+    }
+    if let Err(e) = start_responder(DISCOVERY_PORT) {
+        logging::warn(LogCategory::Connection, e);
+    }
+}
+
+/// Stops the responder once no running server is left to announce.
+pub fn release_responder() {
+    if registry::running_servers().is_empty() {
+        stop_responder();
+    }
+}
+
+/// Sends a probe to `target` (a broadcast or a specific host) and collects replies until `timeout`.
+pub fn probe(target: SocketAddr, timeout: Duration) -> Result<Vec<DiscoveredServer>, String> {
+    let socket = UdpSocket::bind("0.0.0.0:0").map_err(|e| format!("Probe bind failed: {}", e))?;
+    socket.set_broadcast(true).map_err(|e| format!("Probe setup failed: {}", e))?;
+    socket.send_to(DISCOVERY_PROBE, target).map_err(|e| format!("Probe send failed: {}", e))?;
+
+    let deadline = Instant::now() + timeout;
+    let mut found: Vec<DiscoveredServer> = Vec::new();
+    let mut seen = HashSet::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        socket
+            .set_read_timeout(Some(remaining))
+            .map_err(|e| format!("Probe setup failed: {}", e))?;
+        let (amt, src) = match socket.recv_from(&mut buf) {
+            Ok(r) => r,
+            Err(_) => break,
+        };
+        let reply: DiscoveryReply = match serde_json::from_slice(&buf[..amt]) {
+            Ok(r) => r,
+            Err(_) => continue,
+        };
+        if reply.magic != DISCOVERY_MAGIC {
+            continue;
+        }
+        for mut server in reply.servers {
+            let source = src.ip().to_string();
+            if !server.addresses.contains(&source) {
+                server.addresses.insert(0, source);
+            }
+            if seen.insert((server.addresses[0].clone(), server.port)) {
+                found.push(server);
+            }
+        }
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::ServerConfig;
+
+    #[test]
+    fn local_addresses_skip_loopback() {
+        assert!(local_addresses().iter().all(|ip| !ip.is_loopback()));
+    }
+
+    #[test]
+    fn announces_the_bound_port() {
+        let server = Arc::new(SensorServer::with_config(ServerConfig {
+            name: "discovery-test".to_string(),
+            port: 0,
+            shmem_name: format!("rustnithm_test_discovery_{}", std::process::id()),
+            ..ServerConfig::default()
+        }));
+        server.start_configured().unwrap();
+        let bound = server.bound_port().unwrap();
+        assert_ne!(bound, 0);
+
+        let announced = server.clone();
+        let responder = Responder::bind(0, move || vec![announced.clone()]).unwrap();
+        let target = SocketAddr::from(([127, 0, 0, 1], responder.port()));
+        let found = probe(target, Duration::from_millis(500)).unwrap();
+        responder.shutdown();
+        server.stop();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, "discovery-test");
+        assert_eq!(found[0].port, bound);
+        assert_eq!(found[0].transport, "udp");
+        assert_eq!(found[0].version, PROTOCOL_VERSION);
+        assert_eq!(found[0].addresses[0], "127.0.0.1");
+        assert_eq!(server.bound_port(), None);
+    }
+}
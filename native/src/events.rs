@@ -0,0 +1,202 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+use tokio::sync::broadcast::{self, error::RecvError, error::TryRecvError};
+use crate::liveness::LinkState;
+use crate::state::SensorData;
+
+pub const EVENT_CAPACITY: usize = 256;
+
+#[derive(Clone, Debug)]
+pub enum ServerEvent {
+    Input(SensorData),
+    Connected { client: String },
+    Disconnected { client: String },
+    Handshake { client: Option<String>, active: bool, acknowledged: bool },
+    Error { message: String },
+    SyncPending { target: bool },
+    SyncConfirmed { active: bool },
+    /// The sync did not take effect as requested; `active` is the resulting state.
+    SyncFailed { active: bool, reason: String },
+    LinkChanged { client: String, state: LinkState },
+    /// Delivered to a subscriber that fell behind; `missed` older events were dropped for it.
+    Lagged { missed: u64 },
+}
+
+/// Broadcast bus; every subscriber sees every event, and a slow subscriber
+/// loses its oldest events instead of blocking the server.
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<ServerEvent>,
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn publish(&self, event: ServerEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    pub fn has_subscribers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    pub fn subscribe(&self) -> EventSubscriber {
+        EventSubscriber { receiver: self.sender.subscribe() }
+    }
+
+    /// Runs `f` on a dedicated thread for each event until it returns false,
+    /// the bus is dropped, or the returned handle is cancelled.
+    pub fn spawn_listener<F>(&self, mut f: F) -> ListenerHandle
+    where
+        F: FnMut(ServerEvent) -> bool + Send + 'static,
+    {
+        let mut subscriber = self.subscribe();
+        let gate = Arc::new(Mutex::new(()));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let (thread_gate, thread_cancelled) = (gate.clone(), cancelled.clone());
+        let thread = thread::spawn(move || {
+            while let Some(event) = subscriber.recv() {
+                let _guard = match thread_gate.lock() {
+                    Ok(g) => g,
+                    Err(_) => break,
+                };
+                if thread_cancelled.load(Ordering::SeqCst) || !f(event) {
+                    break;
+                }
+            }
+        });
+        ListenerHandle { gate, cancelled, thread: thread.thread().id() }
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(EVENT_CAPACITY)
+    }
+}
+
+pub struct EventSubscriber {
+    receiver: broadcast::Receiver<ServerEvent>,
+}
+
+impl EventSubscriber {
+    /// Blocks until the next event; `None` once the bus is gone. Must not be called from async code.
+    pub fn recv(&mut self) -> Option<ServerEvent> {
+        match self.receiver.blocking_recv() {
+            Ok(event) => Some(event),
+            Err(RecvError::Lagged(missed)) => Some(ServerEvent::Lagged { missed }),
+            Err(RecvError::Closed) => None,
+        }
+    }
+
+    pub fn try_recv(&mut self) -> Option<ServerEvent> {
+        match self.receiver.try_recv() {
+            Ok(event) => Some(event),
+            Err(TryRecvError::Lagged(missed)) => Some(ServerEvent::Lagged { missed }),
+            Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => None,
+        }
+    }
+}
+
+pub struct ListenerHandle {
+    /// Held by the listener thread while the callback runs.
+    gate: Arc<Mutex<()>>,
+    cancelled: Arc<AtomicBool>,
+    thread: ThreadId,
+}
+
+impl ListenerHandle {
+    /// Stops the listener; once this returns the callback will not run again and,
+    /// unless called from the callback itself, is not running.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        if thread::current().id() != self.thread {
+            drop(self.gate.lock());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    fn connected(n: usize) -> ServerEvent {
+        ServerEvent::Connected { client: n.to_string() }
+    }
+
+    fn client(event: Option<ServerEvent>) -> Option<String> {
+        match event {
+            Some(ServerEvent::Connected { client }) => Some(client),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn every_subscriber_sees_every_event() {
+        let bus = EventBus::default();
+        let mut a = bus.subscribe();
+        let mut b = bus.subscribe();
+        assert_eq!(bus.subscriber_count(), 2);
+        bus.publish(connected(1));
+        bus.publish(connected(2));
+        for sub in [&mut a, &mut b] {
+            assert_eq!(client(sub.try_recv()).as_deref(), Some("1"));
+            assert_eq!(client(sub.try_recv()).as_deref(), Some("2"));
+            assert!(sub.try_recv().is_none());
+        }
+        drop(a);
+        assert_eq!(bus.subscriber_count(), 1);
+    }
+
+    #[test]
+    fn slow_subscriber_is_told_what_it_missed() {
+        let bus = EventBus::new(4);
+        let mut slow = bus.subscribe();
+        for n in 0..6 {
+            bus.publish(connected(n));
+        }
+        assert!(matches!(slow.try_recv(), Some(ServerEvent::Lagged { missed: 2 })));
+        assert_eq!(client(slow.try_recv()).as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn cancelled_listener_stops_receiving() {
+        let bus = EventBus::default();
+        let (tx, rx) = mpsc::channel();
+        let listener = bus.spawn_listener(move |event| tx.send(client(Some(event))).is_ok());
+        bus.publish(connected(1));
+        assert_eq!(rx.recv_timeout(Duration::from_secs(1)).unwrap().as_deref(), Some("1"));
+        listener.cancel();
+        bus.publish(connected(2));
+        assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[test]
+    fn listener_can_cancel_itself() {
+        let bus = EventBus::default();
+        let slot: Arc<Mutex<Option<ListenerHandle>>> = Arc::new(Mutex::new(None));
+        let (tx, rx) = mpsc::channel();
+        let own = slot.clone();
+        let listener = bus.spawn_listener(move |_| {
+            if let Some(handle) = own.lock().unwrap().as_ref() {
+                handle.cancel();
+            }
+            tx.send(()).is_ok()
+        });
+        *slot.lock().unwrap() = Some(listener);
+        bus.publish(connected(1));
+        bus.publish(connected(2));
+        assert!(rx.recv_timeout(Duration::from_secs(1)).is_ok());
+        assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+}
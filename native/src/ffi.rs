@@ -0,0 +1,495 @@
+//! C ABI for embedding the server without flutter_rust_bridge.
+//!
+//! `include/rustnithm.h` is generated from this file by `build.rs`.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::path::PathBuf;
+use std::ptr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use crate::card_library::{lookup_card, CARD_LIBRARY};
+use crate::registry;
+use crate::events::{ListenerHandle, ServerEvent};
+use crate::server::{SensorServer, ServerConfig};
+use crate::state::SensorData;
+
+#[repr(C)]
+pub struct RnsConfig {
+    /// Server name; null for "default".
+    pub name: *const c_char,
+    pub port: u16,
+    /// Non-zero for TCP, zero for UDP.
+    pub tcp: u8,
+    /// Shared-memory name; null for the default mapping.
+    pub shmem_name: *const c_char,
+    /// Shared-memory size in bytes; 0 for the default size.
+    pub shmem_size: usize,
+    /// Unix socket path; non-null selects the Unix transport and `port` and `tcp` are ignored.
+    pub socket_path: *const c_char,
+}
+
+#[repr(C)]
+pub struct RnsState {
+    pub air: [u8; 6],
+    pub slider: [u8; 32],
+    pub coin: u8,
+    pub service: u8,
+    pub test: u8,
+    /// Full operator button mask.
+    pub buttons: u8,
+    pub card_present: u8,
+    pub code: [u8; 10],
+    pub active: u8,
+    pub running: u8,
+}
+
+/// Called from server threads with a state that is only valid for the duration of the call.
+pub type RnsStateCallback = Option<extern "C" fn(state: *const RnsState, user_data: *mut c_void)>;
+
+/// Opaque server instance.
+pub struct RnsServer {
+    server: Arc<SensorServer>,
+    listener: Mutex<Option<ListenerHandle>>,
+}
+
+impl RnsServer {
+    /// The lock is released before cancelling, so a callback may replace itself.
+    fn replace_listener(&self, listener: Option<ListenerHandle>) {
+        let old = match self.listener.lock() {
+            Ok(mut guard) => std::mem::replace(&mut *guard, listener),
+            Err(_) => return,
+        };
+        if let Some(old) = old {
+            old.cancel();
+        }
+    }
+}
+
+struct UserData(*mut c_void);
+unsafe impl Send for UserData {}
+unsafe impl Sync for UserData {}
+
+impl UserData {
+    fn get(&self) -> *mut c_void {
+        self.0
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_error(message: String) {
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+fn to_state(data: &SensorData, running: bool) -> RnsState {
+    let mut state = RnsState {
+        air: [0u8; 6],
+        slider: [0u8; 32],
+        coin: data.coin,
+        service: data.service,
+        test: data.test,
+        buttons: data.buttons,
+        card_present: data.card_present as u8,
+        code: data.code,
+        active: data.active as u8,
+        running: running as u8,
+    };
+    let air_len = std::cmp::min(data.air.len(), 6);
+    state.air[..air_len].copy_from_slice(&data.air[..air_len]);
+    let slider_len = std::cmp::min(data.slider.len(), 32);
+    state.slider[..slider_len].copy_from_slice(&data.slider[..slider_len]);
+    state
+}
+
+unsafe fn opt_str(value: *const c_char, field: &str) -> Result<Option<String>, String> {
+    if value.is_null() {
+        return Ok(None);
+    }
+    CStr::from_ptr(value)
+        .to_str()
+        .map(|s| Some(s.to_string()))
+        .map_err(|_| format!("{} is not valid UTF-8", field))
+}
+
+unsafe fn read_config(config: *const RnsConfig) -> Result<ServerConfig, String> {
+    let mut out = ServerConfig::default();
+    let config = match config.as_ref() {
+        Some(c) => c,
+        None => return Ok(out),
+    };
+    if let Some(name) = opt_str(config.name, "name")? {
+        out.name = name;
+    }
+    if let Some(shmem_name) = opt_str(config.shmem_name, "shmem_name")? {
+        out.shmem_name = shmem_name;
+    }
+    if config.shmem_size != 0 {
+        out.shmem_size = config.shmem_size;
+    }
+    out.port = config.port;
+    out.protocol = if config.tcp != 0 { "tcp" } else { "udp" }.to_string();
+    if let Some(socket_path) = opt_str(config.socket_path, "socket_path")? {
+        out.protocol = "unix".to_string();
+        out.socket_path = Some(socket_path);
+    }
+    Ok(out)
+}
+
+/// Message of the last failed call on this thread, or null. Valid until the next call that fails.
+#[no_mangle]
+pub extern "C" fn rns_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |s| s.as_ptr()))
+}
+
+/// Creates a server; `config` may be null for the defaults. Returns null on failure.
+///
+/// # Safety
+/// `config` must be null or point to a valid `RnsConfig` whose strings are null or NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn rns_server_create(config: *const RnsConfig) -> *mut RnsServer {
+    let result = read_config(config).and_then(registry::create_server);
+    match result {
+        Ok(server) => Box::into_raw(Box::new(RnsServer { server, listener: Mutex::new(None) })),
+        Err(e) => {
+            set_error(e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Returns 0 on success, -1 on failure.
+///
+/// # Safety
+/// `server` must come from `rns_server_create` and not have been destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn rns_server_start(server: *mut RnsServer) -> i32 {
+    let server = match server.as_ref() {
+        Some(s) => s,
+        None => {
+            set_error("Null server".to_string());
+            return -1;
+        }
+    };
+    match registry::start_server(&server.server) {
+        Ok(()) => 0,
+        Err(e) => {
+            set_error(e);
+            -1
+        }
+    }
+}
+
+/// # Safety
+/// `server` must come from `rns_server_create` and not have been destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn rns_server_stop(server: *mut RnsServer) -> i32 {
+    match server.as_ref() {
+        Some(s) => {
+            registry::stop_server(&s.server);
+            0
+        }
+        None => {
+            set_error("Null server".to_string());
+            -1
+        }
+    }
+}
+
+/// Stops the server if needed and frees it.
+///
+/// # Safety
+/// `server` must be null or come from `rns_server_create`; it must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn rns_server_destroy(server: *mut RnsServer) {
+    if server.is_null() {
+        return;
+    }
+    let server = Box::from_raw(server);
+    server.replace_listener(None);
+    registry::stop_server(&server.server);
+}
+
+/// # Safety
+/// `server` must come from `rns_server_create`.
+#[no_mangle]
+pub unsafe extern "C" fn rns_server_is_running(server: *const RnsServer) -> u8 {
+    server.as_ref().is_some_and(|s| s.server.is_running_status()) as u8
+}
+
+/// UDP or TCP port the running server is bound to, which differs from `RnsConfig::port`
+/// when that is 0. Returns -1 when the server is stopped or on a Unix socket.
+///
+/// # Safety
+/// `server` must come from `rns_server_create`.
+#[no_mangle]
+pub unsafe extern "C" fn rns_server_bound_port(server: *const RnsServer) -> i32 {
+    let server = match server.as_ref() {
+        Some(s) => s,
+        None => {
+            set_error("Null server".to_string());
+            return -1;
+        }
+    };
+    match server.server.bound_port() {
+        Some(port) => port as i32,
+        None => {
+            set_error("Server is not listening on a port".to_string());
+            -1
+        }
+    }
+}
+
+/// Copies the current input state into `out`. Returns 0 on success, -1 on a null argument.
+///
+/// # Safety
+/// `server` must come from `rns_server_create`; `out` must point to an `RnsState`.
+#[no_mangle]
+pub unsafe extern "C" fn rns_server_poll_state(server: *const RnsServer, out: *mut RnsState) -> i32 {
+    match (server.as_ref(), out.as_mut()) {
+        (Some(s), Some(out)) => {
+            *out = to_state(&s.server.snapshot(), s.server.is_running_status());
+            0
+        }
+        _ => {
+            set_error("Null argument".to_string());
+            -1
+        }
+    }
+}
+
+/// Registers a callback for every published state, replacing any previous one; pass null to remove it.
+/// Once this returns, a replaced callback is no longer running. The callback may call this
+/// function itself; it then finishes its current call and is not invoked again.
+///
+/// # Safety
+/// `server` must come from `rns_server_create`; `user_data` must stay valid while registered.
+#[no_mangle]
+pub unsafe extern "C" fn rns_server_set_state_callback(
+    server: *const RnsServer,
+    callback: RnsStateCallback,
+    user_data: *mut c_void,
+) -> i32 {
+    let server = match server.as_ref() {
+        Some(s) => s,
+        None => {
+            set_error("Null server".to_string());
+            return -1;
+        }
+    };
+    let listener = callback.map(|cb| {
+        let user_data = UserData(user_data);
+        let weak = Arc::downgrade(&server.server);
+        server.server.events().spawn_listener(move |event| {
+            if let ServerEvent::Input(data) = event {
+                let running = weak.upgrade().is_some_and(|s| s.is_running_status());
+                let state = to_state(&data, running);
+                cb(&state, user_data.get());
+            }
+            true
+        })
+    });
+    server.replace_listener(listener);
+    0
+}
+
+/// Loads the card library used by `rns_server_insert_card` from the JSON file at `path`,
+/// where a missing file is an empty library. Returns the number of cards, or -1 on failure.
+///
+/// # Safety
+/// `path` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn rns_card_library_init(path: *const c_char) -> i32 {
+    let result = opt_str(path, "path")
+        .and_then(|p| p.ok_or_else(|| "Null path".to_string()))
+        .and_then(|p| {
+            let mut library = CARD_LIBRARY.lock().map_err(|_| "Failed to lock CARD_LIBRARY")?;
+            library.load(PathBuf::from(p))?;
+            Ok(library.entries().len())
+        });
+    match result {
+        Ok(count) => count as i32,
+        Err(e) => {
+            set_error(e);
+            -1
+        }
+    }
+}
+
+/// Inserts a card from the card library by name for `duration_ms`.
+///
+/// # Safety
+/// `server` must come from `rns_server_create`; `name` must be NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn rns_server_insert_card(server: *const RnsServer, name: *const c_char, duration_ms: u32) -> i32 {
+    let server = match server.as_ref() {
+        Some(s) => s,
+        None => {
+            set_error("Null server".to_string());
+            return -1;
+        }
+    };
+    let result = opt_str(name, "name")
+        .and_then(|n| n.ok_or_else(|| "Null card name".to_string()))
+        .and_then(|n| lookup_card(&n))
+        .and_then(|card| {
+            server.server.init_shmem()?;
+            server.server.insert_card(card, Duration::from_millis(duration_ms as u64));
+            Ok(())
+        });
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            set_error(e);
+            -1
+        }
+    }
+}
+
+/// Queues `count` coin presses; more than 99 is rejected. Returns 0 on success, -1 on failure.
+///
+/// # Safety
+/// `server` must come from `rns_server_create`.
+#[no_mangle]
+pub unsafe extern "C" fn rns_server_insert_credits(server: *const RnsServer, count: u32) -> i32 {
+    match server.as_ref() {
+        Some(s) => {
+            match s.server.init_shmem().and_then(|_| s.server.insert_credits(count)) {
+                Ok(()) => 0,
+                Err(e) => {
+                    set_error(e);
+                    -1
+                }
+            }
+        }
+        None => {
+            set_error("Null server".to_string());
+            -1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc;
+    use crate::card::CardId;
+
+    fn create(name: &str) -> *mut RnsServer {
+        let name = CString::new(name).unwrap();
+        let shmem = CString::new(format!("rustnithm_test_ffi_{}_{}", name.to_str().unwrap(), std::process::id())).unwrap();
+        let config = RnsConfig {
+            name: name.as_ptr(),
+            port: 0,
+            tcp: 0,
+            shmem_name: shmem.as_ptr(),
+            shmem_size: 0,
+            socket_path: ptr::null(),
+        };
+        let server = unsafe { rns_server_create(&config) };
+        assert!(!server.is_null());
+        server
+    }
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(rns_last_error()) }.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn null_server_reports_an_error() {
+        unsafe {
+            assert_eq!(rns_server_stop(ptr::null_mut()), -1);
+            assert_eq!(last_error(), "Null server");
+            assert_eq!(rns_server_insert_credits(ptr::null(), 1), -1);
+            assert_eq!(rns_server_poll_state(ptr::null(), ptr::null_mut()), -1);
+            assert_eq!(last_error(), "Null argument");
+            assert_eq!(rns_server_bound_port(ptr::null()), -1);
+        }
+    }
+
+    #[test]
+    fn card_library_init_feeds_insert_card() {
+        let path = std::env::temp_dir().join(format!("rustnithm-ffi-cards-{}.json", std::process::id()));
+        std::fs::write(&path, r#"[{"name":"alice","access_code":"01234567890123456789"}]"#).unwrap();
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+        let server = create("cards");
+        unsafe {
+            assert_eq!(rns_card_library_init(ptr::null()), -1);
+            assert_eq!(rns_card_library_init(c_path.as_ptr()), 1);
+            let alice = CString::new("alice").unwrap();
+            assert_eq!(rns_server_insert_card(server, alice.as_ptr(), 1000), 0);
+            let mut state: RnsState = std::mem::zeroed();
+            assert_eq!(rns_server_poll_state(server, &mut state), 0);
+            assert_eq!(state.card_present, 1);
+            let bob = CString::new("bob").unwrap();
+            assert_eq!(rns_server_insert_card(server, bob.as_ptr(), 1000), -1);
+            rns_server_destroy(server);
+        }
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn all_zero_access_code_is_present() {
+        let server = create("zero_card");
+        let card = CardId::access_code_from_digits("00000000000000000000").unwrap();
+        unsafe {
+            (*server).server.init_shmem().unwrap();
+            (*server).server.insert_card(card, Duration::from_secs(5));
+            let mut state: RnsState = std::mem::zeroed();
+            assert_eq!(rns_server_poll_state(server, &mut state), 0);
+            assert_eq!((state.card_present, state.code), (1, [0; 10]));
+            rns_server_destroy(server);
+        }
+    }
+
+    #[test]
+    fn bound_port_reports_the_port_in_use() {
+        let server = create("bound_port");
+        unsafe {
+            assert_eq!(rns_server_bound_port(server), -1);
+            assert_eq!(rns_server_start(server), 0);
+            let port = rns_server_bound_port(server);
+            assert!(port > 0);
+            assert_eq!((*server).server.bound_port(), Some(port as u16));
+            assert_eq!(rns_server_stop(server), 0);
+            assert_eq!(rns_server_bound_port(server), -1);
+            rns_server_destroy(server);
+        }
+    }
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn remove_self(_state: *const RnsState, user_data: *mut c_void) {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        unsafe { rns_server_set_state_callback(user_data as *const RnsServer, None, ptr::null_mut()) };
+    }
+
+    #[test]
+    fn callback_can_remove_itself() {
+        let server = create("reentrant");
+        unsafe {
+            assert_eq!(rns_server_set_state_callback(server, Some(remove_self), server as *mut c_void), 0);
+        }
+        let (done, finished) = mpsc::channel();
+        let handle = server as usize;
+        std::thread::spawn(move || {
+            let server = unsafe { &*(handle as *const RnsServer) };
+            server.server.publish_state();
+            while CALLS.load(Ordering::SeqCst) == 0 {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            server.server.publish_state();
+            // Replacing the (already removed) callback must not wait on the listener.
+            unsafe { rns_server_set_state_callback(server, None, ptr::null_mut()) };
+            let _ = done.send(());
+        });
+        finished.recv_timeout(Duration::from_secs(2)).expect("callback deadlocked");
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        unsafe { rns_server_destroy(server) };
+    }
+}
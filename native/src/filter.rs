@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct DebounceConfig {
+    pub enabled: bool,
+    /// A press must be held this long before a release is accepted.
+    pub min_press_ms: u32,
+    /// A release must last this long before a new press is accepted.
+    pub min_release_ms: u32,
+    /// Raw value at which a released cell becomes pressed.
+    pub press_threshold: u8,
+    /// Raw value below which a pressed cell becomes released; values between the
+    /// two thresholds keep the cell's current state.
+    pub release_threshold: u8,
+}
+
+impl DebounceConfig {
+    /// Whether a cell at `value` should be pressed, given its current state.
+    fn wants_press(&self, pressed: bool, value: u8) -> bool {
+        let press = self.press_threshold.max(1);
+        if pressed {
+            value >= self.release_threshold.clamp(1, press)
+        } else {
+            value >= press
+        }
+    }
+}
+
+impl Default for DebounceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_press_ms: 15,
+            min_release_ms: 15,
+            press_threshold: 1,
+            release_threshold: 1,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum FilterTarget {
+    Slider,
+    Air,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct FilterStats {
+    pub slider_suppressed: u64,
+    pub air_suppressed: u64,
+}
+
+pub struct CellFilter<const N: usize> {
+    config: DebounceConfig,
+    /// Per-cell settings that replace `config` for that cell.
+    overrides: [Option<DebounceConfig>; N],
+    pressed: [bool; N],
+    changed_at: [Option<Instant>; N],
+    raw: [u8; N],
+    output: [u8; N],
+    suppressed: u64,
+}
+
+impl<const N: usize> CellFilter<N> {
+    pub fn new(config: DebounceConfig) -> Self {
+        Self {
+            config,
+            overrides: [None; N],
+            pressed: [false; N],
+            changed_at: [None; N],
+            raw: [0u8; N],
+            output: [0u8; N],
+            suppressed: 0,
+        }
+    }
+
+    pub fn config(&self) -> DebounceConfig {
+        self.config
+    }
+
+    pub fn set_config(&mut self, config: DebounceConfig) {
+        let suppressed = self.suppressed;
+        let overrides = self.overrides;
+        *self = Self::new(config);
+        self.suppressed = suppressed;
+        self.overrides = overrides;
+    }
+
+    /// Settings in effect for `cell`.
+    pub fn cell_config(&self, cell: usize) -> Result<DebounceConfig, String> {
+        let entry = self.overrides.get(cell).ok_or_else(|| format!("Cell {} out of range 0..{}", cell, N))?;
+        Ok(entry.unwrap_or(self.config))
+    }
+
+    /// Overrides one cell; `None` returns it to the shared config.
+    pub fn set_cell_config(&mut self, cell: usize, config: Option<DebounceConfig>) -> Result<(), String> {
+        let entry = self.overrides.get_mut(cell).ok_or_else(|| format!("Cell {} out of range 0..{}", cell, N))?;
+        *entry = config;
+        let value = self.raw[cell];
+        self.pressed[cell] = config.unwrap_or(self.config).wants_press(false, value);
+        self.changed_at[cell] = None;
+        Ok(())
+    }
+
+    fn any_enabled(&self) -> bool {
+        self.config.enabled || self.overrides.iter().flatten().any(|c| c.enabled)
+    }
+
+    pub fn suppressed(&self) -> u64 {
+        self.suppressed
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.suppressed = 0;
+    }
+
+    pub fn process(&mut self, raw: &[u8; N], now: Instant) -> [u8; N] {
+        let prev = std::mem::replace(&mut self.raw, *raw);
+        if !self.any_enabled() {
+            self.output = *raw;
+            return self.output;
+        }
+        self.evaluate(now, Some(&prev));
+        self.output
+    }
+
+    /// When the earliest flip blocked by a hold time may go through.
+    pub fn next_change(&self) -> Option<Instant> {
+        (0..N)
+            .filter_map(|i| {
+                let config = self.overrides[i].unwrap_or(self.config);
+                if !config.enabled || config.wants_press(self.pressed[i], self.raw[i]) == self.pressed[i] {
+                    return None;
+                }
+                let hold_ms = if self.pressed[i] { config.min_press_ms } else { config.min_release_ms };
+                self.changed_at[i].map(|t| t + Duration::from_millis(hold_ms as u64))
+            })
+            .min()
+    }
+
+    /// Re-applies the last raw frame once hold times have elapsed; returns the output if it changed.
+    pub fn refresh(&mut self, now: Instant) -> Option<[u8; N]> {
+        if !self.any_enabled() {
+            return None;
+        }
+        let before = self.output;
+        self.evaluate(now, None);
+        if self.output == before { None } else { Some(self.output) }
+    }
+
+    /// `prev` is the previous raw frame; a blocked flip is counted once, when the raw input changes.
+    fn evaluate(&mut self, now: Instant, prev: Option<&[u8; N]>) {
+        for i in 0..N {
+            let value = self.raw[i];
+            let config = self.overrides[i].unwrap_or(self.config);
+            if !config.enabled {
+                self.pressed[i] = value != 0;
+                self.output[i] = value;
+                continue;
+            }
+            let want = config.wants_press(self.pressed[i], value);
+
+            if want != self.pressed[i] {
+                let hold_ms = if self.pressed[i] { config.min_press_ms } else { config.min_release_ms };
+                let hold = Duration::from_millis(hold_ms as u64);
+                let settled = self.changed_at[i].is_none_or(|t| now.duration_since(t) >= hold);
+                if settled {
+                    self.pressed[i] = want;
+                    self.changed_at[i] = Some(now);
+                } else if prev.is_some_and(|p| p[i] != value) {
+                    self.suppressed += 1;
+                }
+            }
+
+            if self.pressed[i] {
+                // A press held through a blocked release keeps its last value.
+                self.output[i] = if value != 0 { value } else { std::cmp::max(self.output[i], 1) };
+            } else {
+                self.output[i] = 0;
+            }
+        }
+    }
+}
+
+/// Chatter filter applied to decoded input before remapping. Settings are shared;
+/// press state is tracked per client so one client's frames never settle another's cells.
+pub struct InputFilter {
+    slider: CellFilter<32>,
+    air: CellFilter<6>,
+    clients: HashMap<Option<IpAddr>, ClientFilter>,
+    /// Suppressions counted by clients that have since been forgotten.
+    retired: FilterStats,
+}
+
+struct ClientFilter {
+    slider: CellFilter<32>,
+    air: CellFilter<6>,
+}
+
+impl InputFilter {
+    pub fn new() -> Self {
+        Self {
+            slider: CellFilter::new(DebounceConfig::default()),
+            air: CellFilter::new(DebounceConfig::default()),
+            clients: HashMap::new(),
+            retired: FilterStats::default(),
+        }
+    }
+
+    pub fn config(&self, target: FilterTarget) -> DebounceConfig {
+        match target {
+            FilterTarget::Slider => self.slider.config(),
+            FilterTarget::Air => self.air.config(),
+        }
+    }
+
+    pub fn set_config(&mut self, target: FilterTarget, config: DebounceConfig) {
+        match target {
+            FilterTarget::Slider => {
+                self.slider.set_config(config);
+                self.clients.values_mut().for_each(|client| client.slider.set_config(config));
+            }
+            FilterTarget::Air => {
+                self.air.set_config(config);
+                self.clients.values_mut().for_each(|client| client.air.set_config(config));
+            }
+        }
+    }
+
+    pub fn cell_config(&self, target: FilterTarget, cell: usize) -> Result<DebounceConfig, String> {
+        match target {
+            FilterTarget::Slider => self.slider.cell_config(cell),
+            FilterTarget::Air => self.air.cell_config(cell),
+        }
+    }
+
+    pub fn set_cell_config(&mut self, target: FilterTarget, cell: usize, config: Option<DebounceConfig>) -> Result<(), String> {
+        match target {
+            FilterTarget::Slider => {
+                self.slider.set_cell_config(cell, config)?;
+                for client in self.clients.values_mut() {
+                    client.slider.set_cell_config(cell, config)?;
+                }
+            }
+            FilterTarget::Air => {
+                self.air.set_cell_config(cell, config)?;
+                for client in self.clients.values_mut() {
+                    client.air.set_cell_config(cell, config)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn process(&mut self, src: Option<IpAddr>, air: &[u8; 6], slider: &[u8; 32], now: Instant) -> ([u8; 6], [u8; 32]) {
+        let client = self.clients.entry(src).or_insert_with(|| ClientFilter {
+            slider: Self::fresh(&self.slider),
+            air: Self::fresh(&self.air),
+        });
+        (client.air.process(air, now), client.slider.process(slider, now))
+    }
+
+    /// A filter with `template`'s settings and no press state.
+    fn fresh<const N: usize>(template: &CellFilter<N>) -> CellFilter<N> {
+        CellFilter { overrides: template.overrides, ..CellFilter::new(template.config) }
+    }
+
+    /// When any client's blocked flip may go through.
+    pub fn next_change(&self) -> Option<Instant> {
+        self.clients
+            .values()
+            .flat_map(|client| [client.air.next_change(), client.slider.next_change()])
+            .flatten()
+            .min()
+    }
+
+    /// Settles every client's pending flips; returns the new output of each client that changed.
+    pub fn refresh(&mut self, now: Instant) -> Vec<(Option<IpAddr>, [u8; 6], [u8; 32])> {
+        let mut changed = Vec::new();
+        for (src, client) in self.clients.iter_mut() {
+            let air = client.air.refresh(now);
+            let slider = client.slider.refresh(now);
+            if air.is_some() || slider.is_some() {
+                changed.push((*src, client.air.output, client.slider.output));
+            }
+        }
+        changed
+    }
+
+    /// Drops a client's press state, keeping its suppression counts.
+    pub fn forget(&mut self, src: Option<IpAddr>) {
+        if let Some(client) = self.clients.remove(&src) {
+            self.retired.slider_suppressed += client.slider.suppressed();
+            self.retired.air_suppressed += client.air.suppressed();
+        }
+    }
+
+    pub fn stats(&self) -> FilterStats {
+        self.clients.values().fold(self.retired, |stats, client| FilterStats {
+            slider_suppressed: stats.slider_suppressed + client.slider.suppressed(),
+            air_suppressed: stats.air_suppressed + client.air.suppressed(),
+        })
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.retired = FilterStats::default();
+        for client in self.clients.values_mut() {
+            client.slider.reset_stats();
+            client.air.reset_stats();
+        }
+    }
+}
+
+impl Default for InputFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ON: DebounceConfig = DebounceConfig {
+        enabled: true,
+        min_press_ms: 10,
+        min_release_ms: 20,
+        press_threshold: 1,
+        release_threshold: 1,
+    };
+
+    fn ms(base: Instant, offset: u64) -> Instant {
+        base + Duration::from_millis(offset)
+    }
+
+    #[test]
+    fn disabled_filter_passes_input_through() {
+        let mut filter = CellFilter::<2>::new(DebounceConfig::default());
+        let now = Instant::now();
+        assert_eq!(filter.process(&[5, 0], now), [5, 0]);
+        assert_eq!(filter.process(&[0, 7], now), [0, 7]);
+        assert_eq!(filter.refresh(now), None);
+    }
+
+    #[test]
+    fn short_release_is_held_until_the_press_time_passes() {
+        let mut filter = CellFilter::<1>::new(ON);
+        let t = Instant::now();
+        assert_eq!(filter.process(&[200], t), [200]);
+        assert_eq!(filter.process(&[0], ms(t, 5)), [200]);
+        assert_eq!(filter.suppressed(), 1);
+        assert_eq!(filter.refresh(ms(t, 9)), None);
+        assert_eq!(filter.refresh(ms(t, 10)), Some([0]));
+    }
+
+    #[test]
+    fn bounce_back_after_release_is_suppressed() {
+        let mut filter = CellFilter::<1>::new(ON);
+        let t = Instant::now();
+        filter.process(&[1], t);
+        assert_eq!(filter.process(&[0], ms(t, 10)), [0]);
+        assert_eq!(filter.process(&[1], ms(t, 15)), [0]);
+        assert_eq!(filter.process(&[0], ms(t, 16)), [0]);
+        assert_eq!(filter.suppressed(), 1);
+        assert_eq!(filter.process(&[1], ms(t, 30)), [1]);
+    }
+
+    #[test]
+    fn values_between_the_thresholds_keep_the_state() {
+        let mut filter = CellFilter::<1>::new(DebounceConfig {
+            press_threshold: 100,
+            release_threshold: 40,
+            min_press_ms: 0,
+            min_release_ms: 0,
+            ..ON
+        });
+        let t = Instant::now();
+        assert_eq!(filter.process(&[60], t), [0]);
+        assert_eq!(filter.process(&[120], t), [120]);
+        assert_eq!(filter.process(&[60], t), [60]);
+        assert_eq!(filter.process(&[39], t), [0]);
+        assert_eq!(filter.process(&[99], t), [0]);
+        assert_eq!(filter.process(&[100], t), [100]);
+    }
+
+    #[test]
+    fn release_threshold_above_press_is_capped() {
+        let mut filter = CellFilter::<1>::new(DebounceConfig {
+            press_threshold: 50,
+            release_threshold: 200,
+            min_press_ms: 0,
+            min_release_ms: 0,
+            ..ON
+        });
+        let t = Instant::now();
+        assert_eq!(filter.process(&[50], t), [50]);
+        assert_eq!(filter.process(&[49], t), [0]);
+    }
+
+    #[test]
+    fn cell_override_replaces_the_shared_config() {
+        let mut filter = CellFilter::<2>::new(DebounceConfig::default());
+        filter.set_cell_config(1, Some(ON)).unwrap();
+        assert_eq!(filter.cell_config(0), Ok(DebounceConfig::default()));
+        assert_eq!(filter.cell_config(1), Ok(ON));
+        assert!(filter.set_cell_config(2, None).is_err());
+
+        let t = Instant::now();
+        filter.process(&[1, 1], t);
+        assert_eq!(filter.process(&[0, 0], ms(t, 1)), [0, 1]);
+
+        filter.set_config(DebounceConfig { min_press_ms: 50, ..DebounceConfig::default() });
+        assert_eq!(filter.cell_config(1), Ok(ON));
+        filter.set_cell_config(1, None).unwrap();
+        assert_eq!(filter.cell_config(1).unwrap().min_press_ms, 50);
+    }
+
+    #[test]
+    fn next_change_is_the_earliest_blocked_flip() {
+        let mut filter = CellFilter::<2>::new(ON);
+        let t = Instant::now();
+        filter.process(&[1, 0], t);
+        assert_eq!(filter.next_change(), None);
+        filter.process(&[0, 1], ms(t, 4));
+        assert_eq!(filter.next_change(), Some(ms(t, 10)));
+        assert_eq!(filter.refresh(ms(t, 10)), Some([0, 1]));
+        assert_eq!(filter.next_change(), None);
+    }
+
+    #[test]
+    fn clients_are_filtered_separately() {
+        let a = Some(IpAddr::from([10, 0, 0, 1]));
+        let b = Some(IpAddr::from([10, 0, 0, 2]));
+        let mut filter = InputFilter::new();
+        filter.set_config(FilterTarget::Air, ON);
+        let t = Instant::now();
+        let pressed = [1, 0, 0, 0, 0, 0];
+
+        filter.process(a, &pressed, &[0; 32], t);
+        assert_eq!(filter.process(b, &[0; 6], &[0; 32], ms(t, 5)).0, [0; 6]);
+        assert_eq!(filter.process(a, &[0; 6], &[0; 32], ms(t, 5)).0, pressed);
+        assert_eq!(filter.next_change(), Some(ms(t, 10)));
+
+        let changed = filter.refresh(ms(t, 10));
+        assert_eq!(changed, vec![(a, [0; 6], [0; 32])]);
+        assert_eq!(filter.stats().air_suppressed, 1);
+        filter.forget(a);
+        assert_eq!(filter.stats().air_suppressed, 1);
+        assert_eq!(filter.next_change(), None);
+    }
+}
@@ -0,0 +1,342 @@
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+
+/// Optional marker before a stream frame's length prefix. Read as a length it is 0x5AA5,
+/// far above any allowed frame size, so it never collides with a legacy prefix.
+pub const FRAME_MAGIC: [u8; 2] = [0xA5, 0x5A];
+/// Largest `max_frame` allowed; anything higher would read `FRAME_MAGIC` as a length.
+pub const MAX_FRAME_LIMIT: u32 = 0x5AA4;
+/// Closed connections kept for `ConnectionTable::all` after they end.
+pub const CLOSED_CONNECTIONS_KEPT: usize = 16;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum FramingPolicy {
+    /// Close the connection on the first framing error.
+    Disconnect,
+    /// Skip to the next `FRAME_MAGIC` and only accept marked frames until one is found.
+    Resync,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct FramingConfig {
+    /// Largest payload accepted from a length prefix.
+    pub max_frame: u32,
+    pub policy: FramingPolicy,
+}
+
+impl FramingConfig {
+    pub fn clamped(self) -> Self {
+        Self { max_frame: self.max_frame.min(MAX_FRAME_LIMIT), ..self }
+    }
+}
+
+impl Default for FramingConfig {
+    fn default() -> Self {
+        Self {
+            max_frame: 512,
+            policy: FramingPolicy::Disconnect,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct FramingStats {
+    pub frames: u64,
+    /// Length prefixes above `max_frame`.
+    pub oversized: u64,
+    pub resyncs: u64,
+    pub discarded_bytes: u64,
+}
+
+/// Per-connection counters.
+#[derive(Clone, Debug)]
+pub struct ConnectionStats {
+    pub client: String,
+    pub transport: String,
+    pub connected: bool,
+    pub framing: FramingStats,
+}
+
+/// Open connections plus the last `CLOSED_CONNECTIONS_KEPT` closed ones, so reconnects
+/// do not grow it without bound.
+#[derive(Default)]
+pub struct ConnectionTable {
+    open: HashMap<SocketAddr, ConnectionStats>,
+    closed: VecDeque<ConnectionStats>,
+}
+
+impl ConnectionTable {
+    pub fn update(&mut self, peer: SocketAddr, stats: ConnectionStats) {
+        if stats.connected {
+            self.open.insert(peer, stats);
+            return;
+        }
+        self.open.remove(&peer);
+        if self.closed.len() == CLOSED_CONNECTIONS_KEPT {
+            self.closed.pop_front();
+        }
+        self.closed.push_back(stats);
+    }
+
+    pub fn all(&self) -> Vec<ConnectionStats> {
+        self.open.values().chain(self.closed.iter()).cloned().collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.open.clear();
+        self.closed.clear();
+    }
+}
+
+/// Reassembles u16 length-prefixed frames from a byte stream.
+/// The buffer never holds more than one frame plus its header and the last read.
+pub struct FrameDecoder {
+    config: FramingConfig,
+    buffer: Vec<u8>,
+    /// False while resyncing: only frames behind `FRAME_MAGIC` are accepted.
+    synced: bool,
+    stats: FramingStats,
+}
+
+impl FrameDecoder {
+    pub fn new(config: FramingConfig) -> Self {
+        Self {
+            config,
+            buffer: Vec::with_capacity(256),
+            synced: true,
+            stats: FramingStats::default(),
+        }
+    }
+
+    pub fn push(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    pub fn stats(&self) -> FramingStats {
+        self.stats
+    }
+
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Next complete frame, `Ok(None)` when more bytes are needed. Errors only under
+    /// `FramingPolicy::Disconnect`, after which the stream cannot be trusted.
+    pub fn next_frame(&mut self) -> Result<Option<Vec<u8>>, String> {
+        loop {
+            if !self.synced && !self.seek_magic() {
+                return Ok(None);
+            }
+            let marked = self.buffer.starts_with(&FRAME_MAGIC);
+            let header = if marked { 4 } else { 2 };
+            if self.buffer.len() < header {
+                return Ok(None);
+            }
+            let frame_len = u16::from_le_bytes([self.buffer[header - 2], self.buffer[header - 1]]) as usize;
+            if frame_len > self.config.max_frame as usize {
+                self.stats.oversized += 1;
+                match self.config.policy {
+                    FramingPolicy::Disconnect => {
+                        return Err(format!("Frame length {} exceeds {}", frame_len, self.config.max_frame));
+                    }
+                    FramingPolicy::Resync => {
+                        // Step past this header's first byte so its own magic is not found again.
+                        self.discard(1);
+                        self.synced = false;
+                        self.stats.resyncs += 1;
+                        continue;
+                    }
+                }
+            }
+            if self.buffer.len() < header + frame_len {
+                return Ok(None);
+            }
+            let frame = self.buffer[header..header + frame_len].to_vec();
+            self.buffer.drain(..header + frame_len);
+            if frame.is_empty() {
+                continue;
+            }
+            self.stats.frames += 1;
+            return Ok(Some(frame));
+        }
+    }
+
+    /// Drops bytes up to the next marker; keeps a trailing partial marker.
+    fn seek_magic(&mut self) -> bool {
+        match self.buffer.windows(2).position(|w| w == FRAME_MAGIC) {
+            Some(start) => {
+                self.discard(start);
+                self.synced = true;
+                true
+            }
+            None => {
+                let keep = usize::from(self.buffer.last() == Some(&FRAME_MAGIC[0]));
+                self.discard(self.buffer.len() - keep);
+                false
+            }
+        }
+    }
+
+    fn discard(&mut self, count: usize) {
+        self.buffer.drain(..count);
+        self.stats.discarded_bytes += count as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decoder(max_frame: u32, policy: FramingPolicy) -> FrameDecoder {
+        FrameDecoder::new(FramingConfig { max_frame, policy })
+    }
+
+    fn frames(decoder: &mut FrameDecoder) -> Vec<Vec<u8>> {
+        let mut out = Vec::new();
+        while let Some(frame) = decoder.next_frame().unwrap() {
+            out.push(frame);
+        }
+        out
+    }
+
+    fn closed(port: u16) -> ConnectionStats {
+        ConnectionStats {
+            client: SocketAddr::from(([127, 0, 0, 1], port)).to_string(),
+            transport: "tcp".to_string(),
+            connected: false,
+            framing: FramingStats::default(),
+        }
+    }
+
+    #[test]
+    fn marked_and_unmarked_frames_decode() {
+        let mut decoder = decoder(8, FramingPolicy::Disconnect);
+        decoder.push(&[1, 0, 7, 0xA5, 0x5A, 2, 0, 8, 9, 1]);
+        assert_eq!(frames(&mut decoder), vec![vec![7], vec![8, 9]]);
+        assert_eq!(decoder.buffered(), 1);
+
+        decoder.push(&[0, 6]);
+        assert_eq!(frames(&mut decoder), vec![vec![6]]);
+        assert_eq!(decoder.stats().frames, 3);
+    }
+
+    #[test]
+    fn frames_split_across_reads_decode() {
+        let mut decoder = decoder(8, FramingPolicy::Disconnect);
+        for byte in [0xA5, 0x5A, 3, 0, 1, 2] {
+            decoder.push(&[byte]);
+            assert_eq!(decoder.next_frame(), Ok(None));
+        }
+        decoder.push(&[3]);
+        assert_eq!(decoder.next_frame(), Ok(Some(vec![1, 2, 3])));
+    }
+
+    #[test]
+    fn empty_frames_are_skipped() {
+        let mut decoder = decoder(8, FramingPolicy::Disconnect);
+        decoder.push(&[0, 0, 0xA5, 0x5A, 0, 0, 1, 0, 3]);
+        assert_eq!(frames(&mut decoder), vec![vec![3]]);
+        assert_eq!(decoder.stats().frames, 1);
+        assert_eq!(decoder.buffered(), 0);
+    }
+
+    #[test]
+    fn oversized_frame_disconnects() {
+        let mut unmarked = decoder(8, FramingPolicy::Disconnect);
+        unmarked.push(&[1, 0, 7, 9, 0]);
+        assert_eq!(unmarked.next_frame(), Ok(Some(vec![7])));
+        assert!(unmarked.next_frame().is_err());
+        assert_eq!(unmarked.stats(), FramingStats { frames: 1, oversized: 1, resyncs: 0, discarded_bytes: 0 });
+
+        let mut marked = decoder(8, FramingPolicy::Disconnect);
+        marked.push(&[0xA5, 0x5A, 9, 0]);
+        assert!(marked.next_frame().is_err());
+    }
+
+    #[test]
+    fn resync_skips_to_the_next_magic() {
+        let mut decoder = decoder(8, FramingPolicy::Resync);
+        decoder.push(&[0xFF, 0xFF, 1, 0, 2, 0xA5, 0x5A, 2, 0, 7, 8, 1, 0, 9]);
+        assert_eq!(frames(&mut decoder), vec![vec![7, 8], vec![9]]);
+        assert_eq!(decoder.stats(), FramingStats { frames: 2, oversized: 1, resyncs: 1, discarded_bytes: 5 });
+    }
+
+    #[test]
+    fn oversized_marked_frame_resyncs_past_its_own_magic() {
+        let mut decoder = decoder(8, FramingPolicy::Resync);
+        decoder.push(&[0xA5, 0x5A, 9, 0, 0xA5, 0x5A, 1, 0, 4]);
+        assert_eq!(frames(&mut decoder), vec![vec![4]]);
+        assert_eq!(decoder.stats().discarded_bytes, 4);
+    }
+
+    #[test]
+    fn partial_magic_is_kept_for_the_next_read() {
+        let mut decoder = decoder(8, FramingPolicy::Resync);
+        decoder.push(&[0xFF, 0xFF, 1, 0xA5]);
+        assert_eq!(decoder.next_frame(), Ok(None));
+        assert_eq!(decoder.buffered(), 1);
+
+        decoder.push(&[0x5A, 1, 0, 9]);
+        assert_eq!(frames(&mut decoder), vec![vec![9]]);
+        assert_eq!(decoder.stats().discarded_bytes, 3);
+    }
+
+    #[test]
+    fn unmarked_frames_are_ignored_until_resynced() {
+        let mut decoder = decoder(8, FramingPolicy::Resync);
+        decoder.push(&[0xFF, 0xFF, 1, 0, 5]);
+        assert_eq!(decoder.next_frame(), Ok(None));
+        assert_eq!(decoder.buffered(), 0);
+    }
+
+    #[test]
+    fn arbitrary_input_stays_bounded() {
+        let mut seed = 0x2545_F491u32;
+        for policy in [FramingPolicy::Disconnect, FramingPolicy::Resync] {
+            let mut decoder = decoder(32, policy);
+            'stream: for _ in 0..2000 {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                decoder.push(&seed.to_le_bytes()[..(seed % 4 + 1) as usize]);
+                loop {
+                    match decoder.next_frame() {
+                        Ok(Some(frame)) => assert!(!frame.is_empty() && frame.len() <= 32),
+                        Ok(None) => break,
+                        Err(_) => break 'stream,
+                    }
+                }
+                assert!(decoder.buffered() <= 32 + 4 + 4);
+            }
+        }
+    }
+
+    #[test]
+    fn config_is_clamped_below_the_magic() {
+        let config = FramingConfig { max_frame: u32::MAX, policy: FramingPolicy::Resync }.clamped();
+        assert_eq!(config.max_frame, MAX_FRAME_LIMIT);
+        assert!((config.max_frame as usize) < u16::from_le_bytes(FRAME_MAGIC) as usize);
+    }
+
+    #[test]
+    fn connection_table_keeps_the_latest_closed_connections() {
+        let mut table = ConnectionTable::default();
+        let peer = SocketAddr::from(([127, 0, 0, 1], 9000));
+        table.update(peer, ConnectionStats { connected: true, ..closed(9000) });
+        assert_eq!(table.all().len(), 1);
+        assert!(table.all()[0].connected);
+
+        for port in 0..CLOSED_CONNECTIONS_KEPT as u16 + 4 {
+            table.update(SocketAddr::from(([127, 0, 0, 1], port)), closed(port));
+        }
+        table.update(peer, closed(9000));
+        let all = table.all();
+        assert_eq!(all.len(), CLOSED_CONNECTIONS_KEPT);
+        assert!(all.iter().all(|stats| !stats.connected));
+        assert_eq!(all[0].client, "127.0.0.1:5");
+        assert_eq!(all.last().unwrap().client, "127.0.0.1:9000");
+
+        table.clear();
+        assert!(table.all().is_empty());
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct AirGestureConfig {
+    pub enabled: bool,
+    /// Longest time from the bottom cell to the top cell of a key that still counts as a swipe.
+    pub max_swipe_ms: u32,
+    /// Keys that must swipe within one `max_swipe_ms` window to trigger.
+    pub min_keys: u8,
+    /// Time for the synthesised hand to rise from the lowest to the highest sensor.
+    pub rise_ms: u32,
+    /// Time all sensors stay blocked after the rise.
+    pub hold_ms: u32,
+}
+
+impl Default for AirGestureConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_swipe_ms: 120,
+            min_keys: 1,
+            rise_ms: 60,
+            hold_ms: 80,
+        }
+    }
+}
+
+/// Synthesises air sensor activity from upward swipes on the slider.
+///
+/// Each of the 16 keys owns two cells: the top cell at `2 * key` and the
+/// bottom cell at `2 * key + 1`. A key swipes when its top cell is pressed
+/// within `max_swipe_ms` of its bottom cell. Swipes are tracked per client, so
+/// frames from two controllers never combine into one swipe.
+pub struct AirGesture {
+    config: AirGestureConfig,
+    clients: HashMap<Option<IpAddr>, SwipeTracker>,
+    triggered_at: Option<Instant>,
+    triggers: u64,
+    client_air: [u8; 6],
+    output: [u8; 6],
+}
+
+#[derive(Default)]
+struct SwipeTracker {
+    prev_slider: [u8; 32],
+    bottom_since: [Option<Instant>; 16],
+    swipes: Vec<Instant>,
+}
+
+impl AirGesture {
+    pub fn new(config: AirGestureConfig) -> Self {
+        Self {
+            config,
+            clients: HashMap::new(),
+            triggered_at: None,
+            triggers: 0,
+            client_air: [0u8; 6],
+            output: [0u8; 6],
+        }
+    }
+
+    pub fn config(&self) -> AirGestureConfig {
+        self.config
+    }
+
+    pub fn set_config(&mut self, config: AirGestureConfig) {
+        *self = Self::new(config);
+    }
+
+    /// Feeds one slider frame from `src` and returns the air state to write.
+    pub fn process(&mut self, src: Option<IpAddr>, client_air: &[u8; 6], slider: &[u8; 32], now: Instant) -> [u8; 6] {
+        self.client_air = *client_air;
+        if !self.config.enabled {
+            self.output = *client_air;
+            return self.output;
+        }
+
+        let window = Duration::from_millis(self.config.max_swipe_ms as u64);
+        let tracker = self.clients.entry(src).or_default();
+        for key in 0..16 {
+            let top = 2 * key;
+            let bottom = top + 1;
+            let bottom_pressed = slider[bottom] != 0;
+            let top_pressed = slider[top] != 0;
+
+            if bottom_pressed && tracker.prev_slider[bottom] == 0 {
+                tracker.bottom_since[key] = Some(now);
+            }
+            if top_pressed && tracker.prev_slider[top] == 0 {
+                if let Some(since) = tracker.bottom_since[key].take() {
+                    if now.duration_since(since) <= window {
+                        tracker.swipes.push(now);
+                    }
+                }
+            }
+            if let Some(since) = tracker.bottom_since[key] {
+                if now.duration_since(since) > window {
+                    tracker.bottom_since[key] = None;
+                }
+            }
+        }
+        tracker.prev_slider = *slider;
+
+        tracker.swipes.retain(|t| now.duration_since(*t) <= window);
+        if tracker.swipes.len() >= self.config.min_keys.max(1) as usize {
+            self.triggered_at = Some(now);
+            self.triggers += 1;
+            tracker.swipes.clear();
+        }
+
+        self.output = self.compose(now);
+        self.output
+    }
+
+    /// Counts triggered gestures, so a driver can tell a retrigger from its own.
+    pub fn triggers(&self) -> u64 {
+        self.triggers
+    }
+
+    /// When the running animation next changes the air state, if one is running.
+    pub fn next_change(&self, now: Instant) -> Option<Instant> {
+        let started = self.triggered_at?;
+        let elapsed = now.duration_since(started).as_millis() as u64;
+        let rise = self.config.rise_ms as u64;
+        // Cell `step + 1` lights once `elapsed * 6 / rise` reaches `step`.
+        let step = (1..6)
+            .map(|step| (step * rise).div_ceil(6))
+            .find(|&at| at > elapsed);
+        let at = step.unwrap_or(rise + self.config.hold_ms as u64);
+        Some(started + Duration::from_millis(at))
+    }
+
+    /// Advances the animation without new input; returns the new air state if it changed.
+    pub fn refresh(&mut self, now: Instant) -> Option<[u8; 6]> {
+        if !self.config.enabled {
+            return None;
+        }
+        let next = self.compose(now);
+        if next == self.output {
+            return None;
+        }
+        self.output = next;
+        Some(next)
+    }
+
+    fn compose(&mut self, now: Instant) -> [u8; 6] {
+        let mut air = self.client_air;
+        let started = match self.triggered_at {
+            Some(t) => t,
+            None => return air,
+        };
+        let elapsed = now.duration_since(started).as_millis() as u64;
+        let rise = self.config.rise_ms as u64;
+        if elapsed >= rise + self.config.hold_ms as u64 {
+            self.triggered_at = None;
+            return air;
+        }
+        let lit = match (elapsed * 6).checked_div(rise) {
+            Some(step) => std::cmp::min(6, 1 + step) as usize,
+            None => 6,
+        };
+        for cell in air.iter_mut().take(lit) {
+            *cell = 1;
+        }
+        air
+    }
+}
+
+impl Default for AirGesture {
+    fn default() -> Self {
+        Self::new(AirGestureConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> AirGesture {
+        AirGesture::new(AirGestureConfig { enabled: true, ..AirGestureConfig::default() })
+    }
+
+    fn cells(pressed: &[usize]) -> [u8; 32] {
+        let mut slider = [0u8; 32];
+        for &cell in pressed {
+            slider[cell] = 1;
+        }
+        slider
+    }
+
+    fn swipe(gesture: &mut AirGesture, key: usize, start: Instant, gap: Duration) -> [u8; 6] {
+        gesture.process(None, &[0; 6], &cells(&[2 * key + 1]), start);
+        gesture.process(None, &[0; 6], &cells(&[2 * key, 2 * key + 1]), start + gap)
+    }
+
+    #[test]
+    fn upward_swipe_raises_and_releases_the_hand() {
+        let mut gesture = enabled();
+        let t0 = Instant::now();
+        assert_eq!(swipe(&mut gesture, 3, t0, Duration::from_millis(20)), [1, 0, 0, 0, 0, 0]);
+        let t = t0 + Duration::from_millis(20);
+        assert_eq!(gesture.refresh(t + Duration::from_millis(30)), Some([1, 1, 1, 1, 0, 0]));
+        assert_eq!(gesture.refresh(t + Duration::from_millis(100)), Some([1; 6]));
+        assert_eq!(gesture.refresh(t + Duration::from_millis(140)), Some([0; 6]));
+        assert_eq!(gesture.next_change(t + Duration::from_millis(140)), None);
+    }
+
+    #[test]
+    fn next_change_walks_every_step_to_the_release() {
+        let mut gesture = enabled();
+        let t0 = Instant::now();
+        swipe(&mut gesture, 0, t0, Duration::ZERO);
+        let mut now = t0;
+        let mut steps = Vec::new();
+        while let Some(at) = gesture.next_change(now) {
+            now = at;
+            steps.push(at.duration_since(t0).as_millis());
+            gesture.refresh(now);
+        }
+        assert_eq!(steps, vec![10, 20, 30, 40, 50, 140]);
+        assert_eq!(gesture.triggers(), 1);
+    }
+
+    #[test]
+    fn slow_swipes_and_disabled_gestures_pass_the_client_air() {
+        let mut gesture = enabled();
+        let t0 = Instant::now();
+        assert_eq!(swipe(&mut gesture, 5, t0, Duration::from_millis(200)), [0; 6]);
+        assert_eq!(gesture.triggers(), 0);
+
+        let mut disabled = AirGesture::default();
+        let client = [1, 0, 1, 0, 0, 0];
+        assert_eq!(disabled.process(None, &client, &cells(&[11]), t0), client);
+        assert_eq!(disabled.process(None, &client, &cells(&[10, 11]), t0), client);
+        assert_eq!(disabled.next_change(t0), None);
+    }
+
+    #[test]
+    fn min_keys_needs_that_many_swipes_in_the_window() {
+        let mut gesture = AirGesture::new(AirGestureConfig {
+            enabled: true,
+            min_keys: 2,
+            ..AirGestureConfig::default()
+        });
+        let t0 = Instant::now();
+        assert_eq!(swipe(&mut gesture, 1, t0, Duration::from_millis(10)), [0; 6]);
+        let both = gesture.process(None, &[0; 6], &cells(&[2, 3, 5]), t0 + Duration::from_millis(20));
+        assert_eq!(both, [0; 6]);
+        assert_eq!(gesture.process(None, &[0; 6], &cells(&[2, 3, 4, 5]), t0 + Duration::from_millis(30)), [1, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn frames_from_different_clients_do_not_form_a_swipe() {
+        let mut gesture = enabled();
+        let a = Some(IpAddr::from([10, 0, 0, 1]));
+        let b = Some(IpAddr::from([10, 0, 0, 2]));
+        let t0 = Instant::now();
+        gesture.process(a, &[0; 6], &cells(&[7]), t0);
+        assert_eq!(gesture.process(b, &[0; 6], &cells(&[6]), t0 + Duration::from_millis(5)), [0; 6]);
+        assert_eq!(gesture.process(b, &[0; 6], &cells(&[6, 7]), t0 + Duration::from_millis(10)), [0; 6]);
+        assert_eq!(gesture.triggers(), 0);
+        assert_eq!(gesture.process(a, &[0; 6], &cells(&[6, 7]), t0 + Duration::from_millis(15)), [1, 0, 0, 0, 0, 0]);
+        assert_eq!(gesture.triggers(), 1);
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use serde::Serialize;
+
+/// Exact buckets below this many microseconds, then eight per power of two.
+const LINEAR_US: u64 = 16;
+const SUB_BUCKETS: u64 = 8;
+const BUCKETS: usize = 16 + 20 * 8;
+
+#[derive(Serialize, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum LatencySource {
+    /// Socket receive to shared-memory write.
+    Receive,
+    /// Client timestamp to shared-memory write, less the fastest transit seen from that
+    /// client: the fixed part of the one-way delay cannot be told apart from clock offset.
+    Client,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct LatencyStats {
+    /// "udp", "tcp", "tcp-loopback" (adb reverse and local relays) or "unix".
+    pub transport: String,
+    pub source: LatencySource,
+    pub count: u64,
+    pub mean_us: u64,
+    pub p50_us: u64,
+    pub p90_us: u64,
+    pub p99_us: u64,
+    pub max_us: u64,
+}
+
+/// When a control sample arrived, carried along to the shared-memory write.
+#[derive(Clone, Copy, Debug)]
+pub struct LatencyMark {
+    pub transport: &'static str,
+    pub received: Instant,
+    /// Client send time mapped onto the server clock.
+    pub client: Option<Instant>,
+}
+
+impl LatencyMark {
+    /// For batched samples, which are due `by` after the batch arrived.
+    pub fn shifted(&self, by: Duration) -> Self {
+        Self {
+            transport: self.transport,
+            received: self.received + by,
+            client: self.client.map(|c| c + by),
+        }
+    }
+}
+
+struct Histogram {
+    buckets: [u64; BUCKETS],
+    count: u64,
+    sum_us: u64,
+    max_us: u64,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Self { buckets: [0; BUCKETS], count: 0, sum_us: 0, max_us: 0 }
+    }
+
+    fn index(us: u64) -> usize {
+        if us < LINEAR_US {
+            return us as usize;
+        }
+        let exp = 63 - us.leading_zeros() as u64;
+        let sub = (us >> (exp - 3)) & (SUB_BUCKETS - 1);
+        std::cmp::min((LINEAR_US + (exp - 4) * SUB_BUCKETS + sub) as usize, BUCKETS - 1)
+    }
+
+    /// Largest value that lands in bucket `index`.
+    fn upper(index: usize) -> u64 {
+        let index = index as u64;
+        if index < LINEAR_US {
+            return index;
+        }
+        let exp = (index - LINEAR_US) / SUB_BUCKETS + 4;
+        let sub = (index - LINEAR_US) % SUB_BUCKETS;
+        ((SUB_BUCKETS + sub + 1) << (exp - 3)) - 1
+    }
+
+    fn record(&mut self, us: u64) {
+        self.buckets[Self::index(us)] += 1;
+        self.count += 1;
+        self.sum_us = self.sum_us.saturating_add(us);
+        self.max_us = self.max_us.max(us);
+    }
+
+    fn percentile(&self, p: f64) -> u64 {
+        if self.count == 0 {
+            return 0;
+        }
+        let rank = ((self.count as f64) * p).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (index, &n) in self.buckets.iter().enumerate() {
+            seen += n;
+            if seen >= rank {
+                // The top bucket is open-ended, so only the observed max bounds it.
+                return match index {
+                    i if i == BUCKETS - 1 => self.max_us,
+                    i => Self::upper(i).min(self.max_us),
+                };
+            }
+        }
+        self.max_us
+    }
+}
+
+/// Per-transport histograms of input latency.
+pub struct LatencyRecorder {
+    epoch: Instant,
+    histograms: HashMap<(&'static str, LatencySource), Histogram>,
+    /// Smallest `receive - client timestamp` seen per client, in wrapping microseconds.
+    baselines: HashMap<SocketAddr, u32>,
+}
+
+impl LatencyRecorder {
+    pub fn new() -> Self {
+        Self { epoch: Instant::now(), histograms: HashMap::new(), baselines: HashMap::new() }
+    }
+
+    /// `client_us` is the client's wrapping microsecond clock, if the packet carried one.
+    pub fn mark(&mut self, transport: &'static str, src: SocketAddr, received: Instant, client_us: Option<u32>) -> LatencyMark {
+        let client = client_us.and_then(|client_us| {
+            let received_us = received.saturating_duration_since(self.epoch).as_micros() as u32;
+            let transit = received_us.wrapping_sub(client_us);
+            let base = self.baselines.entry(src).or_insert(transit);
+            if (transit.wrapping_sub(*base) as i32) < 0 {
+                *base = transit;
+            }
+            let extra = transit.wrapping_sub(*base) as u64;
+            received.checked_sub(Duration::from_micros(extra))
+        });
+        LatencyMark { transport, received, client }
+    }
+
+    pub fn record(&mut self, mark: &LatencyMark, written: Instant) {
+        let receive_us = written.saturating_duration_since(mark.received).as_micros() as u64;
+        self.histograms.entry((mark.transport, LatencySource::Receive)).or_insert_with(Histogram::new).record(receive_us);
+        if let Some(client) = mark.client {
+            let client_us = written.saturating_duration_since(client).as_micros() as u64;
+            self.histograms.entry((mark.transport, LatencySource::Client)).or_insert_with(Histogram::new).record(client_us);
+        }
+    }
+
+    pub fn stats(&self) -> Vec<LatencyStats> {
+        let mut stats: Vec<LatencyStats> = self
+            .histograms
+            .iter()
+            .map(|(&(transport, source), h)| LatencyStats {
+                transport: transport.to_string(),
+                source,
+                count: h.count,
+                mean_us: h.sum_us / h.count.max(1),
+                p50_us: h.percentile(0.50),
+                p90_us: h.percentile(0.90),
+                p99_us: h.percentile(0.99),
+                max_us: h.max_us,
+            })
+            .collect();
+        stats.sort_by(|a, b| a.transport.cmp(&b.transport).then((a.source as u8).cmp(&(b.source as u8))));
+        stats
+    }
+
+    /// Drops the transit baseline of a client that went away.
+    pub fn forget(&mut self, src: SocketAddr) {
+        self.baselines.remove(&src);
+    }
+
+    pub fn reset(&mut self) {
+        self.histograms.clear();
+        self.baselines.clear();
+    }
+}
+
+impl Default for LatencyRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    fn us(base: Instant, offset: u64) -> Instant {
+        base + Duration::from_micros(offset)
+    }
+
+    /// How far `mark` puts the client send time before its receive time.
+    fn extra_us(mark: &LatencyMark) -> u64 {
+        mark.received.duration_since(mark.client.unwrap()).as_micros() as u64
+    }
+
+    #[test]
+    fn buckets_are_exact_then_logarithmic() {
+        for value in 0..LINEAR_US {
+            assert_eq!(Histogram::index(value), value as usize);
+            assert_eq!(Histogram::upper(value as usize), value);
+        }
+        assert_eq!(Histogram::index(16), 16);
+        assert_eq!(Histogram::index(17), 16);
+        assert_eq!(Histogram::upper(16), 17);
+        assert_eq!(Histogram::index(18), 17);
+        assert_eq!(Histogram::index(31), 23);
+        assert_eq!(Histogram::index(32), 24);
+        assert_eq!(Histogram::upper(24), 35);
+
+        for index in 0..BUCKETS - 1 {
+            let upper = Histogram::upper(index);
+            assert_eq!(Histogram::index(upper), index);
+            assert_eq!(Histogram::index(upper + 1), index + 1);
+        }
+        assert_eq!(Histogram::index(u64::MAX), BUCKETS - 1);
+    }
+
+    #[test]
+    fn percentiles_are_bucket_bounds_clamped_to_the_max() {
+        let mut histogram = Histogram::new();
+        assert_eq!(histogram.percentile(0.5), 0);
+        for value in [1, 2, 3, 4, 5, 6, 7, 8, 9, 20] {
+            histogram.record(value);
+        }
+        assert_eq!(histogram.percentile(0.5), 5);
+        assert_eq!(histogram.percentile(0.9), 9);
+        assert_eq!(histogram.percentile(0.99), 20);
+
+        histogram.record(100_000_000);
+        assert_eq!(Histogram::index(100_000_000), BUCKETS - 1);
+        assert!(Histogram::upper(BUCKETS - 1) < 100_000_000);
+        assert_eq!(histogram.percentile(0.99), 100_000_000);
+    }
+
+    #[test]
+    fn client_latency_is_measured_from_the_fastest_transit() {
+        let mut recorder = LatencyRecorder::new();
+        let epoch = recorder.epoch;
+        let first = recorder.mark("udp", addr(1), us(epoch, 1_500), Some(1_000));
+        assert_eq!(extra_us(&first), 0);
+        let slower = recorder.mark("udp", addr(1), us(epoch, 3_000), Some(2_000));
+        assert_eq!(extra_us(&slower), 500);
+        let faster = recorder.mark("udp", addr(1), us(epoch, 4_000), Some(3_800));
+        assert_eq!(extra_us(&faster), 0);
+        let after = recorder.mark("udp", addr(1), us(epoch, 5_000), Some(4_700));
+        assert_eq!(extra_us(&after), 100);
+
+        let other = recorder.mark("udp", addr(2), us(epoch, 5_000), Some(1_000));
+        assert_eq!(extra_us(&other), 0);
+        assert!(recorder.mark("udp", addr(1), us(epoch, 5_000), None).client.is_none());
+    }
+
+    #[test]
+    fn client_clock_wraparound_keeps_the_baseline() {
+        let mut recorder = LatencyRecorder::new();
+        let epoch = recorder.epoch;
+        let first = recorder.mark("udp", addr(1), us(epoch, 1_000), Some(u32::MAX - 499));
+        assert_eq!(extra_us(&first), 0);
+        let wrapped = recorder.mark("udp", addr(1), us(epoch, 3_000), Some(1_200));
+        assert_eq!(extra_us(&wrapped), 300);
+    }
+
+    #[test]
+    fn forgotten_clients_get_a_new_baseline() {
+        let mut recorder = LatencyRecorder::new();
+        let epoch = recorder.epoch;
+        recorder.mark("udp", addr(1), us(epoch, 1_000), Some(500));
+        assert_eq!(extra_us(&recorder.mark("udp", addr(1), us(epoch, 2_000), Some(1_000))), 500);
+        recorder.forget(addr(1));
+        assert_eq!(extra_us(&recorder.mark("udp", addr(1), us(epoch, 3_000), Some(2_000))), 0);
+    }
+
+    #[test]
+    fn records_per_transport_and_source() {
+        let mut recorder = LatencyRecorder::new();
+        let epoch = recorder.epoch;
+        let mark = recorder.mark("tcp", addr(1), us(epoch, 1_000), Some(900));
+        recorder.record(&mark, us(epoch, 1_010));
+        let plain = recorder.mark("udp", addr(2), us(epoch, 1_000), None);
+        recorder.record(&plain, us(epoch, 1_004));
+
+        let stats = recorder.stats();
+        let summary: Vec<(&str, LatencySource, u64)> =
+            stats.iter().map(|s| (s.transport.as_str(), s.source, s.max_us)).collect();
+        assert_eq!(summary, vec![
+            ("tcp", LatencySource::Receive, 10),
+            ("tcp", LatencySource::Client, 10),
+            ("udp", LatencySource::Receive, 4),
+        ]);
+        recorder.reset();
+        assert!(recorder.stats().is_empty());
+    }
+}
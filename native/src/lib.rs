@@ -1,17 +1,32 @@
+#[cfg(feature = "flutter")]
 mod frb_generated;
 
+#[cfg(feature = "flutter")]
 pub mod api;
+pub mod card;
+pub mod card_library;
+pub mod coin;
+pub mod delta;
+pub mod discovery;
+pub mod events;
+pub mod ffi;
+pub mod filter;
+pub mod framing;
+pub mod gesture;
+pub mod latency;
+pub mod liveness;
+pub mod logging;
+pub mod pacing;
+pub mod pairing;
 pub mod server;
 pub mod shmem;
 pub mod protocol;
-
-use crate::server::SensorServer;
-use std::sync::{Mutex, LazyLock};
-
-pub static SERVER_INSTANCE: LazyLock<Mutex<SensorServer>> = LazyLock::new(|| {
-    Mutex::new(SensorServer::new())
-});
+pub mod registry;
+pub mod remap;
+pub mod state;
+pub mod sync;
 
 pub fn init_native_backend() -> Result<(), String> {
-    shmem::init_shmem()
-}
\ No newline at end of file
+    registry::register_default_server();
+    registry::default_server().init_shmem()
+}
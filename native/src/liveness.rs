@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+/// Sessions of clients without keepalives are forgotten after this long without traffic,
+/// so every ephemeral UDP source port does not stay listed forever.
+pub const IDLE_SESSION_MS: u64 = 60_000;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum LinkState {
+    /// No client has been seen yet.
+    Disconnected,
+    Connected,
+    /// Quiet for longer than `stale_ms`.
+    Stale,
+    /// A keepalive client quiet for longer than `lost_ms`, or the TCP connection closed.
+    Lost,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct LivenessConfig {
+    /// Interval between server keepalives to each client that sends keepalives.
+    pub keepalive_ms: u32,
+    pub stale_ms: u32,
+    /// Only applies to clients that send keepalives; others stay stale until they disconnect.
+    pub lost_ms: u32,
+    /// Release held inputs when the client that sent them is lost.
+    pub release_on_loss: bool,
+}
+
+impl Default for LivenessConfig {
+    fn default() -> Self {
+        Self {
+            keepalive_ms: 1000,
+            stale_ms: 3000,
+            lost_ms: 10000,
+            release_on_loss: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ClientSession {
+    pub client: String,
+    pub state: LinkState,
+    pub last_seen_ms: u64,
+}
+
+struct Session {
+    last_seen: Instant,
+    last_keepalive: Option<Instant>,
+    /// Set once the client sends a keepalive handshake.
+    keepalive: bool,
+    state: LinkState,
+}
+
+/// Per-client last-seen tracking.
+pub struct Liveness {
+    config: LivenessConfig,
+    sessions: HashMap<SocketAddr, Session>,
+    seen_any: bool,
+}
+
+impl Liveness {
+    pub fn new(config: LivenessConfig) -> Self {
+        Self { config, sessions: HashMap::new(), seen_any: false }
+    }
+
+    pub fn config(&self) -> LivenessConfig {
+        self.config
+    }
+
+    pub fn set_config(&mut self, config: LivenessConfig) {
+        self.config = config;
+    }
+
+    /// Records traffic from `addr`; returns the new state if it changed.
+    pub fn touch(&mut self, addr: SocketAddr, now: Instant) -> Option<LinkState> {
+        let session = self.sessions.entry(addr).or_insert(Session {
+            last_seen: now,
+            last_keepalive: None,
+            keepalive: false,
+            state: LinkState::Disconnected,
+        });
+        session.last_seen = now;
+        self.seen_any = true;
+        if session.state == LinkState::Connected {
+            return None;
+        }
+        session.state = LinkState::Connected;
+        Some(LinkState::Connected)
+    }
+
+    /// Opts `addr` into server keepalives and the `lost_ms` timeout.
+    pub fn enable_keepalive(&mut self, addr: SocketAddr) {
+        if let Some(session) = self.sessions.get_mut(&addr) {
+            session.keepalive = true;
+        }
+    }
+
+    /// Marks `addr` as lost immediately and forgets it.
+    pub fn drop_client(&mut self, addr: SocketAddr) -> Option<LinkState> {
+        match self.sessions.remove(&addr) {
+            Some(session) if session.state != LinkState::Lost => Some(LinkState::Lost),
+            _ => None,
+        }
+    }
+
+    /// Ages every session; returns the transitions. Lost sessions are forgotten.
+    pub fn tick(&mut self, now: Instant) -> Vec<(SocketAddr, LinkState)> {
+        let stale = Duration::from_millis(self.config.stale_ms as u64);
+        let lost = Duration::from_millis(self.config.lost_ms as u64);
+        let mut changes = Vec::new();
+        for (addr, session) in self.sessions.iter_mut() {
+            let quiet = now.duration_since(session.last_seen);
+            let next = if session.keepalive && quiet >= lost {
+                LinkState::Lost
+            } else if quiet >= stale {
+                LinkState::Stale
+            } else {
+                LinkState::Connected
+            };
+            if next != session.state {
+                session.state = next;
+                changes.push((*addr, next));
+            }
+        }
+        self.sessions.retain(|_, session| session.state != LinkState::Lost);
+        changes
+    }
+
+    /// Forgets clients without keepalives that have been quiet for `IDLE_SESSION_MS`;
+    /// returns them. No transition is reported, since nothing says they went away.
+    pub fn expire(&mut self, now: Instant) -> Vec<SocketAddr> {
+        let idle = Duration::from_millis(IDLE_SESSION_MS);
+        let expired: Vec<SocketAddr> = self
+            .sessions
+            .iter()
+            .filter(|(_, s)| !s.keepalive && now.duration_since(s.last_seen) >= idle)
+            .map(|(addr, _)| *addr)
+            .collect();
+        for addr in &expired {
+            self.sessions.remove(addr);
+        }
+        expired
+    }
+
+    /// Clients due a server keepalive; marks them as sent.
+    pub fn due_keepalives(&mut self, now: Instant) -> Vec<SocketAddr> {
+        let interval = Duration::from_millis(self.config.keepalive_ms as u64);
+        let mut due = Vec::new();
+        for (addr, session) in self.sessions.iter_mut().filter(|(_, s)| s.keepalive) {
+            if session.last_keepalive.is_none_or(|t| now.duration_since(t) >= interval) {
+                session.last_keepalive = Some(now);
+                due.push(*addr);
+            }
+        }
+        due
+    }
+
+    /// Best state across sessions; `Lost` once every client seen has gone.
+    pub fn overall(&self) -> LinkState {
+        let states: Vec<LinkState> = self.sessions.values().map(|s| s.state).collect();
+        if states.contains(&LinkState::Connected) {
+            LinkState::Connected
+        } else if states.contains(&LinkState::Stale) {
+            LinkState::Stale
+        } else if self.seen_any {
+            LinkState::Lost
+        } else {
+            LinkState::Disconnected
+        }
+    }
+
+    pub fn sessions(&self, now: Instant) -> Vec<ClientSession> {
+        self.sessions
+            .iter()
+            .map(|(addr, session)| ClientSession {
+                client: addr.to_string(),
+                state: session.state,
+                last_seen_ms: now.duration_since(session.last_seen).as_millis() as u64,
+            })
+            .collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.sessions.clear();
+        self.seen_any = false;
+    }
+}
+
+impl Default for Liveness {
+    fn default() -> Self {
+        Self::new(LivenessConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: LivenessConfig = LivenessConfig { keepalive_ms: 50, stale_ms: 100, lost_ms: 200, release_on_loss: true };
+
+    fn ms(base: Instant, offset: u64) -> Instant {
+        base + Duration::from_millis(offset)
+    }
+
+    fn addr(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    #[test]
+    fn quiet_clients_go_stale_and_keepalive_clients_are_lost() {
+        let mut liveness = Liveness::new(CONFIG);
+        let t = Instant::now();
+        assert_eq!(liveness.overall(), LinkState::Disconnected);
+        assert_eq!(liveness.touch(addr(1), t), Some(LinkState::Connected));
+        assert_eq!(liveness.touch(addr(2), t), Some(LinkState::Connected));
+        liveness.enable_keepalive(addr(2));
+
+        let mut changes = liveness.tick(ms(t, 100));
+        changes.sort_by_key(|(a, _)| a.port());
+        assert_eq!(changes, vec![(addr(1), LinkState::Stale), (addr(2), LinkState::Stale)]);
+        assert_eq!(liveness.tick(ms(t, 200)), vec![(addr(2), LinkState::Lost)]);
+        assert_eq!(liveness.tick(ms(t, 5000)), vec![]);
+        assert_eq!(liveness.overall(), LinkState::Stale);
+
+        assert_eq!(liveness.touch(addr(1), ms(t, 5000)), Some(LinkState::Connected));
+        assert_eq!(liveness.drop_client(addr(1)), Some(LinkState::Lost));
+        assert_eq!(liveness.overall(), LinkState::Lost);
+    }
+
+    #[test]
+    fn keepalives_go_only_to_clients_that_send_them() {
+        let mut liveness = Liveness::new(CONFIG);
+        let t = Instant::now();
+        liveness.touch(addr(1), t);
+        liveness.touch(addr(2), t);
+        assert!(liveness.due_keepalives(t).is_empty());
+
+        liveness.enable_keepalive(addr(2));
+        assert_eq!(liveness.due_keepalives(t), vec![addr(2)]);
+        assert!(liveness.due_keepalives(ms(t, 49)).is_empty());
+        assert_eq!(liveness.due_keepalives(ms(t, 50)), vec![addr(2)]);
+    }
+
+    #[test]
+    fn idle_clients_without_keepalives_are_forgotten() {
+        let mut liveness = Liveness::new(CONFIG);
+        let t = Instant::now();
+        liveness.touch(addr(1), t);
+        liveness.touch(addr(2), t);
+        liveness.touch(addr(3), ms(t, 1000));
+        liveness.enable_keepalive(addr(2));
+
+        assert!(liveness.expire(ms(t, IDLE_SESSION_MS - 1)).is_empty());
+        assert_eq!(liveness.expire(ms(t, IDLE_SESSION_MS)), vec![addr(1)]);
+        let mut remaining: Vec<String> = liveness.sessions(ms(t, IDLE_SESSION_MS)).into_iter().map(|s| s.client).collect();
+        remaining.sort();
+        assert_eq!(remaining, vec![addr(2).to_string(), addr(3).to_string()]);
+
+        assert_eq!(liveness.expire(ms(t, IDLE_SESSION_MS + 1000)), vec![addr(3)]);
+        assert_eq!(liveness.sessions(ms(t, IDLE_SESSION_MS + 1000)).len(), 1);
+    }
+}
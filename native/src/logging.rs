@@ -0,0 +1,292 @@
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use chrono::Local;
+use tokio::sync::broadcast;
+
+pub const LOG_CAPACITY: usize = 1000;
+pub const LOG_FILE_MAX_BYTES: u64 = 1024 * 1024;
+pub const LOG_FILE_KEEP: usize = 3;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum LogCategory {
+    Connection,
+    Handshake,
+    Transport,
+    Protocol,
+    Shmem,
+}
+
+#[derive(Clone, Debug)]
+pub struct LogEntry {
+    /// Local time, RFC 3339 with milliseconds.
+    pub timestamp: String,
+    pub level: LogLevel,
+    pub category: LogCategory,
+    pub message: String,
+}
+
+impl LogEntry {
+    fn now(level: LogLevel, category: LogCategory, message: String) -> Self {
+        Self {
+            timestamp: Local::now().format("%Y-%m-%dT%H:%M:%S%.3f%:z").to_string(),
+            level,
+            category,
+            message,
+        }
+    }
+
+    fn line(&self) -> String {
+        format!("{} {:<5} [{:?}] {}\n", self.timestamp, format!("{:?}", self.level).to_uppercase(), self.category, self.message)
+    }
+}
+
+/// Size-capped log file; `path.1` .. `path.N` hold older content.
+struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    written: u64,
+    max_bytes: u64,
+    keep: usize,
+}
+
+impl RotatingFile {
+    fn open(path: PathBuf, max_bytes: u64, keep: usize) -> Result<Self, String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Failed to create log directory: {}", e))?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| format!("Failed to open log file: {}", e))?;
+        let written = file.metadata().map(|m| m.len()).unwrap_or(0);
+        Ok(Self { path, file, written, max_bytes, keep })
+    }
+
+    fn rotated(path: &Path, index: usize) -> PathBuf {
+        let mut name = path.as_os_str().to_owned();
+        name.push(format!(".{}", index));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        for index in (1..self.keep).rev() {
+            let from = Self::rotated(&self.path, index);
+            if from.exists() {
+                fs::rename(&from, Self::rotated(&self.path, index + 1))?;
+            }
+        }
+        if self.keep > 0 {
+            fs::rename(&self.path, Self::rotated(&self.path, 1))?;
+        }
+        self.file = OpenOptions::new().create(true).write(true).truncate(true).open(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+
+    fn write(&mut self, line: &str) -> std::io::Result<()> {
+        if self.written > 0 && self.written + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        self.file.write_all(line.as_bytes())?;
+        self.written += line.len() as u64;
+        Ok(())
+    }
+}
+
+pub struct Logger {
+    min_level: Mutex<LogLevel>,
+    buffer: Mutex<VecDeque<LogEntry>>,
+    sender: broadcast::Sender<LogEntry>,
+    file: Mutex<Option<RotatingFile>>,
+}
+
+impl Logger {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(256);
+        Self {
+            min_level: Mutex::new(LogLevel::Info),
+            buffer: Mutex::new(VecDeque::with_capacity(LOG_CAPACITY)),
+            sender,
+            file: Mutex::new(None),
+        }
+    }
+
+    pub fn log(&self, level: LogLevel, category: LogCategory, message: String) {
+        if self.min_level.lock().is_ok_and(|min| level < *min) {
+            return;
+        }
+        let entry = LogEntry::now(level, category, message);
+
+        let mut file_error = None;
+        if let Ok(mut guard) = self.file.lock() {
+            if let Some(file) = guard.as_mut() {
+                if let Err(e) = file.write(&entry.line()) {
+                    file_error = Some(e);
+                    *guard = None;
+                }
+            }
+        }
+        self.record(entry);
+        // The file is already closed, so the failure only reaches the buffer and subscribers.
+        if let Some(e) = file_error {
+            self.record(LogEntry::now(
+                LogLevel::Error,
+                category,
+                format!("Log file write failed, disabling file output: {}", e),
+            ));
+        }
+    }
+
+    fn record(&self, entry: LogEntry) {
+        if let Ok(mut buffer) = self.buffer.lock() {
+            if buffer.len() == LOG_CAPACITY {
+                buffer.pop_front();
+            }
+            buffer.push_back(entry.clone());
+        }
+        let _ = self.sender.send(entry);
+    }
+
+    pub fn set_level(&self, level: LogLevel) {
+        if let Ok(mut min) = self.min_level.lock() {
+            *min = level;
+        }
+    }
+
+    pub fn level(&self) -> LogLevel {
+        self.min_level.lock().map(|min| *min).unwrap_or(LogLevel::Info)
+    }
+
+    /// Mirrors entries to `path`, rotating at `LOG_FILE_MAX_BYTES`; `None` stops file output.
+    pub fn set_file(&self, path: Option<PathBuf>) -> Result<(), String> {
+        let file = match path {
+            Some(p) => Some(RotatingFile::open(p, LOG_FILE_MAX_BYTES, LOG_FILE_KEEP)?),
+            None => None,
+        };
+        let mut guard = self.file.lock().map_err(|_| "Failed to lock log file")?;
+        *guard = file;
+        Ok(())
+    }
+
+    /// Oldest first.
+    pub fn recent(&self, limit: usize) -> Vec<LogEntry> {
+        match self.buffer.lock() {
+            Ok(buffer) => buffer.iter().skip(buffer.len().saturating_sub(limit)).cloned().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut buffer) = self.buffer.lock() {
+            buffer.clear();
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<LogEntry> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub static LOGGER: LazyLock<Logger> = LazyLock::new(Logger::new);
+
+pub fn debug(category: LogCategory, message: impl Into<String>) {
+    LOGGER.log(LogLevel::Debug, category, message.into());
+}
+
+pub fn info(category: LogCategory, message: impl Into<String>) {
+    LOGGER.log(LogLevel::Info, category, message.into());
+}
+
+pub fn warn(category: LogCategory, message: impl Into<String>) {
+    LOGGER.log(LogLevel::Warn, category, message.into());
+}
+
+pub fn error(category: LogCategory, message: impl Into<String>) {
+    LOGGER.log(LogLevel::Error, category, message.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(logger: &Logger) -> Vec<String> {
+        logger.recent(usize::MAX).into_iter().map(|e| e.message).collect()
+    }
+
+    #[test]
+    fn level_filter_drops_lower_levels() {
+        let logger = Logger::new();
+        logger.log(LogLevel::Debug, LogCategory::Protocol, "hidden".to_string());
+        logger.log(LogLevel::Info, LogCategory::Protocol, "shown".to_string());
+        logger.set_level(LogLevel::Debug);
+        logger.log(LogLevel::Debug, LogCategory::Protocol, "debug".to_string());
+        assert_eq!(messages(&logger), ["shown", "debug"]);
+        assert_eq!(logger.level(), LogLevel::Debug);
+    }
+
+    #[test]
+    fn ring_buffer_keeps_the_newest_entries() {
+        let logger = Logger::new();
+        let mut receiver = logger.subscribe();
+        for n in 0..LOG_CAPACITY + 5 {
+            logger.log(LogLevel::Info, LogCategory::Connection, n.to_string());
+        }
+        let all = messages(&logger);
+        assert_eq!(all.len(), LOG_CAPACITY);
+        assert_eq!(all[0], "5");
+        assert_eq!(all[LOG_CAPACITY - 1], (LOG_CAPACITY + 4).to_string());
+        let tail: Vec<String> = logger.recent(2).into_iter().map(|e| e.message).collect();
+        assert_eq!(tail, all[LOG_CAPACITY - 2..]);
+        assert!(receiver.try_recv().is_err_and(|e| matches!(e, broadcast::error::TryRecvError::Lagged(_))));
+        logger.clear();
+        assert!(logger.recent(10).is_empty());
+    }
+
+    #[test]
+    fn file_rotates_and_keeps_older_copies() {
+        let dir = std::env::temp_dir().join(format!("rustnithm-log-{}", std::process::id()));
+        let path = dir.join("server.log");
+        let mut file = RotatingFile::open(path.clone(), 10, 2).unwrap();
+        for line in ["aaaaaaaa\n", "bbbbbbbb\n", "cccccccc\n", "dddddddd\n"] {
+            file.write(line).unwrap();
+        }
+        assert_eq!(fs::read_to_string(&path).unwrap(), "dddddddd\n");
+        assert_eq!(fs::read_to_string(RotatingFile::rotated(&path, 1)).unwrap(), "cccccccc\n");
+        assert_eq!(fs::read_to_string(RotatingFile::rotated(&path, 2)).unwrap(), "bbbbbbbb\n");
+        assert!(!RotatingFile::rotated(&path, 3).exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn failed_file_write_is_logged_and_disables_the_file() {
+        let logger = Logger::new();
+        logger.set_file(Some(PathBuf::from("/dev/full"))).unwrap();
+        logger.log(LogLevel::Warn, LogCategory::Shmem, "first".to_string());
+        logger.log(LogLevel::Warn, LogCategory::Shmem, "second".to_string());
+        let entries = logger.recent(10);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].message, "first");
+        assert_eq!(entries[1].level, LogLevel::Error);
+        assert!(entries[1].message.starts_with("Log file write failed"));
+        assert_eq!(entries[2].message, "second");
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use crate::latency::LatencyMark;
+use crate::protocol::{ControlPayload, ControlSample};
+
+pub struct PacedSample {
+    pub due: Instant,
+    pub src: Option<IpAddr>,
+    pub control: ControlPayload,
+    pub mark: Option<LatencyMark>,
+}
+
+struct PacerState {
+    queue: VecDeque<PacedSample>,
+    worker_running: bool,
+}
+
+/// Batched control samples, replayed by a single worker at their original spacing.
+pub struct ControlPacer {
+    inner: Mutex<PacerState>,
+}
+
+impl ControlPacer {
+    pub fn new() -> Self {
+        Self {
+            inner: Mutex::new(PacerState { queue: VecDeque::new(), worker_running: false }),
+        }
+    }
+
+    /// Schedules `samples` from `now`, or from the end of the previous batch if that is
+    /// still playing, and returns true if the caller must start a worker.
+    pub fn push(&self, src: Option<IpAddr>, samples: &[ControlSample], now: Instant, mark: Option<LatencyMark>) -> bool {
+        let first = match samples.first() {
+            Some(sample) => sample.offset_us,
+            None => return false,
+        };
+        let mut inner = match self.inner.lock() {
+            Ok(g) => g,
+            Err(_) => return false,
+        };
+        let start = inner.queue.back().map_or(now, |last| last.due.max(now));
+        for sample in samples {
+            let offset = Duration::from_micros(sample.offset_us.saturating_sub(first) as u64);
+            inner.queue.push_back(PacedSample {
+                due: start + offset,
+                src,
+                control: sample.control,
+                mark: mark.map(|m| m.shifted(offset)),
+            });
+        }
+        if !inner.worker_running {
+            inner.worker_running = true;
+            return true;
+        }
+        false
+    }
+
+    /// Takes the next sample, or marks the worker stopped when empty.
+    pub fn next(&self) -> Option<PacedSample> {
+        let mut inner = self.inner.lock().ok()?;
+        match inner.queue.pop_front() {
+            Some(sample) => Some(sample),
+            None => {
+                inner.worker_running = false;
+                None
+            }
+        }
+    }
+
+    pub fn pending(&self) -> usize {
+        self.inner.lock().map(|inner| inner.queue.len()).unwrap_or(0)
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.queue.clear();
+        }
+    }
+}
+
+impl Default for ControlPacer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::ProtocolParser;
+
+    fn sample(offset_us: u16, air: u8) -> ControlSample {
+        ControlSample { offset_us, control: ProtocolParser::parse_control(&[air, 0, 0, 0, 0]).unwrap() }
+    }
+
+    #[test]
+    fn samples_keep_their_spacing() {
+        let pacer = ControlPacer::new();
+        let now = Instant::now();
+        assert!(pacer.push(None, &[sample(100, 1), sample(300, 2), sample(300, 3)], now, None));
+        assert_eq!(pacer.pending(), 3);
+
+        let dues: Vec<Duration> = std::iter::from_fn(|| pacer.next()).map(|s| s.due - now).collect();
+        assert_eq!(dues, vec![Duration::ZERO, Duration::from_micros(200), Duration::from_micros(200)]);
+        assert_eq!(pacer.pending(), 0);
+    }
+
+    #[test]
+    fn second_batch_queues_behind_the_one_playing() {
+        let pacer = ControlPacer::new();
+        let now = Instant::now();
+        let mark = LatencyMark { transport: "udp", received: now, client: None };
+        assert!(pacer.push(None, &[sample(0, 1), sample(1000, 2)], now, Some(mark)));
+        let later = now + Duration::from_micros(400);
+        let later_mark = LatencyMark { received: later, ..mark };
+        assert!(!pacer.push(None, &[sample(50, 3), sample(550, 1)], later, Some(later_mark)));
+
+        let queued: Vec<PacedSample> = std::iter::from_fn(|| pacer.next()).collect();
+        let dues: Vec<u128> = queued.iter().map(|s| (s.due - now).as_micros()).collect();
+        assert_eq!(dues, vec![0, 1000, 1000, 1500]);
+        let received: Vec<u128> = queued.iter().map(|s| (s.mark.unwrap().received - now).as_micros()).collect();
+        assert_eq!(received, vec![0, 1000, 400, 900]);
+    }
+
+    #[test]
+    fn worker_is_restarted_once_the_queue_drains() {
+        let pacer = ControlPacer::new();
+        let now = Instant::now();
+        assert!(!pacer.push(None, &[], now, None));
+        assert!(pacer.push(None, &[sample(0, 1)], now, None));
+        assert!(pacer.next().is_some());
+        assert!(!pacer.push(None, &[sample(0, 1)], now, None));
+        assert!(pacer.next().is_some());
+        assert!(pacer.next().is_none());
+        assert!(pacer.push(None, &[sample(0, 1)], now + Duration::from_millis(5), None));
+
+        pacer.clear();
+        assert_eq!(pacer.pending(), 0);
+    }
+}
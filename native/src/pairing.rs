@@ -0,0 +1,226 @@
+use qrcodegen::{QrCode, QrCodeEcc};
+use crate::protocol::PROTOCOL_VERSION;
+use crate::server::SensorServer;
+
+pub const URI_SCHEME: &str = "rustnithm";
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct ConnectionInfo {
+    /// Candidate addresses, tried in order by the client.
+    pub hosts: Vec<String>,
+    pub port: u16,
+    pub transport: String,
+    pub version: u8,
+    pub secret: Option<String>,
+}
+
+impl ConnectionInfo {
+    /// Uses the port the server is bound to, falling back to the configured one while stopped.
+    /// Unix socket servers have no port and cannot be paired over the network.
+    pub fn for_server(server: &SensorServer, hosts: Vec<String>, secret: Option<String>) -> Result<Self, String> {
+        let config = server.config();
+        if config.is_unix() {
+            return Err("Unix socket servers have no network address to pair with".to_string());
+        }
+        Ok(Self {
+            hosts,
+            port: server.bound_port().unwrap_or(config.port),
+            transport: config.transport().to_string(),
+            version: PROTOCOL_VERSION,
+            secret,
+        })
+    }
+
+    /// `rustnithm://connect?host=a,b&port=N&transport=udp&v=1[&secret=S]`
+    pub fn to_uri(&self) -> String {
+        let hosts: Vec<String> = self.hosts.iter().map(|h| encode(h)).collect();
+        let mut uri = format!(
+            "{}://connect?host={}&port={}&transport={}&v={}",
+            URI_SCHEME,
+            hosts.join(","),
+            self.port,
+            encode(&self.transport),
+            self.version
+        );
+        if let Some(secret) = self.secret.as_ref() {
+            uri.push_str("&secret=");
+            uri.push_str(&encode(secret));
+        }
+        uri
+    }
+
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let prefix = format!("{}://connect?", URI_SCHEME);
+        let query = uri
+            .strip_prefix(&prefix)
+            .ok_or_else(|| format!("Not a {} connection URI", URI_SCHEME))?;
+
+        let mut info = ConnectionInfo {
+            hosts: Vec::new(),
+            port: 0,
+            transport: "udp".to_string(),
+            version: 0,
+            secret: None,
+        };
+        for pair in query.split('&').filter(|p| !p.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            match key {
+                "host" => {
+                    info.hosts = value
+                        .split(',')
+                        .filter(|h| !h.is_empty())
+                        .map(decode)
+                        .collect::<Result<_, _>>()?;
+                }
+                "port" => info.port = value.parse().map_err(|_| format!("Invalid port '{}'", value))?,
+                "transport" => info.transport = decode(value)?,
+                "v" => info.version = value.parse().map_err(|_| format!("Invalid version '{}'", value))?,
+                "secret" => info.secret = Some(decode(value)?),
+                _ => {}
+            }
+        }
+        if info.hosts.is_empty() {
+            return Err("Connection URI has no host".to_string());
+        }
+        if info.port == 0 {
+            return Err("Connection URI has no port".to_string());
+        }
+        Ok(info)
+    }
+}
+
+fn encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' => out.push(byte as char),
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+fn decode(value: &str) -> Result<String, String> {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = value.get(i + 1..i + 3).ok_or("Truncated escape in URI")?;
+            out.push(u8::from_str_radix(hex, 16).map_err(|_| format!("Invalid escape '%{}'", hex))?);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(out).map_err(|_| "URI value is not valid UTF-8".to_string())
+}
+
+/// Random 16-hex-digit pairing secret, drawn from the OS random source.
+pub fn generate_secret() -> Result<String, String> {
+    let mut bytes = [0u8; 8];
+    getrandom::getrandom(&mut bytes).map_err(|e| format!("Failed to generate secret: {}", e))?;
+    Ok(format!("{:016x}", u64::from_le_bytes(bytes)))
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct QrMatrix {
+    /// Modules per side, including the quiet-zone border.
+    pub size: u32,
+    /// Row-major, `true` for a dark module.
+    pub modules: Vec<bool>,
+}
+
+pub fn qr_matrix(text: &str, border: u32) -> Result<QrMatrix, String> {
+    let code = QrCode::encode_text(text, QrCodeEcc::Medium)
+        .map_err(|e| format!("QR encoding failed: {:?}", e))?;
+    let inner = code.size();
+    let size = inner as u32 + 2 * border;
+    let offset = border as i32;
+    let mut modules = Vec::with_capacity((size * size) as usize);
+    for y in 0..size as i32 {
+        for x in 0..size as i32 {
+            modules.push(code.get_module(x - offset, y - offset));
+        }
+    }
+    Ok(QrMatrix { size, modules })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uri_round_trips() {
+        let info = ConnectionInfo {
+            hosts: vec!["192.168.1.20".to_string(), "fe80::1%eth0".to_string(), "host name".to_string()],
+            port: 24864,
+            transport: "tcp".to_string(),
+            version: PROTOCOL_VERSION,
+            secret: Some("a&b=c".to_string()),
+        };
+        let uri = info.to_uri();
+        assert!(uri.starts_with("rustnithm://connect?host=192.168.1.20,fe80::1%25eth0,host%20name&port=24864"));
+        assert_eq!(ConnectionInfo::parse(&uri), Ok(info));
+    }
+
+    #[test]
+    fn uri_without_secret_or_transport() {
+        let info = ConnectionInfo::parse("rustnithm://connect?host=10.0.0.2&port=1&v=1").unwrap();
+        assert_eq!(info.hosts, vec!["10.0.0.2"]);
+        assert_eq!(info.transport, "udp");
+        assert_eq!(info.secret, None);
+    }
+
+    #[test]
+    fn rejects_incomplete_uris() {
+        assert!(ConnectionInfo::parse("http://connect?host=a&port=1").is_err());
+        assert!(ConnectionInfo::parse("rustnithm://connect?port=1").is_err());
+        assert!(ConnectionInfo::parse("rustnithm://connect?host=a").is_err());
+        assert!(ConnectionInfo::parse("rustnithm://connect?host=a&port=70000").is_err());
+        assert!(ConnectionInfo::parse("rustnithm://connect?host=%4&port=1").is_err());
+    }
+
+    #[test]
+    fn server_uri_uses_the_bound_port() {
+        use crate::server::ServerConfig;
+        let server = SensorServer::with_config(ServerConfig {
+            port: 0,
+            protocol: "tcp".to_string(),
+            shmem_name: format!("rustnithm_test_pairing_{}", std::process::id()),
+            ..ServerConfig::default()
+        });
+        server.start_configured().unwrap();
+        let info = ConnectionInfo::for_server(&server, vec!["10.0.0.2".to_string()], None).unwrap();
+        let bound = server.bound_port();
+        server.stop();
+
+        assert_eq!(Some(info.port), bound);
+        assert_ne!(info.port, 0);
+        assert_eq!(info.transport, "tcp");
+        assert_eq!(ConnectionInfo::parse(&info.to_uri()), Ok(info));
+    }
+
+    #[test]
+    fn unix_servers_have_no_uri() {
+        use crate::server::ServerConfig;
+        let server = SensorServer::with_config(ServerConfig { protocol: "unix".to_string(), ..ServerConfig::default() });
+        assert!(ConnectionInfo::for_server(&server, vec!["10.0.0.2".to_string()], None).is_err());
+    }
+
+    #[test]
+    fn qr_matrix_includes_the_border() {
+        let qr = qr_matrix("rustnithm://connect?host=a&port=1", 4).unwrap();
+        assert_eq!(qr.modules.len(), (qr.size * qr.size) as usize);
+        assert!(qr.modules[..qr.size as usize * 4].iter().all(|dark| !dark));
+    }
+
+    #[test]
+    fn secrets_are_sixteen_hex_digits() {
+        let a = generate_secret().unwrap();
+        assert_eq!(a.len(), 16);
+        assert!(a.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(a, generate_secret().unwrap());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::card::{CardId, CardKind};
+
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub enum PacketType {
     Handshake = 0b00,
@@ -10,6 +12,7 @@ pub struct PacketHeader {
     pub is_tcp: bool,
     pub is_server: bool,
     pub packet_type: PacketType,
+    pub flags: u8,
 }
 
 pub struct HandshakePayload {
@@ -19,11 +22,74 @@ pub struct HandshakePayload {
     pub server_target: bool,
 }
 
+/// Advertised through discovery so clients can reject servers they cannot talk to.
+pub const PROTOCOL_VERSION: u8 = 1;
+
+pub const BUTTON_COIN: u8 = 0x01;
+pub const BUTTON_SERVICE: u8 = 0x02;
+pub const BUTTON_TEST: u8 = 0x04;
+pub const BUTTON_UP: u8 = 0x08;
+pub const BUTTON_DOWN: u8 = 0x10;
+pub const BUTTON_ENTER: u8 = 0x20;
+pub const BUTTON_COIN_2: u8 = 0x40;
+
+/// Button header flag: the payload is the full current mask, zero releases.
+/// Without it the mask is a legacy pulse released by the server after a TTL.
+pub const BUTTON_FLAG_STATEFUL: u8 = 0x01;
+
+/// Handshake header flag: a keepalive with an optional sequence byte, not a state change.
+pub const HANDSHAKE_FLAG_KEEPALIVE: u8 = 0x01;
+
+/// Control header flags; with neither set the payload is a legacy full frame.
+/// Keyframe payload: `seq, air, slider[4]`.
+pub const CONTROL_FLAG_KEYFRAME: u8 = 0x01;
+/// Delta payload: `seq, base_seq` then `(cell, value)` pairs for every cell that differs
+/// from the acknowledged frame `base_seq`. Cells 0..6 are air, 6..38 are slider.
+pub const CONTROL_FLAG_DELTA: u8 = 0x02;
+/// Server to client: `seq` was applied and may be used as a delta baseline.
+pub const CONTROL_FLAG_ACK: u8 = 0x04;
+/// Server to client: the delta baseline is unknown, send a keyframe.
+pub const CONTROL_FLAG_RESYNC: u8 = 0x08;
+
+/// Client to server: `count` then `count` samples of `offset_us: u16 LE, air, slider[4]`,
+/// offsets relative to the first sample and non-decreasing. Shares its bit with
+/// `CONTROL_FLAG_ACK`, which only the server sends.
+pub const CONTROL_FLAG_BATCH: u8 = 0x04;
+/// Largest batch accepted; keeps a timestamped batch packet under the default 512-byte frame limit.
+pub const MAX_BATCH_SAMPLES: usize = 64;
+
+/// Client to server, combinable with the others: the payload starts with the client's
+/// wrapping microsecond clock as u32 LE, used only for latency measurement. Shares its
+/// bit with `CONTROL_FLAG_RESYNC`, which only the server sends.
+pub const CONTROL_FLAG_TIMESTAMP: u8 = 0x08;
+
+pub const CONTROL_CELLS: usize = 38;
+const BATCH_SAMPLE_LEN: usize = 7;
+
+pub struct ButtonPayload {
+    pub mask: u8,
+    pub stateful: bool,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub struct ControlPayload {
     pub air: [u8; 6],
     pub slider: [u8; 32],
 }
 
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct ControlSample {
+    pub offset_us: u16,
+    pub control: ControlPayload,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum ControlFrame {
+    Full(ControlPayload),
+    Keyframe { seq: u8, control: ControlPayload },
+    Delta { seq: u8, base: u8, changes: Vec<(u8, u8)> },
+}
+
 pub struct ProtocolParser;
 
 impl ProtocolParser {
@@ -31,6 +97,7 @@ impl ProtocolParser {
         let is_tcp = (header >> 7) & 1 == 1;
         let is_server = (header >> 6) & 1 == 1;
         let type_bits = (header >> 4) & 0b11;
+        let flags = header & 0x0F;
 
         let packet_type = match type_bits {
             0b00 => Some(PacketType::Handshake),
@@ -44,6 +111,7 @@ impl ProtocolParser {
             is_tcp,
             is_server,
             packet_type,
+            flags,
         })
     }
 
@@ -71,12 +139,23 @@ impl ProtocolParser {
         [header, payload]
     }
 
+    pub fn build_keepalive(seq: u8) -> [u8; 2] {
+        [0b0100_0000 | HANDSHAKE_FLAG_KEEPALIVE, seq]
+    }
+
+    pub fn parse_button(flags: u8, payload: &[u8]) -> ButtonPayload {
+        ButtonPayload {
+            mask: payload.first().copied().unwrap_or(0),
+            stateful: flags & BUTTON_FLAG_STATEFUL != 0,
+        }
+    }
+
     pub fn parse_control(payload: &[u8]) -> Option<ControlPayload> {
         if payload.len() < 5 { return None; }
         let mut air = [0u8; 6];
         let air_byte = payload[0];
-        for i in 0..6 {
-            air[i] = if (air_byte & (1 << i)) != 0 { 1 } else { 0 };
+        for (i, cell) in air.iter_mut().enumerate() {
+            *cell = if (air_byte & (1 << i)) != 0 { 1 } else { 0 };
         }
         let mut slider = [0u8; 32];
         for byte_idx in 0..4 {
@@ -88,10 +167,182 @@ impl ProtocolParser {
         }
         Some(ControlPayload { air, slider })
     }
+    /// Strips the client timestamp if the flags say there is one.
+    pub fn split_timestamp(flags: u8, payload: &[u8]) -> Result<(Option<u32>, &[u8]), String> {
+        if flags & CONTROL_FLAG_TIMESTAMP == 0 {
+            return Ok((None, payload));
+        }
+        if payload.len() < 4 {
+            return Err(format!("Timestamped control packet has {} bytes", payload.len()));
+        }
+        let client_us = u32::from_le_bytes([payload[0], payload[1], payload[2], payload[3]]);
+        Ok((Some(client_us), &payload[4..]))
+    }
+
+    pub fn parse_control_frame(flags: u8, payload: &[u8]) -> Result<ControlFrame, String> {
+        if flags & CONTROL_FLAG_DELTA != 0 {
+            if payload.len() < 2 || !payload.len().is_multiple_of(2) {
+                return Err(format!("Delta frame has {} bytes", payload.len()));
+            }
+            let changes: Vec<(u8, u8)> = payload[2..].chunks(2).map(|c| (c[0], c[1])).collect();
+            if let Some((cell, _)) = changes.iter().find(|(cell, _)| *cell as usize >= CONTROL_CELLS) {
+                return Err(format!("Delta cell {} out of range", cell));
+            }
+            return Ok(ControlFrame::Delta { seq: payload[0], base: payload[1], changes });
+        }
+        if flags & CONTROL_FLAG_KEYFRAME != 0 {
+            let control = payload
+                .get(1..)
+                .and_then(Self::parse_control)
+                .ok_or_else(|| format!("Keyframe has {} bytes", payload.len()))?;
+            return Ok(ControlFrame::Keyframe { seq: payload[0], control });
+        }
+        Self::parse_control(payload)
+            .map(ControlFrame::Full)
+            .ok_or_else(|| format!("Control frame has {} bytes", payload.len()))
+    }
+
+    pub fn parse_control_batch(payload: &[u8]) -> Result<Vec<ControlSample>, String> {
+        let count = *payload.first().ok_or("Empty batch")? as usize;
+        if count == 0 {
+            return Err("Batch has no samples".to_string());
+        }
+        if count > MAX_BATCH_SAMPLES {
+            return Err(format!("Batch of {} samples exceeds {}", count, MAX_BATCH_SAMPLES));
+        }
+        let body = &payload[1..];
+        if body.len() < count * BATCH_SAMPLE_LEN {
+            return Err(format!("Batch of {} samples has {} bytes", count, body.len()));
+        }
+        let mut samples: Vec<ControlSample> = Vec::with_capacity(count);
+        for chunk in body.chunks_exact(BATCH_SAMPLE_LEN).take(count) {
+            let offset_us = u16::from_le_bytes([chunk[0], chunk[1]]);
+            if samples.last().is_some_and(|prev| prev.offset_us > offset_us) {
+                return Err("Batch offsets go backwards".to_string());
+            }
+            let control = Self::parse_control(&chunk[2..]).ok_or("Short batch sample")?;
+            samples.push(ControlSample { offset_us, control });
+        }
+        Ok(samples)
+    }
+
+    /// `base` with each `(cell, value)` written over it.
+    pub fn apply_delta(base: &ControlPayload, changes: &[(u8, u8)]) -> ControlPayload {
+        let mut out = *base;
+        for &(cell, value) in changes {
+            let cell = cell as usize;
+            if cell < 6 {
+                out.air[cell] = value;
+            } else if cell < CONTROL_CELLS {
+                out.slider[cell - 6] = value;
+            }
+        }
+        out
+    }
+
+    pub fn build_control_ack(seq: u8) -> [u8; 2] {
+        [0b0110_0000 | CONTROL_FLAG_ACK, seq]
+    }
+
+    /// Carries the unknown base so the client can tell stale requests apart.
+    pub fn build_control_resync(base: u8) -> [u8; 2] {
+        [0b0110_0000 | CONTROL_FLAG_RESYNC, base]
+    }
+
     pub fn parse_card(payload: &[u8]) -> Option<[u8; 10]> {
         if payload.len() < 10 { return None; }
         let mut code = [0u8; 10];
         code.copy_from_slice(&payload[..10]);
         Some(code)
     }
-}
\ No newline at end of file
+
+    pub fn parse_card_id(flags: u8, payload: &[u8]) -> Result<CardId, String> {
+        let kind = if flags == 0 {
+            CardKind::AccessCode
+        } else {
+            CardKind::from_marker(flags).ok_or_else(|| format!("Unknown card type {}", flags))?
+        };
+        match kind {
+            CardKind::AccessCode => {
+                let code = Self::parse_card(payload).ok_or("Access code payload too short")?;
+                CardId::access_code_from_bcd(&code)
+            }
+            CardKind::Felica => {
+                let len = std::cmp::min(payload.len(), 16);
+                CardId::from_bytes(kind, &payload[..len])
+            }
+            CardKind::Mifare => CardId::from_bytes(kind, payload),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delta_frames_parse_cell_pairs() {
+        let frame = ProtocolParser::parse_control_frame(CONTROL_FLAG_DELTA, &[9, 8, 0, 1, 37, 1]).unwrap();
+        assert_eq!(frame, ControlFrame::Delta { seq: 9, base: 8, changes: vec![(0, 1), (37, 1)] });
+        assert_eq!(
+            ProtocolParser::parse_control_frame(CONTROL_FLAG_DELTA, &[9, 8]).unwrap(),
+            ControlFrame::Delta { seq: 9, base: 8, changes: vec![] },
+        );
+    }
+
+    #[test]
+    fn malformed_delta_frames_are_rejected() {
+        assert!(ProtocolParser::parse_control_frame(CONTROL_FLAG_DELTA, &[]).is_err());
+        assert!(ProtocolParser::parse_control_frame(CONTROL_FLAG_DELTA, &[9]).is_err());
+        assert!(ProtocolParser::parse_control_frame(CONTROL_FLAG_DELTA, &[9, 8, 0]).is_err());
+        assert!(ProtocolParser::parse_control_frame(CONTROL_FLAG_DELTA, &[9, 8, 38, 1]).is_err());
+        assert!(ProtocolParser::parse_control_frame(CONTROL_FLAG_DELTA, &[9, 8, 0, 1, 255, 1]).is_err());
+        assert!(ProtocolParser::parse_control_frame(CONTROL_FLAG_KEYFRAME, &[9, 0, 0, 0, 0]).is_err());
+        assert!(ProtocolParser::parse_control_frame(0, &[0, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn apply_delta_writes_air_and_slider_cells() {
+        let base = ProtocolParser::parse_control(&[0b10_0000, 0, 0, 0, 0]).unwrap();
+        let out = ProtocolParser::apply_delta(&base, &[(0, 1), (5, 0), (6, 1), (37, 1), (38, 1)]);
+        assert_eq!(out.air, [1, 0, 0, 0, 0, 0]);
+        assert_eq!(out.slider[0], 1);
+        assert_eq!(out.slider[31], 1);
+        assert_eq!(out.slider[1..31], [0; 30]);
+    }
+
+    fn batch(samples: &[(u16, u8)]) -> Vec<u8> {
+        let mut payload = vec![samples.len() as u8];
+        for &(offset_us, air) in samples {
+            payload.extend_from_slice(&offset_us.to_le_bytes());
+            payload.extend_from_slice(&[air, 0, 0, 0, 0]);
+        }
+        payload
+    }
+
+    #[test]
+    fn batches_parse_in_order() {
+        let samples = ProtocolParser::parse_control_batch(&batch(&[(0, 1), (500, 2), (500, 3)])).unwrap();
+        let parsed: Vec<(u16, [u8; 6])> = samples.iter().map(|s| (s.offset_us, s.control.air)).collect();
+        assert_eq!(parsed, vec![(0, [1, 0, 0, 0, 0, 0]), (500, [0, 1, 0, 0, 0, 0]), (500, [1, 1, 0, 0, 0, 0])]);
+
+        let full = vec![(0, 0); MAX_BATCH_SAMPLES];
+        assert_eq!(ProtocolParser::parse_control_batch(&batch(&full)).unwrap().len(), MAX_BATCH_SAMPLES);
+    }
+
+    #[test]
+    fn malformed_batches_are_rejected() {
+        assert!(ProtocolParser::parse_control_batch(&[]).is_err());
+        assert!(ProtocolParser::parse_control_batch(&[0]).is_err());
+        assert!(ProtocolParser::parse_control_batch(&batch(&vec![(0, 0); MAX_BATCH_SAMPLES + 1])).is_err());
+
+        let mut short = batch(&[(0, 1), (10, 1)]);
+        short.pop();
+        assert!(ProtocolParser::parse_control_batch(&short).is_err());
+        let mut overcounted = batch(&[(0, 1)]);
+        overcounted[0] = 2;
+        assert!(ProtocolParser::parse_control_batch(&overcounted).is_err());
+
+        assert!(ProtocolParser::parse_control_batch(&batch(&[(100, 1), (99, 1)])).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+use std::sync::{Arc, LazyLock, Mutex, Weak};
+use rustnithm_reader::layout;
+use crate::discovery;
+use crate::server::{SensorServer, ServerConfig};
+
+static LIVE_SERVERS: LazyLock<Mutex<Vec<Weak<SensorServer>>>> = LazyLock::new(|| {
+    Mutex::new(Vec::new())
+});
+
+static DEFAULT_SERVER: LazyLock<Arc<SensorServer>> = LazyLock::new(|| {
+    let server = Arc::new(SensorServer::new());
+    if let Ok(mut live) = LIVE_SERVERS.lock() {
+        live.push(Arc::downgrade(&server));
+    }
+    server
+});
+
+/// Server used by the legacy single-instance entry points.
+pub fn default_server() -> &'static Arc<SensorServer> {
+    &DEFAULT_SERVER
+}
+
+/// Registers the default server now, so servers created before its first use still see it.
+pub fn register_default_server() {
+    LazyLock::force(&DEFAULT_SERVER);
+}
+
+pub fn running_servers() -> Vec<Arc<SensorServer>> {
+    match LIVE_SERVERS.lock() {
+        Ok(live) => live
+            .iter()
+            .filter_map(|weak| weak.upgrade())
+            .filter(|server| server.is_running_status())
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn check_conflicts(config: &ServerConfig, exclude: Option<&Arc<SensorServer>>) -> Result<(), String> {
+    let mut live = LIVE_SERVERS.lock().map_err(|_| "Failed to lock server registry")?;
+    live.retain(|weak| weak.strong_count() > 0);
+    for other in live.iter().filter_map(|weak| weak.upgrade()) {
+        if exclude.is_some_and(|own| Arc::ptr_eq(own, &other)) {
+            continue;
+        }
+        let other_config = other.config();
+        if other_config.shmem_name == config.shmem_name {
+            return Err(format!("Shared memory '{}' is used by server '{}'", config.shmem_name, other_config.name));
+        }
+        if config.is_unix() || other_config.is_unix() {
+            if config.is_unix()
+                && other_config.is_unix()
+                && other_config.unix_socket_path() == config.unix_socket_path()
+                && other.is_running_status()
+            {
+                return Err(format!(
+                    "Socket {} is used by server '{}'",
+                    config.unix_socket_path().display(),
+                    other_config.name
+                ));
+            }
+            continue;
+        }
+        if config.port != 0
+            && other_config.port == config.port
+            && other_config.transport() == config.transport()
+            && other.is_running_status()
+        {
+            return Err(format!(
+                "{} port {} is used by server '{}'",
+                config.transport().to_uppercase(),
+                config.port,
+                other_config.name
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn create_server(config: ServerConfig) -> Result<Arc<SensorServer>, String> {
+    if config.shmem_name.trim().is_empty() {
+        return Err("Shared memory name must not be empty".to_string());
+    }
+    if config.shmem_size < layout::MIN_SIZE {
+        return Err(format!("Shared memory size {} is below the {} byte layout", config.shmem_size, layout::MIN_SIZE));
+    }
+    check_conflicts(&config, None)?;
+    let server = Arc::new(SensorServer::with_config(config));
+    LIVE_SERVERS
+        .lock()
+        .map_err(|_| "Failed to lock server registry")?
+        .push(Arc::downgrade(&server));
+    Ok(server)
+}
+
+/// Starts `server` on its configured transport after checking it against the other live servers.
+pub fn start_server(server: &Arc<SensorServer>) -> Result<(), String> {
+    let config = server.config();
+    check_conflicts(&config, Some(server))?;
+    server.init_shmem()?;
+    discovery::ensure_responder();
+    server.set_active(true);
+    let started = server.start_configured();
+    if started.is_err() {
+        server.set_active(false);
+        discovery::release_responder();
+    }
+    server.publish_state();
+    started
+}
+
+pub fn stop_server(server: &SensorServer) {
+    server.stop();
+    server.set_active(false);
+    server.publish_state();
+    discovery::release_responder();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::UdpSocket;
+
+    fn config(name: &str, port: u16) -> ServerConfig {
+        ServerConfig {
+            name: name.to_string(),
+            port,
+            shmem_name: format!("rustnithm_test_registry_{}_{}", name, std::process::id()),
+            ..ServerConfig::default()
+        }
+    }
+
+    fn free_udp_port() -> u16 {
+        UdpSocket::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port()
+    }
+
+    #[test]
+    fn rejects_invalid_and_conflicting_configs() {
+        assert!(create_server(ServerConfig { shmem_name: " ".to_string(), ..config("blank", 0) }).is_err());
+        assert!(create_server(ServerConfig { shmem_size: layout::MIN_SIZE - 1, ..config("small", 0) }).is_err());
+
+        let first = create_server(config("shared", 0)).unwrap();
+        let clash = ServerConfig { name: "other".to_string(), ..config("shared", 0) };
+        assert!(create_server(clash.clone()).is_err());
+        drop(first);
+        assert!(create_server(clash).is_ok());
+    }
+
+    #[test]
+    fn servers_run_side_by_side_and_restart() {
+        let port = free_udp_port();
+        let a = create_server(config("side_a", port)).unwrap();
+        let b = create_server(config("side_b", free_udp_port())).unwrap();
+        start_server(&a).unwrap();
+        start_server(&b).unwrap();
+        assert_eq!(running_servers().iter().filter(|s| Arc::ptr_eq(s, &a) || Arc::ptr_eq(s, &b)).count(), 2);
+
+        stop_server(&a);
+        assert!(!a.is_running_status());
+        assert!(b.is_running_status());
+        start_server(&a).unwrap();
+        assert!(a.is_running_status());
+
+        stop_server(&a);
+        stop_server(&b);
+    }
+
+    #[test]
+    fn udp_and_tcp_share_a_port_number() {
+        let port = free_udp_port();
+        let udp = create_server(config("shared_port_udp", port)).unwrap();
+        let tcp = create_server(ServerConfig { protocol: "tcp".to_string(), ..config("shared_port_tcp", port) }).unwrap();
+        start_server(&udp).unwrap();
+        start_server(&tcp).unwrap();
+        assert_eq!(udp.bound_port(), tcp.bound_port());
+
+        assert!(create_server(config("shared_port_clash", port)).is_err());
+
+        stop_server(&udp);
+        stop_server(&tcp);
+    }
+
+    #[test]
+    fn busy_port_fails_to_start() {
+        let holder = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let port = holder.local_addr().unwrap().port();
+        let server = create_server(config("busy", port)).unwrap();
+        assert!(start_server(&server).is_err());
+        assert!(!server.is_running_status());
+        assert!(!server.is_active_status());
+    }
+
+    #[test]
+    fn default_server_is_checked_once_registered() {
+        register_default_server();
+        let default_shmem = default_server().config().shmem_name;
+        assert!(create_server(ServerConfig { shmem_name: default_shmem, ..config("default_clash", 0) }).is_err());
+    }
+}
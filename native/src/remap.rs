@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+pub const REMAP_NONE: u8 = 0xFF;
+
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct SliderProfile {
+    /// Input cells ignored as dead touch zones, before any other stage.
+    pub masked_cells: Vec<u8>,
+    /// Treat input cells 0..16 as keys, each driving output cells `2k` and `2k + 1`.
+    pub sixteen_key: bool,
+    /// Output cell `i` takes input cell `table[i]`; `REMAP_NONE` leaves it released.
+    pub table: Option<Vec<u8>>,
+    pub reverse: bool,
+    /// Shifts cells towards higher indices; cells shifted past either end are dropped.
+    pub offset: i8,
+}
+
+impl SliderProfile {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(cell) = self.masked_cells.iter().find(|&&c| c >= 32) {
+            return Err(format!("Masked cell {} out of range", cell));
+        }
+        if let Some(table) = self.table.as_ref() {
+            if table.len() != 32 {
+                return Err(format!("Remap table must have 32 entries, got {}", table.len()));
+            }
+            if let Some(cell) = table.iter().find(|&&c| c >= 32 && c != REMAP_NONE) {
+                return Err(format!("Remap source cell {} out of range", cell));
+            }
+        }
+        if self.offset.unsigned_abs() >= 32 {
+            return Err(format!("Offset {} out of range", self.offset));
+        }
+        Ok(())
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == SliderProfile::default()
+    }
+
+    pub fn apply(&self, input: &[u8; 32]) -> [u8; 32] {
+        if self.is_identity() {
+            return *input;
+        }
+
+        let mut cells = *input;
+        for &cell in &self.masked_cells {
+            cells[cell as usize] = 0;
+        }
+
+        if self.sixteen_key {
+            let keys = cells;
+            for key in 0..16 {
+                cells[2 * key] = keys[key];
+                cells[2 * key + 1] = keys[key];
+            }
+        }
+
+        if let Some(table) = self.table.as_ref() {
+            let source = cells;
+            for (out, &from) in cells.iter_mut().zip(table.iter()) {
+                *out = if from == REMAP_NONE { 0 } else { source[from as usize] };
+            }
+        }
+
+        if self.reverse {
+            cells.reverse();
+        }
+
+        if self.offset != 0 {
+            let source = cells;
+            cells = [0u8; 32];
+            for (i, &value) in source.iter().enumerate() {
+                let target = i as i32 + self.offset as i32;
+                if (0..32).contains(&target) {
+                    cells[target as usize] = value;
+                }
+            }
+        }
+
+        cells
+    }
+}
+
+/// Slider profiles keyed by client IP, falling back to a default profile.
+#[derive(Default)]
+pub struct SliderRemapper {
+    default: SliderProfile,
+    clients: HashMap<IpAddr, SliderProfile>,
+}
+
+impl SliderRemapper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn profile(&self, client: Option<IpAddr>) -> &SliderProfile {
+        client
+            .and_then(|ip| self.clients.get(&ip))
+            .unwrap_or(&self.default)
+    }
+
+    pub fn set_profile(&mut self, client: Option<IpAddr>, profile: SliderProfile) -> Result<(), String> {
+        profile.validate()?;
+        match client {
+            Some(ip) => {
+                self.clients.insert(ip, profile);
+            }
+            None => self.default = profile,
+        }
+        Ok(())
+    }
+
+    pub fn clear_profile(&mut self, client: IpAddr) -> bool {
+        self.clients.remove(&client).is_some()
+    }
+
+    pub fn apply(&self, client: Option<IpAddr>, slider: &[u8; 32]) -> [u8; 32] {
+        self.profile(client).apply(slider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ramp() -> [u8; 32] {
+        let mut slider = [0u8; 32];
+        for (i, cell) in slider.iter_mut().enumerate() {
+            *cell = i as u8 + 1;
+        }
+        slider
+    }
+
+    #[test]
+    fn stages_apply_in_order() {
+        let profile = SliderProfile {
+            masked_cells: vec![0],
+            sixteen_key: true,
+            reverse: true,
+            offset: 1,
+            ..SliderProfile::default()
+        };
+        let out = profile.apply(&ramp());
+        // Mask, expand to keys, reverse, then shift: masked key 0 ends up in cell 31.
+        assert_eq!(out[0], 0);
+        assert_eq!(&out[1..5], &[16, 16, 15, 15]);
+        assert_eq!(out[31], 0);
+        assert_eq!(out[30], 2);
+    }
+
+    #[test]
+    fn table_remaps_and_releases_cells() {
+        let mut table: Vec<u8> = (0..32).rev().collect();
+        table[5] = REMAP_NONE;
+        let profile = SliderProfile { table: Some(table), ..SliderProfile::default() };
+        let out = profile.apply(&ramp());
+        assert_eq!(out[0], 32);
+        assert_eq!(out[5], 0);
+        assert_eq!(out[31], 1);
+    }
+
+    #[test]
+    fn invalid_profiles_are_rejected() {
+        let bad = [
+            SliderProfile { masked_cells: vec![32], ..SliderProfile::default() },
+            SliderProfile { table: Some(vec![0; 31]), ..SliderProfile::default() },
+            SliderProfile { table: Some(vec![40; 32]), ..SliderProfile::default() },
+            SliderProfile { offset: -32, ..SliderProfile::default() },
+        ];
+        let mut remapper = SliderRemapper::new();
+        for profile in bad {
+            assert!(remapper.set_profile(None, profile).is_err());
+        }
+        assert!(remapper.profile(None).is_identity());
+    }
+
+    #[test]
+    fn client_profiles_override_the_default() {
+        let client: IpAddr = [10, 0, 0, 2].into();
+        let other: IpAddr = [10, 0, 0, 3].into();
+        let mut remapper = SliderRemapper::new();
+        remapper.set_profile(None, SliderProfile { reverse: true, ..SliderProfile::default() }).unwrap();
+        remapper.set_profile(Some(client), SliderProfile::default()).unwrap();
+
+        assert_eq!(remapper.apply(Some(client), &ramp()), ramp());
+        assert_eq!(remapper.apply(Some(other), &ramp())[0], 32);
+        assert_eq!(remapper.apply(None, &ramp())[0], 32);
+        assert!(remapper.clear_profile(client));
+        assert!(!remapper.clear_profile(client));
+        assert_eq!(remapper.apply(Some(client), &ramp())[0], 32);
+    }
+}
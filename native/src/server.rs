@@ -1,249 +1,1468 @@
-use std::net::{UdpSocket, SocketAddr, TcpListener, TcpStream};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::net::{UdpSocket, SocketAddr, IpAddr, TcpListener, TcpStream};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use std::sync::atomic::AtomicU16;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
-use std::thread;
+use std::thread::{self, JoinHandle};
 use std::time::{Duration, Instant};
 use std::io::{Read, Write};
-use crate::protocol::{ProtocolParser, PacketType};
-use crate::shmem::GLOBAL_SHMEM;
-use crate::api::report_to_flutter;
+use crate::protocol::{ControlFrame, ControlSample, ProtocolParser, PacketType, HandshakePayload, CONTROL_FLAG_BATCH};
+use crate::shmem::{open_shmem, ShmemManager, DEFAULT_SHMEM_NAME, DEFAULT_SHMEM_SIZE};
+use crate::state::{InputState, SensorData};
+use crate::events::{EventBus, ServerEvent};
+use crate::logging::{self, LogCategory};
+use crate::delta::{DeltaDecoder, DeltaResult};
+use crate::pacing::ControlPacer;
+use crate::latency::{LatencyMark, LatencyRecorder, LatencyStats};
+use crate::framing::{ConnectionStats, ConnectionTable, FrameDecoder, FramingConfig, FramingStats};
+use crate::sync::{SyncConfig, SyncMachine, SyncOutcome, SyncState};
+use crate::liveness::{ClientSession, LinkState, Liveness, LivenessConfig};
+use crate::protocol::HANDSHAKE_FLAG_KEEPALIVE;
+use crate::card::{CardId, CardKind};
+use crate::coin::{CoinCadence, CoinQueue, MAX_CREDITS};
+use crate::gesture::{AirGesture, AirGestureConfig};
+use crate::remap::{SliderProfile, SliderRemapper};
+use crate::filter::{DebounceConfig, FilterStats, FilterTarget, InputFilter};
+use crate::protocol::BUTTON_COIN;
 
+#[derive(Clone, Debug)]
 pub struct ServerConfig {
+    pub name: String,
     pub port: u16,
+    /// "udp", "tcp" or "unix".
     pub protocol: String,
+    pub shmem_name: String,
+    pub shmem_size: usize,
+    /// Socket path for the "unix" protocol; `None` for `rustnithm-<name>.sock` in the temp directory.
+    pub socket_path: Option<String>,
 }
 
+impl ServerConfig {
+    pub fn is_tcp(&self) -> bool {
+        self.protocol.eq_ignore_ascii_case("tcp")
+    }
+
+    pub fn is_unix(&self) -> bool {
+        self.protocol.eq_ignore_ascii_case("unix")
+    }
+
+    pub fn transport(&self) -> &'static str {
+        if self.is_unix() {
+            "unix"
+        } else if self.is_tcp() {
+            "tcp"
+        } else {
+            "udp"
+        }
+    }
+
+    pub fn unix_socket_path(&self) -> PathBuf {
+        match self.socket_path.as_ref() {
+            Some(path) => PathBuf::from(path),
+            None => std::env::temp_dir().join(format!("rustnithm-{}.sock", self.name)),
+        }
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            name: "default".to_string(),
+            port: 0,
+            protocol: "udp".to_string(),
+            shmem_name: DEFAULT_SHMEM_NAME.to_string(),
+            shmem_size: DEFAULT_SHMEM_SIZE,
+            socket_path: None,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum CardMode {
+    /// Hold the card for a fixed time after each tap.
+    FixedHold { hold_ms: u32 },
+    /// Hold the card until the client sends any non-card packet.
+    UntilNextPacket,
+    /// Each tap of the same card alternates between insert and remove.
+    Toggle,
+    /// Hold the card until the game sets the acknowledge byte, or until the timeout.
+    UntilAck { timeout_ms: u32 },
+}
+
+impl Default for CardMode {
+    fn default() -> Self {
+        CardMode::FixedHold { hold_ms: 100 }
+    }
+}
+
+/// How often an `UntilAck` hold checks the acknowledge byte.
+const CARD_ACK_POLL: Duration = Duration::from_millis(5);
+
 pub struct SensorServer {
+    config: Mutex<ServerConfig>,
+    ctx: InputContext,
+    /// Transport and liveness threads of the current run, joined by `stop`.
+    workers: Mutex<Vec<JoinHandle<()>>>,
+    /// Port the current run is listening on, which differs from `config.port` when that is 0.
+    bound_port: Mutex<Option<u16>>,
+}
+
+#[derive(Clone)]
+struct InputContext {
     is_running: Arc<AtomicBool>,
     is_active: Arc<AtomicBool>,
-    pub last_client_addr: Arc<Mutex<Option<SocketAddr>>>,
-    pub socket: Arc<Mutex<Option<UdpSocket>>>,
-    pub tcp_writer: Arc<Mutex<Option<TcpStream>>>,
+    last_client_addr: Arc<Mutex<Option<SocketAddr>>>,
+    socket: Arc<Mutex<Option<UdpSocket>>>,
+    /// Writers for connected TCP or Unix socket clients, keyed by peer.
+    stream_writers: Arc<Mutex<HashMap<SocketAddr, Box<dyn Write + Send>>>>,
+    /// Port of the next Unix socket client's placeholder address.
+    #[cfg(unix)]
+    next_unix_peer: Arc<AtomicU16>,
+    shmem: Arc<Mutex<Option<ShmemManager>>>,
+    events: EventBus,
+    input_state: Arc<Mutex<InputState>>,
+    card_mode: Arc<Mutex<CardMode>>,
+    card_since: Arc<Mutex<Option<Instant>>>,
+    card_generation: Arc<AtomicU64>,
+    coin_queue: Arc<CoinQueue>,
+    air_gesture: Arc<Mutex<AirGesture>>,
+    slider_remap: Arc<Mutex<SliderRemapper>>,
+    input_filter: Arc<Mutex<InputFilter>>,
+    /// Set while a thread is waiting on the filter's next deadline; changed under the filter lock.
+    filter_watched: Arc<AtomicBool>,
+    control_pacer: Arc<ControlPacer>,
+    latency: Arc<Mutex<LatencyRecorder>>,
+    framing: Arc<Mutex<FramingConfig>>,
+    connections: Arc<Mutex<ConnectionTable>>,
+    sync: Arc<Mutex<SyncMachine>>,
+    liveness: Arc<Mutex<Liveness>>,
 }
 
-impl SensorServer {
-    pub fn new() -> Self {
-        Self {
-            is_running: Arc::new(AtomicBool::new(false)),
-            is_active: Arc::new(AtomicBool::new(false)),
-            last_client_addr: Arc::new(Mutex::new(None)),
-            socket: Arc::new(Mutex::new(None)),
-            tcp_writer: Arc::new(Mutex::new(None)),
+impl InputContext {
+    fn snapshot(&self) -> SensorData {
+        let active = self.is_active.load(Ordering::SeqCst);
+        let client = self.last_client_addr.lock().ok().and_then(|guard| *guard);
+        match self.input_state.lock() {
+            Ok(state) => state.snapshot(active, client),
+            Err(_) => InputState::new().snapshot(active, client),
+        }
+    }
+
+    fn publish(&self) {
+        self.emit(self.snapshot());
+    }
+
+    fn emit(&self, data: SensorData) {
+        self.events.publish(ServerEvent::Input(data));
+    }
+
+    fn report_error(&self, category: LogCategory, message: String) {
+        logging::error(category, message.clone());
+        self.events.publish(ServerEvent::Error { message });
+    }
+
+    fn report_connected(&self, client: SocketAddr) {
+        logging::info(LogCategory::Connection, format!("Client {} connected", client));
+        self.events.publish(ServerEvent::Connected { client: client.to_string() });
+    }
+
+    fn with_shmem<R>(&self, f: impl FnOnce(&ShmemManager) -> R) -> Option<R> {
+        match self.shmem.lock() {
+            Ok(lock) => lock.as_ref().map(f),
+            Err(_) => None,
+        }
+    }
+
+    /// `src` is the client the handshake came from; `None` stands for the last client.
+    fn handle_handshake(&self, src: Option<SocketAddr>, incoming: HandshakePayload) {
+        if !self.is_running.load(Ordering::SeqCst) { return; }
+
+        let client = src.or_else(|| self.last_client_addr.lock().ok().and_then(|guard| *guard));
+        let outcome = match (client, self.sync.lock()) {
+            (Some(client), Ok(mut sync)) => sync.on_handshake(client, &incoming),
+            _ => None,
+        };
+        if let Some(outcome) = outcome {
+            self.finish_sync(outcome);
+        }
+
+        let current_s = self.is_active.load(Ordering::SeqCst);
+        let new_state = incoming.client_target;
+        if new_state != current_s {
+            self.is_active.store(new_state, Ordering::SeqCst);
+            logging::info(LogCategory::Handshake, format!("Sync state {} -> {}", current_s, new_state));
+            self.publish();
+        }
+
+        let response = HandshakePayload {
+            client_current: incoming.client_current,
+            server_current: new_state,
+            client_target: incoming.client_target,
+            server_target: new_state,
+        };
+        let acknowledged = self.send_handshake(response, client);
+        if !acknowledged {
+            logging::warn(LogCategory::Handshake, "Failed to send handshake response");
+        }
+        self.events.publish(ServerEvent::Handshake {
+            client: client.map(|addr| addr.to_string()),
+            active: new_state,
+            acknowledged,
+        });
+    }
+
+    fn send_handshake(&self, p: HandshakePayload, dest: Option<SocketAddr>) -> bool {
+        let packet = ProtocolParser::build_handshake_response(
+            p.client_current,
+            p.server_current,
+            p.client_target,
+            p.server_target,
+        );
+        self.send_packet(&packet, dest)
+    }
+
+    /// Sends over the transport `dest` is connected on; `None` targets the last client.
+    fn send_packet(&self, packet: &[u8], dest: Option<SocketAddr>) -> bool {
+        let target = match dest.or_else(|| self.last_client_addr.lock().ok().and_then(|guard| *guard)) {
+            Some(addr) => addr,
+            None => return false,
+        };
+
+        if let Ok(mut writers) = self.stream_writers.lock() {
+            if let Some(stream) = writers.get_mut(&target) {
+                let mut framed = Vec::with_capacity(2 + packet.len());
+                framed.extend_from_slice(&(packet.len() as u16).to_le_bytes());
+                framed.extend_from_slice(packet);
+                return stream.write_all(&framed).is_ok();
+            }
+        }
+
+        if let Ok(socket_guard) = self.socket.lock() {
+            if let Some(socket) = socket_guard.as_ref() {
+                return socket.send_to(packet, target).is_ok();
+            }
+        }
+
+        false
+    }
+
+    fn is_stream_client(&self, addr: Option<SocketAddr>) -> bool {
+        match (addr, self.stream_writers.lock()) {
+            (Some(addr), Ok(writers)) => writers.contains_key(&addr),
+            _ => false,
+        }
+    }
+
+    fn update_connection(&self, peer: SocketAddr, transport: &str, connected: bool, framing: FramingStats) {
+        if let Ok(mut connections) = self.connections.lock() {
+            connections.update(peer, ConnectionStats {
+                client: peer.to_string(),
+                transport: transport.to_string(),
+                connected,
+                framing,
+            });
+        }
+    }
+
+    fn touch_client(&self, addr: SocketAddr) {
+        let change = match self.liveness.lock() {
+            Ok(mut liveness) => liveness.touch(addr, Instant::now()),
+            Err(_) => None,
+        };
+        if let Some(state) = change {
+            self.link_changed(addr, state);
+        }
+    }
+
+    fn drop_client(&self, addr: SocketAddr) {
+        let change = match self.liveness.lock() {
+            Ok(mut liveness) => liveness.drop_client(addr),
+            Err(_) => None,
+        };
+        if let Some(state) = change {
+            self.link_changed(addr, state);
+        }
+    }
+
+    fn link_changed(&self, addr: SocketAddr, state: LinkState) {
+        match state {
+            LinkState::Lost | LinkState::Stale => {
+                logging::warn(LogCategory::Connection, format!("Client {} is {:?}", addr, state));
+            }
+            _ => logging::info(LogCategory::Connection, format!("Client {} is {:?}", addr, state)),
+        }
+        self.events.publish(ServerEvent::LinkChanged { client: addr.to_string(), state });
+        if state == LinkState::Lost {
+            if let Ok(mut latency) = self.latency.lock() {
+                latency.forget(addr);
+            }
+            if let Ok(mut filter) = self.input_filter.lock() {
+                filter.forget(Some(addr.ip()));
+            }
+        }
+
+        let release = self.liveness.lock().is_ok_and(|l| l.config().release_on_loss);
+        let was_last = self.last_client_addr.lock().ok().and_then(|guard| *guard) == Some(addr);
+        if state == LinkState::Lost && release && was_last {
+            self.release_inputs();
+        }
+    }
+
+    /// Clears everything the lost client was holding.
+    fn release_inputs(&self) {
+        logging::info(LogCategory::Connection, "Releasing inputs after client loss");
+        self.control_pacer.clear();
+        self.write_control(&[0u8; 6], &[0u8; 32], None);
+        self.apply_buttons(0);
+        if self.input_state.lock().is_ok_and(|state| state.card.is_some()) {
+            self.remove_card();
+        }
+    }
+
+    fn tick_liveness(&self) {
+        let now = Instant::now();
+        let (changes, expired, due) = match self.liveness.lock() {
+            Ok(mut liveness) => (liveness.tick(now), liveness.expire(now), liveness.due_keepalives(now)),
+            Err(_) => return,
+        };
+        for (addr, state) in changes {
+            self.link_changed(addr, state);
+        }
+        if !expired.is_empty() {
+            if let Ok(mut latency) = self.latency.lock() {
+                expired.iter().for_each(|addr| latency.forget(*addr));
+            }
+        }
+        for addr in due {
+            self.send_packet(&ProtocolParser::build_keepalive(0), Some(addr));
+        }
+    }
+
+    /// Rebuilds keyframes and deltas into full frames, acking each so the client can diff against it.
+    /// `decoder` belongs to the connection the frame arrived on.
+    fn apply_control_frame(
+        &self,
+        decoder: &mut DeltaDecoder,
+        src: SocketAddr,
+        frame: ControlFrame,
+        mark: Option<LatencyMark>,
+    ) {
+        match decoder.decode(src, frame) {
+            DeltaResult::Applied { seq, control } => {
+                if let Some(seq) = seq {
+                    self.send_packet(&ProtocolParser::build_control_ack(seq), Some(src));
+                }
+                self.apply_control(Some(src.ip()), &control.air, &control.slider, mark);
+            }
+            DeltaResult::UnknownBase { base } => {
+                logging::debug(
+                    LogCategory::Protocol,
+                    format!("Delta from {} against unknown base {}, requesting keyframe", src, base),
+                );
+                self.send_packet(&ProtocolParser::build_control_resync(base), Some(src));
+            }
+        }
+    }
+
+    fn queue_samples(&self, src: Option<IpAddr>, samples: &[ControlSample], mark: Option<LatencyMark>) {
+        if !self.control_pacer.push(src, samples, Instant::now(), mark) { return; }
+        let ctx = self.clone();
+        thread::spawn(move || {
+            while let Some(sample) = ctx.control_pacer.next() {
+                thread::sleep(sample.due.saturating_duration_since(Instant::now()));
+                if !ctx.is_running.load(Ordering::SeqCst) {
+                    ctx.control_pacer.clear();
+                    continue;
+                }
+                ctx.apply_control(sample.src, &sample.control.air, &sample.control.slider, sample.mark);
+            }
+        });
+    }
+
+    fn apply_control(&self, src: Option<IpAddr>, air: &[u8; 6], slider: &[u8; 32], mark: Option<LatencyMark>) {
+        let (air, slider) = match self.input_filter.lock() {
+            Ok(mut filter) => {
+                let filtered = filter.process(src, air, slider, Instant::now());
+                if filter.next_change().is_some() && !self.filter_watched.swap(true, Ordering::SeqCst) {
+                    self.watch_filter();
+                }
+                filtered
+            }
+            Err(_) => (*air, *slider),
+        };
+        self.map_control(src, &air, &slider, mark);
+    }
+
+    fn map_control(&self, src: Option<IpAddr>, air: &[u8; 6], slider: &[u8; 32], mark: Option<LatencyMark>) {
+        let slider = match self.slider_remap.lock() {
+            Ok(remap) => remap.apply(src, slider),
+            Err(_) => *slider,
+        };
+        let (air, triggered) = match self.air_gesture.lock() {
+            Ok(mut gesture) => {
+                let before = gesture.triggers();
+                let air = gesture.process(src, air, &slider, Instant::now());
+                (air, (gesture.triggers() != before).then(|| gesture.triggers()))
+            }
+            Err(_) => (*air, None),
+        };
+        self.write_control(&air, &slider, mark);
+        if let Some(trigger) = triggered {
+            self.watch_air(trigger);
+        }
+    }
+
+    /// Releases flips the filter held back once their hold time is up; exits when none are left.
+    fn watch_filter(&self) {
+        let ctx = self.clone();
+        thread::spawn(move || loop {
+            let wait = match ctx.input_filter.lock() {
+                Ok(filter) => {
+                    let now = Instant::now();
+                    match filter.next_change() {
+                        Some(at) => at.saturating_duration_since(now),
+                        None => {
+                            ctx.filter_watched.store(false, Ordering::SeqCst);
+                            return;
+                        }
+                    }
+                }
+                Err(_) => {
+                    ctx.filter_watched.store(false, Ordering::SeqCst);
+                    return;
+                }
+            };
+            thread::sleep(wait);
+            ctx.tick_filter();
+        });
+    }
+
+    fn tick_filter(&self) {
+        let refreshed = match self.input_filter.lock() {
+            Ok(mut filter) => filter.refresh(Instant::now()),
+            Err(_) => return,
+        };
+        for (src, air, slider) in refreshed {
+            self.map_control(src, &air, &slider, None);
+        }
+    }
+
+    fn write_control(&self, air: &[u8; 6], slider: &[u8; 32], mark: Option<LatencyMark>) {
+        self.with_shmem(|manager| manager.write_data(air, slider));
+        if let (Some(mark), Ok(mut latency)) = (mark, self.latency.lock()) {
+            latency.record(&mark, Instant::now());
+        }
+        if let Ok(mut state) = self.input_state.lock() {
+            state.set_control(air, slider);
+        }
+        self.publish();
+    }
+
+    /// Plays a triggered gesture to its end; a retrigger hands over to a new watcher.
+    fn watch_air(&self, trigger: u64) {
+        let ctx = self.clone();
+        thread::spawn(move || loop {
+            let wait = match ctx.air_gesture.lock() {
+                Ok(gesture) if gesture.triggers() == trigger => {
+                    let now = Instant::now();
+                    match gesture.next_change(now) {
+                        Some(at) => at.saturating_duration_since(now),
+                        None => return,
+                    }
+                }
+                _ => return,
+            };
+            thread::sleep(wait);
+            ctx.tick_air();
+        });
+    }
+
+    fn tick_air(&self) {
+        let air = match self.air_gesture.lock() {
+            Ok(mut gesture) => gesture.refresh(Instant::now()),
+            Err(_) => None,
+        };
+        if let Some(air) = air {
+            let slider = match self.input_state.lock() {
+                Ok(state) => state.slider,
+                Err(_) => return,
+            };
+            self.write_control(&air, &slider, None);
+        }
+    }
+
+    fn apply_buttons(&self, mask: u8) {
+        if let Ok(mut state) = self.input_state.lock() {
+            state.set_button_mask(mask & !BUTTON_COIN);
+        }
+        self.flush_buttons();
+    }
+
+    fn set_coin_pulse(&self, pressed: bool) {
+        if let Ok(mut state) = self.input_state.lock() {
+            state.coin_pulse = pressed;
+        }
+        self.flush_buttons();
+    }
+
+    fn flush_buttons(&self) {
+        let mask = match self.input_state.lock() {
+            Ok(state) => state.effective_buttons(),
+            Err(_) => return,
+        };
+        self.with_shmem(|manager| manager.write_buttons(mask));
+        self.publish();
+    }
+
+    fn request_sync(&self, target: bool) -> Result<(), String> {
+        if !self.is_running.load(Ordering::SeqCst) {
+            return Err("Server is not running".to_string());
+        }
+        let current = self.is_active.load(Ordering::SeqCst);
+        let peer = self.last_client_addr.lock().ok().and_then(|guard| *guard);
+        let request = {
+            let mut sync = self.sync.lock().map_err(|_| "Failed to lock sync state")?;
+            sync.begin(peer, current, target, Instant::now())?
+        };
+
+        self.is_active.store(target, Ordering::SeqCst);
+        logging::info(LogCategory::Handshake, format!("Sync {} -> {} pending", current, target));
+        self.events.publish(ServerEvent::SyncPending { target });
+        self.publish();
+        if !self.send_handshake(request, peer) {
+            logging::warn(LogCategory::Handshake, "Failed to send sync request");
+        }
+
+        let ctx = self.clone();
+        thread::spawn(move || loop {
+            thread::sleep(Duration::from_millis(20));
+            let reliable = ctx.is_stream_client(peer);
+            let (outcome, resend) = match ctx.sync.lock() {
+                Ok(mut sync) => {
+                    if !sync.is_pending() {
+                        break;
+                    }
+                    let now = Instant::now();
+                    match sync.tick(now) {
+                        Some(outcome) => (Some(outcome), None),
+                        None if reliable => (None, None),
+                        None => (None, sync.retransmit(now)),
+                    }
+                }
+                Err(_) => break,
+            };
+            if let Some(outcome) = outcome {
+                ctx.finish_sync(outcome);
+                break;
+            }
+            if let Some(request) = resend {
+                ctx.send_handshake(request, peer);
+            }
+        });
+        Ok(())
+    }
+
+    fn finish_sync(&self, outcome: SyncOutcome) {
+        match outcome {
+            SyncOutcome::Confirmed { active, attempts } => {
+                logging::info(
+                    LogCategory::Handshake,
+                    format!("Sync to {} confirmed after {} attempt(s)", active, attempts),
+                );
+                self.events.publish(ServerEvent::SyncConfirmed { active });
+            }
+            SyncOutcome::TimedOut { active, attempts } => {
+                self.is_active.store(active, Ordering::SeqCst);
+                logging::warn(
+                    LogCategory::Handshake,
+                    format!("Sync timed out after {} attempt(s), rolled back to {}", attempts, active),
+                );
+                self.events.publish(ServerEvent::SyncFailed {
+                    active,
+                    reason: "Client did not acknowledge".to_string(),
+                });
+                self.publish();
+            }
+            SyncOutcome::Overridden { active } => {
+                logging::info(LogCategory::Handshake, format!("Sync overridden by client target {}", active));
+                self.events.publish(ServerEvent::SyncFailed {
+                    active,
+                    reason: "Client requested a different state".to_string(),
+                });
+            }
+        }
+    }
+
+    fn queue_coins(&self, count: u32) -> Result<(), String> {
+        if !self.coin_queue.push(count)? { return Ok(()); }
+        let ctx = self.clone();
+        thread::spawn(move || {
+            while let Some(cadence) = ctx.coin_queue.take() {
+                ctx.set_coin_pulse(true);
+                thread::sleep(cadence.press());
+                ctx.set_coin_pulse(false);
+                thread::sleep(cadence.release());
+            }
+        });
+        Ok(())
+    }
+
+    fn card_mode(&self) -> CardMode {
+        self.card_mode.lock().map(|mode| *mode).unwrap_or_default()
+    }
+
+    /// `tracked` cards are released by the configured card mode; untracked
+    /// ones are left for the caller to remove.
+    fn insert_card(&self, card: CardId, tracked: bool) -> u64 {
+        let generation = self.card_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        self.with_shmem(|manager| {
+            manager.write_card_raw(card.kind(), &card.to_bytes());
+            manager.write_card_ack(false);
+        });
+        if let Ok(mut state) = self.input_state.lock() {
+            state.set_card(card);
+        }
+        if let Ok(mut since) = self.card_since.lock() {
+            *since = if tracked { Some(Instant::now()) } else { None };
+        }
+        self.publish();
+        if tracked {
+            self.watch_card(generation);
+        }
+        generation
+    }
+
+    /// Releases a tracked card once its mode allows, independent of any transport loop.
+    /// Ends when the card is removed or replaced.
+    fn watch_card(&self, generation: u64) {
+        let ctx = self.clone();
+        thread::spawn(move || loop {
+            if ctx.card_generation.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            let elapsed = match ctx.card_held_since() {
+                Some(since) => since.elapsed(),
+                None => return,
+            };
+            let wait = match ctx.card_mode() {
+                CardMode::FixedHold { hold_ms } => Duration::from_millis(hold_ms as u64).saturating_sub(elapsed),
+                CardMode::UntilAck { timeout_ms } => {
+                    if ctx.with_shmem(|manager| manager.read_card_ack()).unwrap_or(false) {
+                        Duration::ZERO
+                    } else {
+                        Duration::from_millis(timeout_ms as u64).saturating_sub(elapsed).min(CARD_ACK_POLL)
+                    }
+                }
+                CardMode::UntilNextPacket | CardMode::Toggle => return,
+            };
+            if wait.is_zero() {
+                ctx.remove_card();
+                return;
+            }
+            thread::sleep(wait);
+        });
+    }
+
+    fn remove_card(&self) {
+        self.with_shmem(|manager| manager.write_card_raw(CardKind::AccessCode, &[]));
+        if let Ok(mut state) = self.input_state.lock() {
+            state.clear_card();
+        }
+        if let Ok(mut since) = self.card_since.lock() {
+            *since = None;
+        }
+        self.publish();
+    }
+
+    fn present_card(&self, card: CardId) {
+        if self.card_mode() == CardMode::Toggle {
+            let current = self.input_state.lock().ok().and_then(|state| state.card);
+            if current == Some(card) {
+                self.remove_card();
+                return;
+            }
+        }
+        self.insert_card(card, true);
+    }
+
+    fn card_held_since(&self) -> Option<Instant> {
+        self.card_since.lock().ok().and_then(|since| *since)
+    }
+
+    fn release_card_on_packet(&self) {
+        if self.card_mode() == CardMode::UntilNextPacket && self.card_held_since().is_some() {
+            self.remove_card();
+        }
+    }
+}
+
+impl SensorServer {
+    pub fn new() -> Self {
+        Self::with_config(ServerConfig::default())
+    }
+
+    pub fn with_config(config: ServerConfig) -> Self {
+        Self {
+            config: Mutex::new(config),
+            ctx: InputContext {
+                is_running: Arc::new(AtomicBool::new(false)),
+                is_active: Arc::new(AtomicBool::new(false)),
+                last_client_addr: Arc::new(Mutex::new(None)),
+                socket: Arc::new(Mutex::new(None)),
+                stream_writers: Arc::new(Mutex::new(HashMap::new())),
+                #[cfg(unix)]
+                next_unix_peer: Arc::new(AtomicU16::new(1)),
+                shmem: Arc::new(Mutex::new(None)),
+                events: EventBus::default(),
+                input_state: Arc::new(Mutex::new(InputState::new())),
+                card_mode: Arc::new(Mutex::new(CardMode::default())),
+                card_since: Arc::new(Mutex::new(None)),
+                card_generation: Arc::new(AtomicU64::new(0)),
+                coin_queue: Arc::new(CoinQueue::new()),
+                air_gesture: Arc::new(Mutex::new(AirGesture::default())),
+                slider_remap: Arc::new(Mutex::new(SliderRemapper::new())),
+                input_filter: Arc::new(Mutex::new(InputFilter::new())),
+                filter_watched: Arc::new(AtomicBool::new(false)),
+                control_pacer: Arc::new(ControlPacer::new()),
+                latency: Arc::new(Mutex::new(LatencyRecorder::new())),
+                framing: Arc::new(Mutex::new(FramingConfig::default())),
+                connections: Arc::new(Mutex::new(ConnectionTable::default())),
+                sync: Arc::new(Mutex::new(SyncMachine::default())),
+                liveness: Arc::new(Mutex::new(Liveness::default())),
+            },
+            workers: Mutex::new(Vec::new()),
+            bound_port: Mutex::new(None),
+        }
+    }
+
+    pub fn config(&self) -> ServerConfig {
+        self.config.lock().map(|config| config.clone()).unwrap_or_default()
+    }
+
+    pub fn init_shmem(&self) -> Result<(), String> {
+        let config = self.config();
+        let mut lock = self.ctx.shmem.lock().map_err(|_| "Failed to lock shmem")?;
+        if lock.is_none() {
+            *lock = Some(open_shmem(&config.shmem_name, config.shmem_size)?);
+        }
+        Ok(())
+    }
+
+    pub fn events(&self) -> &EventBus {
+        &self.ctx.events
+    }
+
+    pub fn set_last_client(&self, addr: SocketAddr) {
+        if let Ok(mut guard) = self.ctx.last_client_addr.lock() {
+            *guard = Some(addr);
+        }
+    }
+
+    pub fn sync_input(&self, air: &[u8], slider: &[u8], coin: u8, service: u8, test: u8) {
+        self.ctx.with_shmem(|manager| {
+            manager.write_data(air, slider);
+            manager.write_status(coin, service, test);
+        });
+        if let Ok(mut state) = self.ctx.input_state.lock() {
+            let air: Option<[u8; 6]> = air.try_into().ok();
+            let slider: Option<[u8; 32]> = slider.try_into().ok();
+            if let (Some(air), Some(slider)) = (air, slider) {
+                state.set_control(&air, &slider);
+            }
+            state.set_buttons(coin, service, test);
+        }
+        self.ctx.publish();
+    }
+
+    pub fn handle_handshake(&self, incoming: HandshakePayload) {
+        self.ctx.handle_handshake(None, incoming);
+    }
+
+    /// Starts a server-initiated toggle of the active state; the outcome arrives as an event.
+    pub fn request_sync(&self) -> Result<(), String> {
+        self.ctx.request_sync(!self.is_active_status())
+    }
+
+    pub fn sync_state(&self) -> SyncState {
+        self.ctx.sync.lock().map(|sync| sync.state()).unwrap_or(SyncState::Idle)
+    }
+
+    pub fn set_sync_config(&self, config: SyncConfig) -> Result<(), String> {
+        let mut sync = self.ctx.sync.lock().map_err(|_| "Failed to lock sync state")?;
+        sync.set_config(config);
+        Ok(())
+    }
+
+    pub fn sync_config(&self) -> SyncConfig {
+        self.ctx.sync.lock().map(|sync| sync.config()).unwrap_or_default()
+    }
+
+    pub fn link_state(&self) -> LinkState {
+        self.ctx.liveness.lock().map(|l| l.overall()).unwrap_or(LinkState::Disconnected)
+    }
+
+    pub fn sessions(&self) -> Vec<ClientSession> {
+        match self.ctx.liveness.lock() {
+            Ok(liveness) => liveness.sessions(Instant::now()),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    pub fn set_liveness_config(&self, config: LivenessConfig) -> Result<(), String> {
+        let mut liveness = self.ctx.liveness.lock().map_err(|_| "Failed to lock liveness")?;
+        liveness.set_config(config);
+        Ok(())
+    }
+
+    pub fn liveness_config(&self) -> LivenessConfig {
+        self.ctx.liveness.lock().map(|l| l.config()).unwrap_or_default()
+    }
+
+    /// Applies to stream connections accepted after the call; `max_frame` is capped at `MAX_FRAME_LIMIT`.
+    pub fn set_framing_config(&self, config: FramingConfig) {
+        if let Ok(mut framing) = self.ctx.framing.lock() {
+            *framing = config.clamped();
+        }
+    }
+
+    pub fn framing_config(&self) -> FramingConfig {
+        self.ctx.framing.lock().map(|framing| *framing).unwrap_or_default()
+    }
+
+    /// Open stream connections and the most recently closed ones.
+    pub fn connection_stats(&self) -> Vec<ConnectionStats> {
+        let mut stats: Vec<ConnectionStats> = match self.ctx.connections.lock() {
+            Ok(connections) => connections.all(),
+            Err(_) => Vec::new(),
+        };
+        stats.sort_by(|a, b| a.client.cmp(&b.client));
+        stats
+    }
+
+    /// Receive and client-timestamp latency per transport, sorted by transport.
+    pub fn latency_stats(&self) -> Vec<LatencyStats> {
+        self.ctx.latency.lock().map(|latency| latency.stats()).unwrap_or_default()
+    }
+
+    pub fn reset_latency_stats(&self) {
+        if let Ok(mut latency) = self.ctx.latency.lock() {
+            latency.reset();
+        }
+    }
+
+    pub fn set_filter(&self, target: FilterTarget, config: DebounceConfig) -> Result<(), String> {
+        let mut filter = self.ctx.input_filter.lock().map_err(|_| "Failed to lock input filter")?;
+        filter.set_config(target, config);
+        Ok(())
+    }
+
+    pub fn filter_configs(&self) -> (DebounceConfig, DebounceConfig) {
+        match self.ctx.input_filter.lock() {
+            Ok(filter) => (filter.config(FilterTarget::Slider), filter.config(FilterTarget::Air)),
+            Err(_) => (DebounceConfig::default(), DebounceConfig::default()),
+        }
+    }
+
+    pub fn set_cell_filter(&self, target: FilterTarget, cell: usize, config: Option<DebounceConfig>) -> Result<(), String> {
+        let mut filter = self.ctx.input_filter.lock().map_err(|_| "Failed to lock input filter")?;
+        filter.set_cell_config(target, cell, config)
+    }
+
+    pub fn cell_filter(&self, target: FilterTarget, cell: usize) -> Result<DebounceConfig, String> {
+        let filter = self.ctx.input_filter.lock().map_err(|_| "Failed to lock input filter")?;
+        filter.cell_config(target, cell)
+    }
+
+    pub fn filter_stats(&self) -> FilterStats {
+        self.ctx.input_filter.lock().map(|filter| filter.stats()).unwrap_or_default()
+    }
+
+    pub fn reset_filter_stats(&self) {
+        if let Ok(mut filter) = self.ctx.input_filter.lock() {
+            filter.reset_stats();
+        }
+    }
+
+    pub fn set_slider_profile(&self, client: Option<IpAddr>, profile: SliderProfile) -> Result<(), String> {
+        let mut remap = self.ctx.slider_remap.lock().map_err(|_| "Failed to lock slider remap")?;
+        remap.set_profile(client, profile)
+    }
+
+    pub fn slider_profile(&self, client: Option<IpAddr>) -> SliderProfile {
+        match self.ctx.slider_remap.lock() {
+            Ok(remap) => remap.profile(client).clone(),
+            Err(_) => SliderProfile::default(),
+        }
+    }
+
+    pub fn clear_slider_profile(&self, client: IpAddr) -> bool {
+        match self.ctx.slider_remap.lock() {
+            Ok(mut remap) => remap.clear_profile(client),
+            Err(_) => false,
+        }
+    }
+
+    pub fn set_air_gesture(&self, config: AirGestureConfig) -> Result<(), String> {
+        let mut gesture = self.ctx.air_gesture.lock().map_err(|_| "Failed to lock air gesture")?;
+        gesture.set_config(config);
+        Ok(())
+    }
+
+    pub fn air_gesture(&self) -> AirGestureConfig {
+        self.ctx.air_gesture.lock().map(|gesture| gesture.config()).unwrap_or_default()
+    }
+
+    pub fn insert_credits(&self, count: u32) -> Result<(), String> {
+        if count > MAX_CREDITS {
+            return Err(format!("Cannot insert {} credits; the limit is {}", count, MAX_CREDITS));
+        }
+        self.ctx.queue_coins(count)
+    }
+
+    pub fn pending_credits(&self) -> u32 {
+        self.ctx.coin_queue.pending()
+    }
+
+    pub fn set_coin_cadence(&self, cadence: CoinCadence) -> Result<(), String> {
+        self.ctx.coin_queue.set_cadence(cadence)
+    }
+
+    pub fn coin_cadence(&self) -> CoinCadence {
+        self.ctx.coin_queue.cadence()
+    }
+
+    /// Also applies to a card that is already held.
+    pub fn set_card_mode(&self, mode: CardMode) -> Result<(), String> {
+        *self.ctx.card_mode.lock().map_err(|_| "Failed to lock card mode")? = mode;
+        if self.ctx.card_held_since().is_some() {
+            self.ctx.watch_card(self.ctx.card_generation.load(Ordering::SeqCst));
         }
+        Ok(())
+    }
+
+    pub fn card_mode(&self) -> CardMode {
+        self.ctx.card_mode()
+    }
+
+    pub fn snapshot(&self) -> SensorData {
+        self.ctx.snapshot()
+    }
+
+    pub fn publish_state(&self) {
+        self.ctx.publish();
     }
 
     pub fn set_active(&self, active: bool) {
-        self.is_active.store(active, Ordering::SeqCst);
+        self.ctx.is_active.store(active, Ordering::SeqCst);
     }
 
     pub fn is_active_status(&self) -> bool {
-        self.is_active.load(Ordering::SeqCst)
+        self.ctx.is_active.load(Ordering::SeqCst)
     }
 
     pub fn is_running_status(&self) -> bool {
-        self.is_running.load(Ordering::SeqCst)
+        self.ctx.is_running.load(Ordering::SeqCst)
     }
 
-    pub fn start(&self, port: u16, is_tcp: bool) {
-        if self.is_running.load(Ordering::SeqCst) {
-            return;
+    /// UDP or TCP port of the running server; `None` when stopped or on a Unix socket.
+    pub fn bound_port(&self) -> Option<u16> {
+        self.bound_port.lock().ok().and_then(|port| *port)
+    }
+
+    pub fn start(&self, port: u16, is_tcp: bool) -> Result<(), String> {
+        if self.ctx.is_running.load(Ordering::SeqCst) {
+            return Ok(());
         }
+        self.set_transport(port, is_tcp);
+        self.start_configured()
+    }
 
-        self.is_running.store(true, Ordering::SeqCst);
-        let is_running = self.is_running.clone();
-        let is_active = self.is_active.clone();
-        let last_client_addr = self.last_client_addr.clone();
-        let shared_socket = self.socket.clone();
-        let shared_tcp_writer = self.tcp_writer.clone();
-
-        if is_tcp {
-            thread::spawn(move || {
-                let listener = match TcpListener::bind(format!("0.0.0.0:{}", port)) {
-                    Ok(l) => l,
-                    Err(_) => {
-                        is_running.store(false, Ordering::SeqCst);
-                        return;
-                    }
-                };
-                listener.set_nonblocking(false).unwrap();
-
-                while is_running.load(Ordering::SeqCst) {
-                    match listener.accept() {
-                        Ok((stream, src)) => {
-                            if let Ok(mut addr_guard) = last_client_addr.lock() {
-                                *addr_guard = Some(src);
-                            }
-
-                            let writer_clone = match stream.try_clone() {
-                                Ok(c) => c,
-                                Err(_) => continue,
-                            };
-                            if let Ok(mut guard) = shared_tcp_writer.lock() {
-                                *guard = Some(writer_clone);
-                            }
-
-                            let is_running_inner = is_running.clone();
-                            let is_active_inner = is_active.clone();
-
-                            thread::spawn(move || {
-                                handle_tcp_client(stream, is_running_inner, is_active_inner);
-                            });
-                        }
-                        Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
-                            thread::sleep(Duration::from_millis(10));
-                        }
-                        Err(_) => {
-                            thread::sleep(Duration::from_millis(10));
+    /// Switches the config to UDP or TCP on `port`; takes effect on the next start.
+    pub fn set_transport(&self, port: u16, is_tcp: bool) {
+        if let Ok(mut config) = self.config.lock() {
+            config.port = port;
+            config.protocol = if is_tcp { "tcp" } else { "udp" }.to_string();
+        }
+    }
+
+    /// Binds the transport in the config, then serves it on background threads.
+    /// Bind failures are returned here rather than only logged.
+    pub fn start_configured(&self) -> Result<(), String> {
+        if self.ctx.is_running.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        let config = self.config();
+        if let Err(e) = self.init_shmem() {
+            self.ctx.report_error(LogCategory::Shmem, e);
+        }
+        let transport = match bind_transport(&config) {
+            Ok(t) => t,
+            Err(e) => {
+                self.ctx.report_error(LogCategory::Transport, e.clone());
+                return Err(e);
+            }
+        };
+        let port = transport.port();
+        match port {
+            Some(port) => logging::info(
+                LogCategory::Transport,
+                format!("Starting {} server on port {}", config.transport().to_uppercase(), port),
+            ),
+            None => logging::info(
+                LogCategory::Transport,
+                format!("Starting Unix socket server on {}", config.unix_socket_path().display()),
+            ),
+        }
+        if let Ok(mut bound) = self.bound_port.lock() {
+            *bound = port;
+        }
+
+        self.ctx.is_running.store(true, Ordering::SeqCst);
+        let liveness_ctx = self.ctx.clone();
+        let liveness = thread::spawn(move || {
+            while liveness_ctx.is_running.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_millis(100));
+                liveness_ctx.tick_liveness();
+            }
+        });
+        let ctx = self.ctx.clone();
+        let serve = match transport {
+            BoundTransport::Udp(socket) => thread::spawn(move || serve_udp(socket, ctx)),
+            BoundTransport::Tcp(listener) => thread::spawn(move || serve_tcp(listener, ctx)),
+            #[cfg(unix)]
+            BoundTransport::Unix(listener, path) => thread::spawn(move || serve_unix(listener, path, ctx)),
+        };
+        if let Ok(mut workers) = self.workers.lock() {
+            workers.push(liveness);
+            workers.push(serve);
+        }
+        Ok(())
+    }
+
+    /// Returns once the transport threads, including every client connection's, have exited
+    /// and released their sockets, so a following `start` sees none of this run's clients.
+    pub fn stop(&self) {
+        let was_running = self.ctx.is_running.swap(false, Ordering::SeqCst);
+        let workers: Vec<JoinHandle<()>> = match self.workers.lock() {
+            Ok(mut workers) => workers.drain(..).collect(),
+            Err(_) => Vec::new(),
+        };
+        for worker in workers {
+            if worker.thread().id() != thread::current().id() {
+                let _ = worker.join();
+            }
+        }
+        if was_running {
+            logging::info(LogCategory::Transport, format!("Server '{}' stopped", self.config().name));
+        }
+        if let Ok(mut bound) = self.bound_port.lock() {
+            *bound = None;
+        }
+        if let Ok(mut writers) = self.ctx.stream_writers.lock() {
+            writers.clear();
+        }
+        self.ctx.coin_queue.clear();
+        self.ctx.control_pacer.clear();
+        if let Ok(mut sync) = self.ctx.sync.lock() {
+            sync.cancel();
+        }
+        if let Ok(mut liveness) = self.ctx.liveness.lock() {
+            liveness.clear();
+        }
+        if let Ok(mut connections) = self.ctx.connections.lock() {
+            connections.clear();
+        }
+        if let Ok(mut state) = self.ctx.input_state.lock() {
+            state.clear();
+        }
+    }
+
+    pub fn insert_card(&self, card: CardId, hold: Duration) {
+        let ctx = self.ctx.clone();
+        let generation = ctx.insert_card(card, false);
+
+        thread::spawn(move || {
+            thread::sleep(hold);
+            if ctx.card_generation.load(Ordering::SeqCst) != generation { return; }
+            ctx.remove_card();
+        });
+    }
+
+    pub fn send_handshake(&self, p: HandshakePayload) -> bool {
+        self.ctx.send_handshake(p, None)
+    }
+}
+
+impl Default for SensorServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SensorServer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn handle_tcp_client(
+    stream: TcpStream,
+    peer: SocketAddr,
+    is_running: Arc<AtomicBool>,
+    ctx: InputContext,
+) {
+    let transport = if peer.ip().is_loopback() { "tcp-loopback" } else { "tcp" };
+    handle_stream_client(stream, peer, transport, is_running, ctx);
+}
+
+/// Unix socket clients have no address; each connection gets a loopback placeholder
+/// so liveness and per-client state can key on it like any other client. Once the
+/// counter wraps, port 0 and placeholders of open connections are skipped.
+#[cfg(unix)]
+fn unix_peer(ctx: &InputContext) -> Option<SocketAddr> {
+    let writers = ctx.stream_writers.lock().ok()?;
+    (0..=u16::MAX).find_map(|_| {
+        let port = ctx.next_unix_peer.fetch_add(1, Ordering::SeqCst);
+        let peer = SocketAddr::from(([127, 0, 0, 1], port));
+        (port != 0 && !writers.contains_key(&peer)).then_some(peer)
+    })
+}
+
+enum BoundTransport {
+    Udp(UdpSocket),
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener, PathBuf),
+}
+
+impl BoundTransport {
+    fn port(&self) -> Option<u16> {
+        match self {
+            BoundTransport::Udp(socket) => socket.local_addr().ok().map(|addr| addr.port()),
+            BoundTransport::Tcp(listener) => listener.local_addr().ok().map(|addr| addr.port()),
+            #[cfg(unix)]
+            BoundTransport::Unix(..) => None,
+        }
+    }
+}
+
+fn bind_transport(config: &ServerConfig) -> Result<BoundTransport, String> {
+    let port = config.port;
+    if config.is_unix() {
+        return bind_unix(config.unix_socket_path());
+    }
+    if config.is_tcp() {
+        let listener = TcpListener::bind(("0.0.0.0", port))
+            .map_err(|e| format!("TCP bind on port {} failed: {}", port, e))?;
+        // Nonblocking so the accept loop sees `stop` and releases the port.
+        listener
+            .set_nonblocking(true)
+            .map_err(|e| format!("Failed to configure TCP listener: {}", e))?;
+        return Ok(BoundTransport::Tcp(listener));
+    }
+    let socket = UdpSocket::bind(("0.0.0.0", port))
+        .map_err(|e| format!("UDP bind on port {} failed: {}", port, e))?;
+    socket
+        .set_read_timeout(Some(Duration::from_millis(100)))
+        .map_err(|e| format!("Failed to configure UDP socket: {}", e))?;
+    Ok(BoundTransport::Udp(socket))
+}
+
+#[cfg(unix)]
+fn bind_unix(path: PathBuf) -> Result<BoundTransport, String> {
+    remove_stale_socket(&path)?;
+    let listener = UnixListener::bind(&path)
+        .map_err(|e| format!("Unix socket bind on {} failed: {}", path.display(), e))?;
+    listener
+        .set_nonblocking(true)
+        .map_err(|e| format!("Failed to configure Unix socket: {}", e))?;
+    Ok(BoundTransport::Unix(listener, path))
+}
+
+/// Unlinks `path` only if it is a socket nobody answers on, as a crashed run leaves behind.
+#[cfg(unix)]
+fn remove_stale_socket(path: &Path) -> Result<(), String> {
+    use std::os::unix::fs::FileTypeExt;
+
+    let metadata = match std::fs::symlink_metadata(path) {
+        Ok(m) => m,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(format!("Failed to inspect {}: {}", path.display(), e)),
+    };
+    if !metadata.file_type().is_socket() {
+        return Err(format!("{} exists and is not a socket", path.display()));
+    }
+    if UnixStream::connect(path).is_ok() {
+        return Err(format!("{} is in use by another server", path.display()));
+    }
+    std::fs::remove_file(path).map_err(|e| format!("Failed to remove stale socket {}: {}", path.display(), e))
+}
+
+#[cfg(not(unix))]
+fn bind_unix(_path: PathBuf) -> Result<BoundTransport, String> {
+    Err("Unix sockets are not supported on this platform".to_string())
+}
+
+fn serve_udp(socket: UdpSocket, ctx: InputContext) {
+    if let Ok(s_clone) = socket.try_clone() {
+        if let Ok(mut guard) = ctx.socket.lock() {
+            *guard = Some(s_clone);
+        }
+    }
+
+    let mut buf = [0u8; 1024];
+    let mut control_decoder = DeltaDecoder::new();
+    let mut last_button_time: Option<Instant> = None;
+    let ttl_duration = Duration::from_millis(100);
+
+    while ctx.is_running.load(Ordering::SeqCst) {
+        match socket.recv_from(&mut buf) {
+            Ok((amt, src)) => {
+                let received = Instant::now();
+                if amt > 0 {
+                    if let Ok(mut addr_guard) = ctx.last_client_addr.lock() {
+                        if *addr_guard != Some(src) {
+                            ctx.report_connected(src);
                         }
+                        *addr_guard = Some(src);
                     }
+                    process_packet(
+                        &buf[..amt],
+                        src,
+                        ("udp", received),
+                        &ctx,
+                        &mut control_decoder,
+                        &mut last_button_time,
+                    );
                 }
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut
+                || e.kind() == std::io::ErrorKind::WouldBlock => {}
+            Err(e) => logging::debug(LogCategory::Transport, format!("UDP receive failed: {}", e)),
+        }
 
-                if let Ok(mut guard) = shared_tcp_writer.lock() {
-                    *guard = None;
-                }
-            });
-        } else {
-            thread::spawn(move || {
-                let socket = match UdpSocket::bind(format!("0.0.0.0:{}", port)) {
-                    Ok(s) => s,
-                    Err(_) => {
-                        is_running.store(false, Ordering::SeqCst);
-                        return;
-                    }
-                };
+        tick_ttl(&ctx, &mut last_button_time, ttl_duration);
+    }
 
-                if let Ok(s_clone) = socket.try_clone() {
-                    if let Ok(mut guard) = shared_socket.lock() {
-                        *guard = Some(s_clone);
-                    }
+    if let Ok(mut guard) = ctx.socket.lock() {
+        *guard = None;
+    }
+}
+
+fn serve_tcp(listener: TcpListener, ctx: InputContext) {
+    let mut clients: Vec<JoinHandle<()>> = Vec::new();
+    while ctx.is_running.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, src)) => {
+                // Accepted sockets inherit nonblocking mode on some platforms. Either call
+                // fails on a connection that was reset before it was accepted; drop it.
+                if let Err(e) = stream.set_nonblocking(false)
+                    .and_then(|_| stream.set_read_timeout(Some(Duration::from_millis(100))))
+                {
+                    logging::warn(LogCategory::Transport, format!("Failed to configure TCP client {}: {}", src, e));
+                    continue;
+                }
+                if let Ok(mut addr_guard) = ctx.last_client_addr.lock() {
+                    *addr_guard = Some(src);
                 }
 
-                socket.set_read_timeout(Some(Duration::from_millis(100))).unwrap();
-
-                let mut buf = [0u8; 1024];
-                let mut last_button_time: Option<Instant> = None;
-                let mut last_card_time: Option<Instant> = None;
-                let ttl_duration = Duration::from_millis(100);
-
-                while is_running.load(Ordering::SeqCst) {
-                    match socket.recv_from(&mut buf) {
-                        Ok((amt, src)) => {
-                            if amt > 0 {
-                                if let Ok(mut addr_guard) = last_client_addr.lock() {
-                                    *addr_guard = Some(src);
-                                }
-                                process_packet(
-                                    &buf[..amt],
-                                    &is_active,
-                                    &mut last_button_time,
-                                    &mut last_card_time,
-                                );
-                            }
-                        }
-                        Err(_) => {}
+                let writer_clone = match stream.try_clone() {
+                    Ok(c) => c,
+                    Err(e) => {
+                        logging::warn(LogCategory::Transport, format!("Failed to clone stream for {}: {}", src, e));
+                        continue;
                     }
-
-                    tick_ttl(&mut last_button_time, &mut last_card_time, ttl_duration);
+                };
+                if let Ok(mut writers) = ctx.stream_writers.lock() {
+                    writers.insert(src, Box::new(writer_clone));
                 }
 
-                if let Ok(mut guard) = shared_socket.lock() {
-                    *guard = None;
-                }
-            });
-        }
-    }
+                let is_running_inner = ctx.is_running.clone();
+                let ctx_inner = ctx.clone();
+                ctx.report_connected(src);
+                ctx.publish();
 
-    pub fn stop(&self) {
-        self.is_running.store(false, Ordering::SeqCst);
-        if let Ok(mut guard) = self.tcp_writer.lock() {
-            *guard = None;
-        }
-    }
-
-    pub fn send_handshake(&self, p: crate::protocol::HandshakePayload) -> bool {
-        let header = 0b0100_0000u8;
-        let mut payload = 0u8;
-        if p.client_current { payload |= 1 << 7; }
-        if p.server_current { payload |= 1 << 6; }
-        if p.client_target  { payload |= 1 << 5; }
-        if p.server_target  { payload |= 1 << 4; }
-
-        if let Ok(mut tcp_guard) = self.tcp_writer.lock() {
-            if let Some(stream) = tcp_guard.as_mut() {
-                let frame_len = 2u16;
-                let mut framed = [0u8; 4];
-                framed[0..2].copy_from_slice(&frame_len.to_le_bytes());
-                framed[2] = header;
-                framed[3] = payload;
-                return stream.write_all(&framed).is_ok();
+                clients.retain(|client| !client.is_finished());
+                clients.push(thread::spawn(move || {
+                    handle_tcp_client(stream, src, is_running_inner, ctx_inner);
+                }));
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(10));
+            }
+            Err(e) => {
+                logging::warn(LogCategory::Transport, format!("TCP accept failed: {}", e));
+                thread::sleep(Duration::from_millis(10));
             }
         }
+    }
 
-        let target = if let Ok(addr_guard) = self.last_client_addr.lock() {
-            *addr_guard
-        } else {
-            None
-        };
+    for client in clients {
+        let _ = client.join();
+    }
+    if let Ok(mut writers) = ctx.stream_writers.lock() {
+        writers.clear();
+    }
+}
 
-        if let (Some(dest), Ok(socket_guard)) = (target, self.socket.lock()) {
-            if let Some(socket) = socket_guard.as_ref() {
-                let packet = [header, payload];
-                return socket.send_to(&packet, dest).is_ok();
+#[cfg(unix)]
+fn serve_unix(listener: UnixListener, path: PathBuf, ctx: InputContext) {
+    let mut clients: Vec<JoinHandle<()>> = Vec::new();
+    while ctx.is_running.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let peer = match unix_peer(&ctx) {
+                    Some(peer) => peer,
+                    None => {
+                        logging::warn(LogCategory::Transport, "No free placeholder address for a Unix client");
+                        continue;
+                    }
+                };
+                if let Ok(mut addr_guard) = ctx.last_client_addr.lock() {
+                    *addr_guard = Some(peer);
+                }
+                if let Err(e) = stream.set_nonblocking(false)
+                    .and_then(|_| stream.set_read_timeout(Some(Duration::from_millis(100))))
+                {
+                    logging::warn(LogCategory::Transport, format!("Failed to configure Unix client {}: {}", peer, e));
+                    continue;
+                }
+                let writer_clone = match stream.try_clone() {
+                    Ok(c) => c,
+                    Err(e) => {
+                        logging::warn(LogCategory::Transport, format!("Failed to clone stream for {}: {}", peer, e));
+                        continue;
+                    }
+                };
+                if let Ok(mut writers) = ctx.stream_writers.lock() {
+                    writers.insert(peer, Box::new(writer_clone));
+                }
+
+                let is_running_inner = ctx.is_running.clone();
+                let ctx_inner = ctx.clone();
+                ctx.report_connected(peer);
+                ctx.publish();
+
+                clients.retain(|client| !client.is_finished());
+                clients.push(thread::spawn(move || {
+                    handle_stream_client(stream, peer, "unix", is_running_inner, ctx_inner);
+                }));
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(10));
+            }
+            Err(e) => {
+                logging::warn(LogCategory::Transport, format!("Unix socket accept failed: {}", e));
+                thread::sleep(Duration::from_millis(10));
             }
         }
+    }
 
-        false
+    for client in clients {
+        let _ = client.join();
+    }
+    if let Ok(mut writers) = ctx.stream_writers.lock() {
+        writers.clear();
+    }
+    if let Err(e) = std::fs::remove_file(&path) {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            logging::warn(LogCategory::Transport, format!("Failed to remove {}: {}", path.display(), e));
+        }
     }
 }
 
-fn handle_tcp_client(
-    stream: TcpStream,
+/// Reads u16 length-prefixed frames until the client closes or the server stops.
+fn handle_stream_client(
+    mut stream: impl Read,
+    peer: SocketAddr,
+    transport: &'static str,
     is_running: Arc<AtomicBool>,
-    is_active: Arc<AtomicBool>,
+    ctx: InputContext,
 ) {
-    stream.set_read_timeout(Some(Duration::from_millis(100))).unwrap();
-
     let mut raw_buf = [0u8; 1024];
-    let mut reassembly: Vec<u8> = Vec::with_capacity(256);
+    let config = ctx.framing.lock().map(|config| *config).unwrap_or_default();
+    let mut decoder = FrameDecoder::new(config);
+    let mut control_decoder = DeltaDecoder::new();
     let mut last_button_time: Option<Instant> = None;
-    let mut last_card_time: Option<Instant> = None;
     let ttl_duration = Duration::from_millis(100);
+    ctx.update_connection(peer, transport, true, decoder.stats());
 
-    let mut stream = stream;
-
-    while is_running.load(Ordering::SeqCst) {
+    'read: while is_running.load(Ordering::SeqCst) {
         match stream.read(&mut raw_buf) {
             Ok(0) => break,
             Ok(n) => {
-                reassembly.extend_from_slice(&raw_buf[..n]);
+                let received = Instant::now();
+                decoder.push(&raw_buf[..n]);
+                let before = decoder.stats();
 
                 loop {
-                    if reassembly.len() < 2 { break; }
-                    let frame_len = u16::from_le_bytes([reassembly[0], reassembly[1]]) as usize;
-                    if reassembly.len() < 2 + frame_len { break; }
-
-                    let frame: Vec<u8> = reassembly[2..2 + frame_len].to_vec();
-                    reassembly.drain(..2 + frame_len);
-
-                    if !frame.is_empty() {
-                        process_packet(&frame, &is_active, &mut last_button_time, &mut last_card_time);
+                    match decoder.next_frame() {
+                        Ok(Some(frame)) => {
+                            process_packet(
+                                &frame,
+                                peer,
+                                (transport, received),
+                                &ctx,
+                                &mut control_decoder,
+                                &mut last_button_time,
+                            );
+                        }
+                        Ok(None) => break,
+                        Err(e) => {
+                            ctx.report_error(LogCategory::Transport, format!("Dropping {}: {}", peer, e));
+                            break 'read;
+                        }
                     }
                 }
+
+                let after = decoder.stats();
+                if after.resyncs > before.resyncs {
+                    logging::warn(
+                        LogCategory::Transport,
+                        format!("Resyncing stream from {} ({} bytes discarded so far)", peer, after.discarded_bytes),
+                    );
+                }
+                ctx.update_connection(peer, transport, true, after);
             }
             Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut
                 || e.kind() == std::io::ErrorKind::WouldBlock => {}
-            Err(_) => break,
+            Err(e) => {
+                logging::warn(LogCategory::Transport, format!("Read from {} failed: {}", peer, e));
+                break;
+            }
         }
 
-        tick_ttl(&mut last_button_time, &mut last_card_time, ttl_duration);
+        tick_ttl(&ctx, &mut last_button_time, ttl_duration);
+    }
+
+    if let Ok(mut writers) = ctx.stream_writers.lock() {
+        writers.remove(&peer);
+    }
+    ctx.update_connection(peer, transport, false, decoder.stats());
+    logging::info(LogCategory::Connection, format!("Client {} disconnected", peer));
+    ctx.events.publish(ServerEvent::Disconnected { client: peer.to_string() });
+    ctx.drop_client(peer);
+}
+
+fn queue_client_coin(ctx: &InputContext, src: SocketAddr) {
+    if let Err(e) = ctx.queue_coins(1) {
+        logging::warn(LogCategory::Protocol, format!("Dropped coin from {}: {}", src, e));
     }
 }
 
+/// `origin` is the transport label and the time the packet was read off the socket;
+/// `control_decoder` and `last_button_time` are per connection, or per socket for UDP.
 fn process_packet(
     raw: &[u8],
-    is_active: &AtomicBool,
+    src: SocketAddr,
+    origin: (&'static str, Instant),
+    ctx: &InputContext,
+    control_decoder: &mut DeltaDecoder,
     last_button_time: &mut Option<Instant>,
-    last_card_time: &mut Option<Instant>,
 ) {
     if raw.is_empty() { return; }
 
@@ -252,58 +1471,97 @@ fn process_packet(
 
     let header = match ProtocolParser::parse_header(header_byte) {
         Some(h) => h,
-        None => return,
+        None => {
+            logging::warn(LogCategory::Protocol, format!("Unknown header {:#04x} from {}", header_byte, src));
+            return;
+        }
     };
 
+    ctx.touch_client(src);
+
+    if header.packet_type == PacketType::Handshake && header.flags & HANDSHAKE_FLAG_KEEPALIVE != 0 {
+        if !header.is_server {
+            if let Ok(mut liveness) = ctx.liveness.lock() {
+                liveness.enable_keepalive(src);
+            }
+            let seq = payload.first().copied().unwrap_or(0);
+            ctx.send_packet(&ProtocolParser::build_keepalive(seq), Some(src));
+        }
+        return;
+    }
+
     if header.packet_type == PacketType::Handshake {
         if !payload.is_empty() {
             let incoming = ProtocolParser::parse_handshake(payload[0]);
-            crate::api::handle_handshake(incoming);
+            ctx.handle_handshake(Some(src), incoming);
         }
         return;
     }
 
-    if !is_active.load(Ordering::SeqCst) { return; }
+    // Acks and resyncs reuse the batch and timestamp bits, so only client control packets are read.
+    if header.packet_type == PacketType::Control && header.is_server { return; }
+
+    if !ctx.is_active.load(Ordering::SeqCst) { return; }
+
+    if header.packet_type != PacketType::Card {
+        ctx.release_card_on_packet();
+    }
 
     match header.packet_type {
         PacketType::Button => {
-            let mask = if !payload.is_empty() { payload[0] } else { 0 };
-            let coin    = (mask & 0x01 != 0) as u8;
-            let service = (mask & 0x02 != 0) as u8;
-            let test    = (mask & 0x04 != 0) as u8;
-
-            if coin != 0 || service != 0 || test != 0 {
-                if let Ok(lock) = GLOBAL_SHMEM.lock() {
-                    if let Some(manager) = lock.as_ref() {
-                        manager.write_status(coin, service, test);
-                    }
+            let button = ProtocolParser::parse_button(header.flags, payload);
+            let coin_pressed = button.mask & BUTTON_COIN != 0;
+            if button.stateful {
+                if ctx.coin_queue.client_edge(src, coin_pressed) {
+                    queue_client_coin(ctx, src);
+                }
+                ctx.apply_buttons(button.mask);
+                *last_button_time = None;
+            } else if button.mask != 0 {
+                if coin_pressed {
+                    queue_client_coin(ctx, src);
+                }
+                if button.mask & !BUTTON_COIN != 0 {
+                    ctx.apply_buttons(button.mask);
+                    *last_button_time = Some(Instant::now());
                 }
-                report_to_flutter(vec![0; 6], vec![0; 32], coin, service, test, [0u8; 10]);
-                *last_button_time = Some(Instant::now());
             }
         }
         PacketType::Control => {
-            if let Some(ctrl) = ProtocolParser::parse_control(payload) {
-                if let Ok(lock) = GLOBAL_SHMEM.lock() {
-                    if let Some(manager) = lock.as_ref() {
-                        manager.write_data(&ctrl.air, &ctrl.slider);
-                    }
+            let (client_us, payload) = match ProtocolParser::split_timestamp(header.flags, payload) {
+                Ok(split) => split,
+                Err(e) => {
+                    logging::warn(LogCategory::Protocol, format!("Malformed control packet from {}: {}", src, e));
+                    return;
                 }
-                report_to_flutter(ctrl.air.to_vec(), ctrl.slider.to_vec(), 0, 0, 0, [0u8; 10]);
+            };
+            let (transport, received) = origin;
+            let mark = match ctx.latency.lock() {
+                Ok(mut latency) => Some(latency.mark(transport, src, received, client_us)),
+                Err(_) => None,
+            };
+            if header.flags & CONTROL_FLAG_BATCH != 0 {
+                match ProtocolParser::parse_control_batch(payload) {
+                    Ok(samples) => ctx.queue_samples(Some(src.ip()), &samples, mark),
+                    Err(e) => logging::warn(
+                        LogCategory::Protocol,
+                        format!("Malformed control batch from {}: {}", src, e),
+                    ),
+                }
+                return;
+            }
+            match ProtocolParser::parse_control_frame(header.flags, payload) {
+                Ok(frame) => ctx.apply_control_frame(control_decoder, src, frame, mark),
+                Err(e) => logging::warn(
+                    LogCategory::Protocol,
+                    format!("Malformed control packet from {}: {}", src, e),
+                ),
             }
         }
         PacketType::Card => {
-            if payload.len() >= 10 {
-                let raw_bcd = &payload[0..10];
-                if let Ok(lock) = GLOBAL_SHMEM.lock() {
-                    if let Some(manager) = lock.as_ref() {
-                        manager.write_card_raw(raw_bcd);
-                    }
-                }
-                if let Some(code) = ProtocolParser::parse_card(raw_bcd) {
-                    report_to_flutter(vec![0; 6], vec![0; 32], 0, 0, 0, code);
-                }
-                *last_card_time = Some(Instant::now());
+            match ProtocolParser::parse_card_id(header.flags, payload) {
+                Ok(card) => ctx.present_card(card),
+                Err(e) => ctx.report_error(LogCategory::Protocol, format!("Bad card packet from {}: {}", src, e)),
             }
         }
         _ => {}
@@ -311,31 +1569,367 @@ fn process_packet(
 }
 
 fn tick_ttl(
+    ctx: &InputContext,
     last_button_time: &mut Option<Instant>,
-    last_card_time: &mut Option<Instant>,
     ttl_duration: Duration,
 ) {
     if let Some(time) = *last_button_time {
         if time.elapsed() >= ttl_duration {
-            if let Ok(lock) = GLOBAL_SHMEM.lock() {
-                if let Some(manager) = lock.as_ref() {
-                    manager.write_status(0, 0, 0);
-                }
-            }
-            report_to_flutter(vec![0; 6], vec![0; 32], 0, 0, 0, [0u8; 10]);
+            ctx.apply_buttons(0);
             *last_button_time = None;
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::CONTROL_FLAG_TIMESTAMP;
+    use crate::shmem::ShmemSnapshot;
+
+    fn server(name: &str) -> SensorServer {
+        let server = SensorServer::with_config(ServerConfig {
+            name: name.to_string(),
+            shmem_name: format!("rustnithm_test_{}_{}", name, std::process::id()),
+            ..ServerConfig::default()
+        });
+        server.init_shmem().unwrap();
+        server
+    }
 
-    if let Some(time) = *last_card_time {
-        if time.elapsed() >= ttl_duration {
-            if let Ok(lock) = GLOBAL_SHMEM.lock() {
-                if let Some(manager) = lock.as_ref() {
-                    manager.write_card_raw(&[]);
-                }
-            }
-            report_to_flutter(vec![0; 6], vec![0; 32], 0, 0, 0, [0u8; 10]);
-            *last_card_time = None;
+    fn shmem(server: &SensorServer) -> ShmemSnapshot {
+        let bytes = server.ctx.with_shmem(|manager| manager.read_bytes()).unwrap();
+        ShmemSnapshot::decode(&bytes)
+    }
+
+    #[test]
+    fn inserted_card_is_removed_after_its_hold() {
+        let server = server("insert_card");
+        let card = CardId::access_code_from_digits("01234567890123456789").unwrap();
+        server.insert_card(card, Duration::from_millis(30));
+        assert_eq!(shmem(&server).card, Some(card.to_string()));
+        assert_eq!(server.snapshot().code, card.slot_bytes());
+
+        thread::sleep(Duration::from_millis(150));
+        assert!(!shmem(&server).card_present);
+        assert_eq!(server.snapshot().code, [0; 10]);
+    }
+
+    #[test]
+    fn reinserting_extends_the_hold() {
+        let server = server("reinsert_card");
+        let first = CardId::access_code_from_digits("11111111111111111111").unwrap();
+        let second = CardId::mifare(&[1, 2, 3, 4]).unwrap();
+        server.insert_card(first, Duration::from_millis(30));
+        server.insert_card(second, Duration::from_millis(300));
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(shmem(&server).card, Some(second.to_string()));
+    }
+
+    fn access_code() -> CardId {
+        CardId::access_code_from_digits("01234567890123456789").unwrap()
+    }
+
+    #[test]
+    fn fixed_hold_expires_without_a_transport() {
+        let server = server("fixed_hold");
+        server.set_card_mode(CardMode::FixedHold { hold_ms: 60 }).unwrap();
+        server.ctx.present_card(access_code());
+        thread::sleep(Duration::from_millis(30));
+        assert!(shmem(&server).card_present);
+        thread::sleep(Duration::from_millis(80));
+        assert!(!shmem(&server).card_present);
+    }
+
+    #[test]
+    fn until_ack_releases_on_the_acknowledge_byte() {
+        let server = server("until_ack");
+        server.set_card_mode(CardMode::UntilAck { timeout_ms: 5000 }).unwrap();
+        server.ctx.present_card(access_code());
+        assert!(!shmem(&server).card_ack);
+        thread::sleep(Duration::from_millis(30));
+        assert!(shmem(&server).card_present);
+
+        server.ctx.with_shmem(|manager| manager.write_card_ack(true));
+        thread::sleep(Duration::from_millis(50));
+        assert!(!shmem(&server).card_present);
+    }
+
+    #[test]
+    fn toggle_removes_on_the_second_tap() {
+        let server = server("toggle");
+        server.set_card_mode(CardMode::Toggle).unwrap();
+        server.ctx.present_card(access_code());
+        thread::sleep(Duration::from_millis(150));
+        assert!(shmem(&server).card_present);
+        server.ctx.present_card(access_code());
+        assert!(!shmem(&server).card_present);
+    }
+
+    #[test]
+    fn until_next_packet_releases_on_any_other_packet() {
+        let server = server("next_packet");
+        server.set_active(true);
+        server.set_card_mode(CardMode::UntilNextPacket).unwrap();
+        server.ctx.present_card(access_code());
+        thread::sleep(Duration::from_millis(150));
+        assert!(shmem(&server).card_present);
+
+        let src = SocketAddr::from(([127, 0, 0, 1], 40000));
+        process_packet(&[0x10, 0], src, ("udp", Instant::now()), &server.ctx, &mut DeltaDecoder::new(), &mut None);
+        assert!(!shmem(&server).card_present);
+    }
+
+    fn packet(server: &SensorServer, raw: &[u8], last_button_time: &mut Option<Instant>) {
+        let src = SocketAddr::from(([127, 0, 0, 1], 40001));
+        process_packet(raw, src, ("udp", Instant::now()), &server.ctx, &mut DeltaDecoder::new(), last_button_time);
+    }
+
+    #[test]
+    fn stateful_buttons_hold_until_released() {
+        use crate::protocol::{BUTTON_FLAG_STATEFUL, BUTTON_SERVICE, BUTTON_UP};
+        let server = server("stateful_buttons");
+        server.set_active(true);
+        let mut last_button_time = None;
+        packet(&server, &[0x10 | BUTTON_FLAG_STATEFUL, BUTTON_SERVICE | BUTTON_UP], &mut last_button_time);
+        tick_ttl(&server.ctx, &mut last_button_time, Duration::ZERO);
+
+        let state = shmem(&server);
+        assert_eq!((state.service, state.test, state.extra_buttons), (1, 0, BUTTON_UP >> 3));
+        assert_eq!(server.snapshot().buttons, BUTTON_SERVICE | BUTTON_UP);
+
+        packet(&server, &[0x10 | BUTTON_FLAG_STATEFUL, BUTTON_UP], &mut last_button_time);
+        assert_eq!(server.snapshot().buttons, BUTTON_UP);
+        packet(&server, &[0x10 | BUTTON_FLAG_STATEFUL, 0], &mut last_button_time);
+        let state = shmem(&server);
+        assert_eq!((state.service, state.extra_buttons), (0, 0));
+    }
+
+    #[test]
+    fn legacy_button_pulse_expires_after_the_ttl() {
+        use crate::protocol::BUTTON_TEST;
+        let server = server("legacy_buttons");
+        server.set_active(true);
+        let mut last_button_time = None;
+        packet(&server, &[0x10, BUTTON_TEST], &mut last_button_time);
+        assert!(last_button_time.is_some());
+        assert_eq!(shmem(&server).test, 1);
+
+        let ttl = Duration::from_millis(20);
+        tick_ttl(&server.ctx, &mut last_button_time, ttl);
+        assert_eq!(shmem(&server).test, 1);
+        thread::sleep(ttl);
+        tick_ttl(&server.ctx, &mut last_button_time, ttl);
+        assert_eq!(shmem(&server).test, 0);
+        assert!(last_button_time.is_none());
+    }
+
+    #[test]
+    fn inactive_server_ignores_buttons() {
+        use crate::protocol::{BUTTON_FLAG_STATEFUL, BUTTON_TEST};
+        let server = server("inactive_buttons");
+        packet(&server, &[0x10 | BUTTON_FLAG_STATEFUL, BUTTON_TEST], &mut None);
+        assert_eq!(shmem(&server).test, 0);
+    }
+
+    #[test]
+    fn credits_play_as_press_release_cycles() {
+        let server = server("coin_cycles");
+        server.set_coin_cadence(CoinCadence { press_ms: 40, release_ms: 40 }).unwrap();
+        server.insert_credits(2).unwrap();
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(shmem(&server).coin, 1);
+        assert_eq!(server.pending_credits(), 1);
+        thread::sleep(Duration::from_millis(40));
+        assert_eq!(shmem(&server).coin, 0);
+        thread::sleep(Duration::from_millis(40));
+        assert_eq!(shmem(&server).coin, 1);
+        assert_eq!(server.pending_credits(), 0);
+        thread::sleep(Duration::from_millis(80));
+        assert_eq!(shmem(&server).coin, 0);
+    }
+
+    #[test]
+    fn credits_beyond_the_limit_are_rejected() {
+        let server = server("coin_limit");
+        server.set_coin_cadence(CoinCadence { press_ms: 1000, release_ms: 1000 }).unwrap();
+        assert!(server.insert_credits(MAX_CREDITS + 1).is_err());
+        server.insert_credits(MAX_CREDITS).unwrap();
+        assert!(server.insert_credits(2).is_err());
+        assert!(server.pending_credits() >= MAX_CREDITS - 1);
+        server.stop();
+    }
+
+    fn held_service(name: &str, keepalive: bool) -> SensorServer {
+        use crate::protocol::{BUTTON_FLAG_STATEFUL, BUTTON_SERVICE};
+        let server = server(name);
+        server.set_active(true);
+        server.set_liveness_config(LivenessConfig { stale_ms: 10, lost_ms: 30, ..LivenessConfig::default() }).unwrap();
+        let src = SocketAddr::from(([127, 0, 0, 1], 40001));
+        server.set_last_client(src);
+        if keepalive {
+            packet(&server, &[HANDSHAKE_FLAG_KEEPALIVE, 1], &mut None);
         }
+        packet(&server, &[0x10 | BUTTON_FLAG_STATEFUL, BUTTON_SERVICE], &mut None);
+        assert_eq!(shmem(&server).service, 1);
+        server
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn lost_keepalive_client_releases_its_inputs() {
+        let server = held_service("keepalive_lost", true);
+        thread::sleep(Duration::from_millis(40));
+        server.ctx.tick_liveness();
+        assert_eq!(server.link_state(), LinkState::Lost);
+        assert_eq!(shmem(&server).service, 0);
+    }
+
+    #[test]
+    fn quiet_client_without_keepalives_keeps_its_inputs() {
+        let server = held_service("keepalive_none", false);
+        thread::sleep(Duration::from_millis(40));
+        server.ctx.tick_liveness();
+        assert_eq!(server.link_state(), LinkState::Stale);
+        assert_eq!(shmem(&server).service, 1);
+    }
+
+    #[test]
+    fn air_gesture_releases_without_a_transport() {
+        let server = server("air_release");
+        server.set_air_gesture(AirGestureConfig { enabled: true, rise_ms: 30, hold_ms: 30, ..AirGestureConfig::default() }).unwrap();
+        let mut slider = [0u8; 32];
+        slider[1] = 1;
+        server.ctx.map_control(None, &[0; 6], &slider, None);
+        slider[0] = 1;
+        server.ctx.map_control(None, &[0; 6], &slider, None);
+        assert_eq!(shmem(&server).air, [1, 0, 0, 0, 0, 0]);
+
+        thread::sleep(Duration::from_millis(45));
+        assert_eq!(shmem(&server).air, [1; 6]);
+        thread::sleep(Duration::from_millis(40));
+        assert_eq!(shmem(&server).air, [0; 6]);
+        assert_eq!(server.snapshot().air, [0; 6]);
+    }
+
+    #[test]
+    fn filtered_release_lands_without_a_transport() {
+        let server = server("filter_release");
+        let config = DebounceConfig { enabled: true, min_press_ms: 30, ..DebounceConfig::default() };
+        server.set_filter(FilterTarget::Air, config).unwrap();
+        server.ctx.apply_control(None, &[1, 0, 0, 0, 0, 0], &[0; 32], None);
+        server.ctx.apply_control(None, &[0; 6], &[0; 32], None);
+        assert_eq!(shmem(&server).air, [1, 0, 0, 0, 0, 0]);
+
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(shmem(&server).air, [0; 6]);
+        assert!(!server.ctx.filter_watched.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn server_resync_is_not_read_as_a_timestamped_frame() {
+        let server = server("resync_echo");
+        server.ctx.is_active.store(true, Ordering::SeqCst);
+        let src = SocketAddr::from(([127, 0, 0, 1], 9000));
+        let mut packet = ProtocolParser::build_control_resync(1).to_vec();
+        packet.extend_from_slice(&[0, 0, 0, 0x3F, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert!(ProtocolParser::parse_header(packet[0]).is_some_and(|h| h.flags & CONTROL_FLAG_TIMESTAMP != 0));
+
+        let mut decoder = DeltaDecoder::new();
+        process_packet(&packet, src, ("udp", Instant::now()), &server.ctx, &mut decoder, &mut None);
+        assert_eq!(shmem(&server).air, [0; 6]);
+        assert!(server.latency_stats().is_empty());
+    }
+
+    #[cfg(unix)]
+    fn socket_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rustnithm-test-{}-{}.sock", name, std::process::id()))
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn only_stale_sockets_are_removed() {
+        let missing = socket_path("missing");
+        assert_eq!(remove_stale_socket(&missing), Ok(()));
+
+        let file = socket_path("regular");
+        std::fs::write(&file, b"not a socket").unwrap();
+        assert!(remove_stale_socket(&file).is_err());
+        assert!(file.exists());
+        std::fs::remove_file(&file).unwrap();
+
+        let live = socket_path("live");
+        let _ = std::fs::remove_file(&live);
+        let listener = UnixListener::bind(&live).unwrap();
+        assert!(remove_stale_socket(&live).is_err());
+        assert!(live.exists());
+
+        drop(listener);
+        assert_eq!(remove_stale_socket(&live), Ok(()));
+        assert!(!live.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unix_clients_exchange_framed_packets() {
+        let path = socket_path("round_trip");
+        let server = SensorServer::with_config(ServerConfig {
+            name: "unix_round_trip".to_string(),
+            protocol: "unix".to_string(),
+            socket_path: Some(path.to_string_lossy().into_owned()),
+            shmem_name: format!("rustnithm_test_unix_round_trip_{}", std::process::id()),
+            ..ServerConfig::default()
+        });
+        server.start_configured().unwrap();
+        assert_eq!(server.bound_port(), None);
+
+        let mut client = UnixStream::connect(&path).unwrap();
+        client.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+        client.write_all(&[2, 0, 0x00, 0b0010_0000]).unwrap();
+        let mut reply = [0u8; 4];
+        client.read_exact(&mut reply).unwrap();
+        assert_eq!(reply, [2, 0, 0x40, 0b0111_0000]);
+        assert!(server.is_active_status());
+
+        drop(client);
+        server.stop();
+        assert!(!path.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unix_peers_skip_zero_and_open_connections_when_wrapping() {
+        let server = server("unix_peers");
+        let held = SocketAddr::from(([127, 0, 0, 1], 1));
+        server.ctx.stream_writers.lock().unwrap().insert(held, Box::new(std::io::sink()));
+        server.ctx.next_unix_peer.store(u16::MAX, Ordering::SeqCst);
+
+        assert_eq!(unix_peer(&server.ctx).map(|peer| peer.port()), Some(u16::MAX));
+        assert_eq!(unix_peer(&server.ctx).map(|peer| peer.port()), Some(2));
+    }
+
+    #[test]
+    fn stop_closes_client_connections_before_returning() {
+        let server = SensorServer::with_config(ServerConfig {
+            name: "stop_clients".to_string(),
+            protocol: "tcp".to_string(),
+            shmem_name: format!("rustnithm_test_stop_clients_{}", std::process::id()),
+            ..ServerConfig::default()
+        });
+        server.start_configured().unwrap();
+        let port = server.bound_port().unwrap();
+        let mut client = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        client.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+        client.write_all(&[2, 0, 0x00, 0b0010_0000]).unwrap();
+        let mut reply = [0u8; 4];
+        client.read_exact(&mut reply).unwrap();
+
+        server.stop();
+        client.set_read_timeout(Some(Duration::from_millis(20))).unwrap();
+        assert_eq!(client.read(&mut reply).unwrap(), 0);
+
+        server.start_configured().unwrap();
+        thread::sleep(Duration::from_millis(150));
+        assert!(server.connection_stats().is_empty());
+        server.stop();
+    }
+}
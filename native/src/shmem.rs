@@ -1,7 +1,8 @@
-use std::sync::Mutex;
 use shared_memory::*;
 use std::slice;
-use std::sync::LazyLock;
+use serde::Serialize;
+use rustnithm_reader::layout;
+use crate::card::{CardId, CardKind};
 
 pub struct ShmemManager {
     shmem: Shmem,
@@ -20,16 +21,32 @@ impl ShmemManager {
         Ok(Self { shmem })
     }
 
+    /// Opens an existing mapping without creating it.
+    pub fn open_existing(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let shmem = ShmemConf::new().os_id(path).open()?;
+        Ok(Self { shmem })
+    }
+
+    pub fn read_bytes(&self) -> Vec<u8> {
+        let len = self.shmem.len();
+        let ptr = self.shmem.as_ptr();
+        unsafe { slice::from_raw_parts(ptr, len) }.to_vec()
+    }
+
+    pub fn read_leds(&self) -> Vec<[u8; 3]> {
+        ShmemSnapshot::decode(&self.read_bytes()).leds
+    }
+
     pub fn write_data(&self, air: &[u8], slider: &[u8]) {
         let len = self.shmem.len();
         let ptr = self.shmem.as_ptr();
         let data_slice = unsafe { slice::from_raw_parts_mut(ptr, len) };
 
-        if air.len() >= 6 && data_slice.len() >= 6 {
-            data_slice[0..6].copy_from_slice(&air[0..6]);
+        if air.len() >= 6 && data_slice.len() >= layout::AIR.end {
+            data_slice[layout::AIR].copy_from_slice(&air[0..6]);
         }
-        if slider.len() >= 32 && data_slice.len() >= 38 {
-            data_slice[6..38].copy_from_slice(&slider[0..32]);
+        if slider.len() >= 32 && data_slice.len() >= layout::SLIDER.end {
+            data_slice[layout::SLIDER].copy_from_slice(&slider[0..32]);
         }
     }
 
@@ -38,46 +55,201 @@ impl ShmemManager {
         let ptr = self.shmem.as_ptr();
         let data_slice = unsafe { slice::from_raw_parts_mut(ptr, len) };
 
-        if data_slice.len() < 137 { return; }
-        data_slice[134] = test;
-        data_slice[135] = service;
-        data_slice[136] = coin;
+        if data_slice.len() <= layout::COIN { return; }
+        data_slice[layout::TEST] = test;
+        data_slice[layout::SERVICE] = service;
+        data_slice[layout::COIN] = coin;
+    }
+
+    /// Writes test/service/coin to 134..137 and the remaining operator
+    /// buttons (mask bits 3..7) as a bitmask in 137.
+    pub fn write_buttons(&self, mask: u8) {
+        use crate::protocol::{BUTTON_COIN, BUTTON_SERVICE, BUTTON_TEST};
+        self.write_status(
+            (mask & BUTTON_COIN != 0) as u8,
+            (mask & BUTTON_SERVICE != 0) as u8,
+            (mask & BUTTON_TEST != 0) as u8,
+        );
+
+        let len = self.shmem.len();
+        let ptr = self.shmem.as_ptr();
+        let data_slice = unsafe { slice::from_raw_parts_mut(ptr, len) };
+        if data_slice.len() <= layout::EXTRA_BUTTONS { return; }
+        data_slice[layout::EXTRA_BUTTONS] = mask >> 3;
     }
 
-    pub fn write_card_raw(&self, raw_bcd: &[u8]) {
+    /// The only writer of the card region described in `rustnithm_reader::layout`.
+    /// `raw` is `CardId::to_bytes()` for `kind`; an empty `raw` clears the slot, while
+    /// an all-zero card is still present.
+    pub fn write_card_raw(&self, kind: CardKind, raw: &[u8]) {
         let len = self.shmem.len();
         let ptr = self.shmem.as_ptr();
         let data_slice = unsafe { slice::from_raw_parts_mut(ptr, len) };
-        if data_slice.len() < 150 { return; }
-        let is_empty = raw_bcd.is_empty() || raw_bcd.iter().all(|&x| x == 0);
-
-        if is_empty {
-            data_slice[138] = 0;
-            data_slice[140..150].fill(0);
-        } else {
-            let copy_len = std::cmp::min(raw_bcd.len(), 10);
-            data_slice[140..140 + copy_len].copy_from_slice(&raw_bcd[..copy_len]);
-            data_slice[138] = 1;
+        if data_slice.len() <= layout::CARD_LEN { return; }
+
+        data_slice[layout::CARD_ID].fill(0);
+        data_slice[layout::CARD_PMM.start..=layout::CARD_LEN].fill(0);
+        if raw.is_empty() {
+            data_slice[layout::CARD_PRESENT] = 0;
+            data_slice[layout::CARD_KIND] = 0;
+            return;
+        }
+        // FeliCa carries IDm then PMm; the other kinds are just the identifier.
+        let id_len = match kind {
+            CardKind::Felica => std::cmp::min(raw.len(), 8),
+            _ => std::cmp::min(raw.len(), layout::CARD_ID.len()),
+        };
+        let start = layout::CARD_ID.start;
+        data_slice[start..start + id_len].copy_from_slice(&raw[..id_len]);
+        if kind == CardKind::Felica && raw.len() > 8 {
+            let pmm = &raw[8..std::cmp::min(raw.len(), 16)];
+            data_slice[layout::CARD_PMM.start..layout::CARD_PMM.start + pmm.len()].copy_from_slice(pmm);
         }
+        data_slice[layout::CARD_LEN] = id_len as u8;
+        data_slice[layout::CARD_KIND] = kind.marker();
+        data_slice[layout::CARD_PRESENT] = 1;
+    }
+
+    pub fn write_card_ack(&self, acked: bool) {
+        let len = self.shmem.len();
+        let ptr = self.shmem.as_ptr();
+        let data_slice = unsafe { slice::from_raw_parts_mut(ptr, len) };
+        if data_slice.len() < layout::MIN_SIZE { return; }
+        data_slice[layout::CARD_ACK] = acked as u8;
+    }
+
+    pub fn read_card_ack(&self) -> bool {
+        let len = self.shmem.len();
+        let ptr = self.shmem.as_ptr();
+        let data_slice = unsafe { slice::from_raw_parts(ptr, len) };
+        data_slice.len() >= layout::MIN_SIZE && data_slice[layout::CARD_ACK] != 0
     }
 }
 
-pub static GLOBAL_SHMEM: LazyLock<Mutex<Option<ShmemManager>>> = LazyLock::new(|| {
-    Mutex::new(None)
-});
-
-pub fn init_shmem() -> Result<(), String> {
-    let mut lock = GLOBAL_SHMEM.lock().map_err(|_| "Failed to lock GLOBAL_SHMEM")?;
-    if lock.is_none() {
-        match ShmemManager::new("RustnithmSharedMemory", 1024) {
-            Ok(manager) => {
-                manager.write_card_raw(&[]);
-                *lock = Some(manager);
-                Ok(())
-            }
-            Err(e) => Err(format!("Shmem Init Error: {}", e)),
+pub const DEFAULT_SHMEM_NAME: &str = layout::DEFAULT_NAME;
+pub const DEFAULT_SHMEM_SIZE: usize = layout::DEFAULT_SIZE;
+
+pub fn open_shmem(name: &str, size: usize) -> Result<ShmemManager, String> {
+    match ShmemManager::new(name, size) {
+        Ok(manager) => {
+            manager.write_card_raw(CardKind::AccessCode, &[]);
+            Ok(manager)
+        }
+        Err(e) => Err(format!("Shmem Init Error: {}", e)),
+    }
+}
+
+/// Decoded view of the mapping, used by the inspector tool.
+#[derive(Serialize, PartialEq, Clone, Debug)]
+pub struct ShmemSnapshot {
+    pub air: [u8; 6],
+    pub slider: [u8; 32],
+    pub leds: Vec<[u8; 3]>,
+    pub test: u8,
+    pub service: u8,
+    pub coin: u8,
+    pub extra_buttons: u8,
+    pub card_present: bool,
+    pub card_kind: Option<String>,
+    pub card: Option<String>,
+    pub card_ack: bool,
+}
+
+impl ShmemSnapshot {
+    pub fn decode(data: &[u8]) -> Self {
+        let byte = |i: usize| data.get(i).copied().unwrap_or(0);
+        let mut air = [0u8; 6];
+        let mut slider = [0u8; 32];
+        for (i, cell) in air.iter_mut().enumerate() {
+            *cell = byte(layout::AIR.start + i);
+        }
+        for (i, cell) in slider.iter_mut().enumerate() {
+            *cell = byte(layout::SLIDER.start + i);
+        }
+        let leds = (0..layout::LED_COUNT)
+            .map(|i| {
+                let at = layout::LEDS.start + i * 3;
+                [byte(at), byte(at + 1), byte(at + 2)]
+            })
+            .collect();
+
+        let card_present = byte(layout::CARD_PRESENT) != 0;
+        let kind = CardKind::from_marker(byte(layout::CARD_KIND));
+        let card = match kind {
+            Some(kind) if card_present && data.len() > layout::CARD_LEN => Some(match decode_card(kind, data) {
+                Ok(c) => c.to_string(),
+                Err(e) => format!("<invalid: {}>", e),
+            }),
+            _ => None,
+        };
+
+        Self {
+            air,
+            slider,
+            leds,
+            test: byte(layout::TEST),
+            service: byte(layout::SERVICE),
+            coin: byte(layout::COIN),
+            extra_buttons: byte(layout::EXTRA_BUTTONS),
+            card_present,
+            card_kind: kind.map(|k| format!("{:?}", k)),
+            card,
+            card_ack: byte(layout::CARD_ACK) != 0,
         }
-    } else {
-        Ok(())
     }
-}
\ No newline at end of file
+}
+
+fn decode_card(kind: CardKind, data: &[u8]) -> Result<CardId, String> {
+    let id = &data[layout::CARD_ID];
+    let len = std::cmp::min(data[layout::CARD_LEN] as usize, id.len());
+    match kind {
+        CardKind::AccessCode => CardId::access_code_from_bcd(id),
+        CardKind::Felica => CardId::felica(&id[..8], &data[layout::CARD_PMM]),
+        CardKind::Mifare => CardId::mifare(&id[..len]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager(name: &str) -> ShmemManager {
+        open_shmem(&format!("rustnithm_test_{}_{}", name, std::process::id()), DEFAULT_SHMEM_SIZE).unwrap()
+    }
+
+    #[test]
+    fn card_region_round_trips_every_kind() {
+        let shmem = manager("card_kinds");
+        let cards = [
+            CardId::access_code_from_digits("01234567890123456789").unwrap(),
+            CardId::felica(&[1, 2, 3, 4, 5, 6, 7, 8], &[9, 10, 11, 12, 13, 14, 15, 16]).unwrap(),
+            CardId::mifare(&[0xDE, 0xAD, 0xBE, 0xEF]).unwrap(),
+        ];
+        for card in cards {
+            shmem.write_card_raw(card.kind(), &card.to_bytes());
+            let snapshot = ShmemSnapshot::decode(&shmem.read_bytes());
+            assert!(snapshot.card_present);
+            assert_eq!(snapshot.card_kind, Some(format!("{:?}", card.kind())));
+            assert_eq!(snapshot.card, Some(card.to_string()));
+        }
+    }
+
+    #[test]
+    fn empty_card_clears_the_slot() {
+        let shmem = manager("card_clear");
+        let felica = CardId::felica(&[1; 8], &[2; 8]).unwrap();
+        shmem.write_card_raw(felica.kind(), &felica.to_bytes());
+        shmem.write_card_raw(CardKind::AccessCode, &[]);
+        let data = shmem.read_bytes();
+        assert!(data[layout::CARD_PRESENT..=layout::CARD_LEN].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn all_zero_access_code_is_present() {
+        let shmem = manager("card_zero");
+        shmem.write_card_raw(CardKind::AccessCode, &[0; 10]);
+        let snapshot = ShmemSnapshot::decode(&shmem.read_bytes());
+        assert!(snapshot.card_present);
+        assert_eq!(snapshot.card.as_deref(), Some("00000000000000000000"));
+    }
+}
@@ -0,0 +1,141 @@
+use std::net::SocketAddr;
+use crate::card::CardId;
+use crate::protocol::{BUTTON_COIN, BUTTON_SERVICE, BUTTON_TEST};
+
+#[derive(Clone, Debug)]
+pub struct SensorData {
+    pub air: Vec<u8>,
+    pub slider: Vec<u8>,
+    pub coin: u8,
+    pub service: u8,
+    pub test: u8,
+    pub code: [u8; 10],
+    /// Set while a card is held, even one whose identifier is all zeros.
+    pub card_present: bool,
+    pub buttons: u8,
+    pub active: bool,
+    pub client: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+pub struct InputState {
+    pub air: [u8; 6],
+    pub slider: [u8; 32],
+    pub buttons: u8,
+    pub coin_pulse: bool,
+    pub card: Option<CardId>,
+}
+
+impl InputState {
+    pub fn new() -> Self {
+        Self {
+            air: [0u8; 6],
+            slider: [0u8; 32],
+            buttons: 0,
+            coin_pulse: false,
+            card: None,
+        }
+    }
+
+    pub fn set_control(&mut self, air: &[u8; 6], slider: &[u8; 32]) {
+        self.air = *air;
+        self.slider = *slider;
+    }
+
+    pub fn set_buttons(&mut self, coin: u8, service: u8, test: u8) {
+        let mut mask = self.buttons & !(BUTTON_COIN | BUTTON_SERVICE | BUTTON_TEST);
+        if coin != 0 { mask |= BUTTON_COIN; }
+        if service != 0 { mask |= BUTTON_SERVICE; }
+        if test != 0 { mask |= BUTTON_TEST; }
+        self.buttons = mask;
+    }
+
+    pub fn set_button_mask(&mut self, mask: u8) {
+        self.buttons = mask;
+    }
+
+    /// Client-held buttons plus the coin bit driven by the coin queue.
+    pub fn effective_buttons(&self) -> u8 {
+        if self.coin_pulse { self.buttons | BUTTON_COIN } else { self.buttons }
+    }
+
+    pub fn set_card(&mut self, card: CardId) {
+        self.card = Some(card);
+    }
+
+    pub fn clear_card(&mut self) {
+        self.card = None;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    pub fn snapshot(&self, active: bool, client: Option<SocketAddr>) -> SensorData {
+        SensorData {
+            air: self.air.to_vec(),
+            slider: self.slider.to_vec(),
+            coin: (self.effective_buttons() & BUTTON_COIN != 0) as u8,
+            service: (self.buttons & BUTTON_SERVICE != 0) as u8,
+            test: (self.buttons & BUTTON_TEST != 0) as u8,
+            buttons: self.effective_buttons(),
+            code: self.card.map(|c| c.slot_bytes()).unwrap_or([0u8; 10]),
+            card_present: self.card.is_some(),
+            active,
+            client: client.map(|addr| addr.to_string()),
+        }
+    }
+}
+
+impl Default for InputState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::BUTTON_ENTER;
+
+    #[test]
+    fn snapshot_carries_the_full_state() {
+        let mut state = InputState::new();
+        state.set_control(&[1; 6], &[2; 32]);
+        state.set_button_mask(BUTTON_SERVICE | BUTTON_ENTER);
+        state.set_card(CardId::mifare(&[1, 2, 3, 4]).unwrap());
+        let client = SocketAddr::from(([10, 0, 0, 5], 24864));
+
+        let data = state.snapshot(true, Some(client));
+        assert_eq!(data.air, vec![1; 6]);
+        assert_eq!(data.slider, vec![2; 32]);
+        assert_eq!((data.coin, data.service, data.test), (0, 1, 0));
+        assert_eq!(data.buttons, BUTTON_SERVICE | BUTTON_ENTER);
+        assert_eq!(data.code[..4], [1, 2, 3, 4]);
+        assert!(data.card_present);
+        assert!(data.active);
+        assert_eq!(data.client.as_deref(), Some("10.0.0.5:24864"));
+    }
+
+    #[test]
+    fn set_buttons_keeps_the_other_bits() {
+        let mut state = InputState::new();
+        state.set_button_mask(BUTTON_ENTER | BUTTON_TEST);
+        state.set_buttons(1, 0, 0);
+        assert_eq!(state.buttons, BUTTON_ENTER | BUTTON_COIN);
+    }
+
+    #[test]
+    fn coin_pulse_shows_without_a_held_coin() {
+        let mut state = InputState::new();
+        state.coin_pulse = true;
+        let data = state.snapshot(false, None);
+        assert_eq!((data.coin, data.buttons), (1, BUTTON_COIN));
+        assert_eq!(state.buttons, 0);
+
+        state.clear();
+        let data = state.snapshot(false, None);
+        assert_eq!((data.coin, data.code, data.client), (0, [0; 10], None));
+        assert!(!data.card_present);
+    }
+}
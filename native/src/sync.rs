@@ -0,0 +1,253 @@
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use crate::protocol::HandshakePayload;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct SyncConfig {
+    /// Interval between handshake retransmissions over UDP.
+    pub retransmit_ms: u32,
+    /// Time to wait for the client before rolling back.
+    pub timeout_ms: u32,
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            retransmit_ms: 100,
+            timeout_ms: 2000,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SyncState {
+    Idle,
+    Pending { target: bool, previous: bool, attempts: u32 },
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum SyncOutcome {
+    Confirmed { active: bool, attempts: u32 },
+    /// The client never acknowledged; `active` is the state rolled back to.
+    TimedOut { active: bool, attempts: u32 },
+    /// The client answered with a different target, which wins.
+    Overridden { active: bool },
+}
+
+/// Server-initiated activation change, confirmed by the client's next handshake.
+pub struct SyncMachine {
+    config: SyncConfig,
+    state: SyncState,
+    /// Client the pending request went to; `None` when there was no client to send it to.
+    peer: Option<SocketAddr>,
+    started: Option<Instant>,
+    last_sent: Option<Instant>,
+}
+
+impl SyncMachine {
+    pub fn new(config: SyncConfig) -> Self {
+        Self {
+            config,
+            state: SyncState::Idle,
+            peer: None,
+            started: None,
+            last_sent: None,
+        }
+    }
+
+    pub fn config(&self) -> SyncConfig {
+        self.config
+    }
+
+    pub fn set_config(&mut self, config: SyncConfig) {
+        self.config = config;
+    }
+
+    pub fn state(&self) -> SyncState {
+        self.state
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.state != SyncState::Idle
+    }
+
+    /// Client the pending request is addressed to.
+    pub fn peer(&self) -> Option<SocketAddr> {
+        self.peer
+    }
+
+    /// Starts moving `peer` from `current` to `target`; returns the handshake to send.
+    pub fn begin(
+        &mut self,
+        peer: Option<SocketAddr>,
+        current: bool,
+        target: bool,
+        now: Instant,
+    ) -> Result<HandshakePayload, String> {
+        if self.is_pending() {
+            return Err("Sync already pending".to_string());
+        }
+        self.state = SyncState::Pending { target, previous: current, attempts: 1 };
+        self.peer = peer;
+        self.started = Some(now);
+        self.last_sent = Some(now);
+        Ok(Self::request(current, target))
+    }
+
+    fn request(current: bool, target: bool) -> HandshakePayload {
+        HandshakePayload {
+            client_current: current,
+            server_current: target,
+            client_target: target,
+            server_target: target,
+        }
+    }
+
+    /// Returns the handshake to retransmit if one is due.
+    pub fn retransmit(&mut self, now: Instant) -> Option<HandshakePayload> {
+        let (target, previous) = match &mut self.state {
+            SyncState::Pending { target, previous, attempts } => {
+                let due = self.last_sent.is_none_or(|t| {
+                    now.duration_since(t) >= Duration::from_millis(self.config.retransmit_ms as u64)
+                });
+                if !due {
+                    return None;
+                }
+                *attempts += 1;
+                (*target, *previous)
+            }
+            SyncState::Idle => return None,
+        };
+        self.last_sent = Some(now);
+        Some(Self::request(previous, target))
+    }
+
+    /// Settles the pending request with `from`'s handshake; handshakes from any other client are ignored.
+    pub fn on_handshake(&mut self, from: SocketAddr, incoming: &HandshakePayload) -> Option<SyncOutcome> {
+        let (target, attempts) = match self.state {
+            SyncState::Pending { target, attempts, .. } if self.peer == Some(from) => (target, attempts),
+            _ => return None,
+        };
+        self.reset();
+        if incoming.client_target == target {
+            Some(SyncOutcome::Confirmed { active: target, attempts })
+        } else {
+            Some(SyncOutcome::Overridden { active: incoming.client_target })
+        }
+    }
+
+    pub fn tick(&mut self, now: Instant) -> Option<SyncOutcome> {
+        let (previous, attempts) = match self.state {
+            SyncState::Pending { previous, attempts, .. } => (previous, attempts),
+            SyncState::Idle => return None,
+        };
+        let expired = self.started.is_some_and(|t| {
+            now.duration_since(t) >= Duration::from_millis(self.config.timeout_ms as u64)
+        });
+        if !expired {
+            return None;
+        }
+        self.reset();
+        Some(SyncOutcome::TimedOut { active: previous, attempts })
+    }
+
+    pub fn cancel(&mut self) {
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        self.state = SyncState::Idle;
+        self.peer = None;
+        self.started = None;
+        self.last_sent = None;
+    }
+}
+
+impl Default for SyncMachine {
+    fn default() -> Self {
+        Self::new(SyncConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(base: Instant, offset: u64) -> Instant {
+        base + Duration::from_millis(offset)
+    }
+
+    fn peer(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    fn reply(client_target: bool) -> HandshakePayload {
+        HandshakePayload {
+            client_current: client_target,
+            server_current: client_target,
+            client_target,
+            server_target: client_target,
+        }
+    }
+
+    /// `(client_current, server_target)` of a sent request.
+    fn sent(request: Option<HandshakePayload>) -> Option<(bool, bool)> {
+        request.map(|r| (r.client_current, r.server_target))
+    }
+
+    #[test]
+    fn retransmits_on_the_interval_until_confirmed() {
+        let mut sync = SyncMachine::default();
+        let t = Instant::now();
+        assert_eq!(sent(sync.begin(Some(peer(1)), false, true, t).ok()), Some((false, true)));
+        assert!(sync.begin(Some(peer(1)), false, true, t).is_err());
+
+        assert_eq!(sent(sync.retransmit(ms(t, 99))), None);
+        assert_eq!(sent(sync.retransmit(ms(t, 100))), Some((false, true)));
+        assert_eq!(sent(sync.retransmit(ms(t, 150))), None);
+        assert_eq!(sent(sync.retransmit(ms(t, 200))), Some((false, true)));
+        assert_eq!(sync.state(), SyncState::Pending { target: true, previous: false, attempts: 3 });
+
+        assert_eq!(sync.on_handshake(peer(1), &reply(true)), Some(SyncOutcome::Confirmed { active: true, attempts: 3 }));
+        assert!(!sync.is_pending());
+        assert!(sync.retransmit(ms(t, 300)).is_none());
+    }
+
+    #[test]
+    fn rolls_back_after_the_timeout() {
+        let mut sync = SyncMachine::new(SyncConfig { retransmit_ms: 50, timeout_ms: 120 });
+        let t = Instant::now();
+        sync.begin(Some(peer(1)), true, false, t).unwrap();
+        sync.retransmit(ms(t, 50));
+        assert_eq!(sync.tick(ms(t, 119)), None);
+        assert_eq!(sync.tick(ms(t, 120)), Some(SyncOutcome::TimedOut { active: true, attempts: 2 }));
+        assert_eq!(sync.tick(ms(t, 500)), None);
+        assert_eq!(sync.on_handshake(peer(1), &reply(false)), None);
+    }
+
+    #[test]
+    fn client_with_another_target_overrides() {
+        let mut sync = SyncMachine::default();
+        let t = Instant::now();
+        sync.begin(Some(peer(1)), false, true, t).unwrap();
+        assert_eq!(sync.on_handshake(peer(1), &reply(false)), Some(SyncOutcome::Overridden { active: false }));
+        sync.begin(Some(peer(1)), false, true, t).unwrap();
+        sync.cancel();
+        assert_eq!(sync.state(), SyncState::Idle);
+    }
+
+    #[test]
+    fn handshakes_from_other_clients_leave_the_request_pending() {
+        let mut sync = SyncMachine::default();
+        let t = Instant::now();
+        sync.begin(Some(peer(1)), false, true, t).unwrap();
+        assert_eq!(sync.on_handshake(peer(2), &reply(false)), None);
+        assert!(sync.is_pending());
+        assert_eq!(sync.peer(), Some(peer(1)));
+        assert_eq!(sync.on_handshake(peer(1), &reply(true)), Some(SyncOutcome::Confirmed { active: true, attempts: 1 }));
+
+        sync.begin(None, false, true, t).unwrap();
+        assert_eq!(sync.on_handshake(peer(1), &reply(true)), None);
+        assert!(sync.is_pending());
+    }
+}